@@ -32,13 +32,25 @@ use convert_case::{
 };
 use proc_macro2::{
     Ident,
+    Literal,
     TokenStream,
 };
 use quote::*;
+use sha2::{
+    Digest,
+    Sha256,
+};
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     str::FromStr,
 };
+use tiny_keccak::{
+    Hasher,
+    Keccak,
+};
 
 // constant vector of rust keywords which are not keywords in solidity
 const RUST_KEYWORDS: [&str; 27] = [
@@ -60,7 +72,7 @@ pub fn assemble_contract(contract: &Contract) -> TokenStream {
     let comments = &contract.contract_doc;
     let emit_functions = assemble_contract_emit_functions(&contract.events);
     let base = contract
-        .base
+        .interfaces
         .iter()
         .map(|base| TokenStream::from_str(base).unwrap())
         .collect::<Vec<_>>();
@@ -110,7 +122,12 @@ pub fn assemble_contract(contract: &Contract) -> TokenStream {
 pub fn assemble_impl(contract: &Contract) -> TokenStream {
     let trait_name = format_ident!("{}", contract.name);
     let signature = signature();
-    let imports = Vec::from_iter(&contract.imports);
+    // the global items of the file are re-exported by the trait definition
+    let imports = contract
+        .imports
+        .iter()
+        .filter(|import| !matches!(import, Import::Globals(_) | Import::ImportedGlobals(_)))
+        .collect::<Vec<_>>();
     let data = assemble_data_struct(&contract.fields);
     let getters = assemble_getters(&contract.fields);
     let mut modifiers_map = HashMap::new();
@@ -187,6 +204,9 @@ pub fn assemble_trait(contract: &Contract) -> TokenStream {
     let imports = Vec::from_iter(&contract.imports);
     let enums = assemble_enums(&contract.enums);
     let structs = assemble_structs(&contract.structs);
+    let value_types = assemble_value_types(&contract.value_types);
+    let errors = assemble_errors(&contract.errors);
+    let globals_error = assemble_globals_error(&contract.imports, &contract.globals_errors);
     let getters_trait = assemble_getters_trait(&contract.fields, &contract.functions);
     let function_headers = assemble_function_headers(
         &contract
@@ -210,12 +230,15 @@ pub fn assemble_trait(contract: &Contract) -> TokenStream {
         #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
         pub enum Error {
             Custom(String),
+            #errors
         }
         _blank_!();
+        #globals_error
         #enums
         _blank_!();
         #structs
         _blank_!();
+        #value_types
         #[openbrush::wrapper]
         pub type #ref_name = dyn #trait_name;
         _blank_!();
@@ -264,15 +287,32 @@ pub fn assemble_interface(interface: Interface) -> TokenStream {
     let events = assemble_events(&interface.events);
     let enums = assemble_enums(&interface.enums);
     let structs = assemble_structs(&interface.structs);
+    let value_types = assemble_value_types(&interface.value_types);
     let function_headers = assemble_function_headers(&interface.function_headers);
+    let errors = if interface.errors.is_empty() {
+        quote!()
+    } else {
+        let errors = assemble_errors(&interface.errors);
+        quote! {
+            #[derive(Debug, scale::Encode, scale::Decode, PartialEq, Eq)]
+            #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+            pub enum Error {
+                Custom(String),
+                #errors
+            }
+            _blank_!();
+        }
+    };
 
     let interface = quote! {
         #signature
         #(#imports)*
         _blank_!();
+        #errors
         #events
         #enums
         #structs
+        #value_types
         #[openbrush::wrapper]
         pub type #interface_name_ref = dyn #interface_name;
         _blank_!();
@@ -292,6 +332,8 @@ pub fn assemble_library(library: Library) -> TokenStream {
     let events = assemble_events(&library.events);
     let enums = assemble_enums(&library.enums);
     let structs = assemble_structs(&library.structs);
+    let value_types = assemble_value_types(&library.value_types);
+    let errors = assemble_errors(&library.errors);
     let constants = assemble_constants(&library.fields);
     let functions = assemble_functions(&library.functions, true);
     let comments = &library.libraray_doc;
@@ -307,6 +349,7 @@ pub fn assemble_library(library: Library) -> TokenStream {
         _blank_!();
         pub enum Error {
             Custom(String),
+            #errors
         }
         _blank_!();
 
@@ -314,12 +357,354 @@ pub fn assemble_library(library: Library) -> TokenStream {
         #events
         #enums
         #structs
+        #value_types
         #functions
     };
 
     library
 }
 
+/// Assembles the TokenStream of the items defined outside of contracts in a solidity file
+/// Free functions are assembled as the functions of a library
+pub fn assemble_globals(globals: Globals) -> TokenStream {
+    let signature = signature();
+    let imports = Vec::from_iter(&globals.imports);
+    let constants = assemble_constants(&globals.fields);
+    let enums = assemble_enums(&globals.enums);
+    let structs = assemble_structs(&globals.structs);
+    let value_types = assemble_value_types(&globals.value_types);
+    let errors = assemble_errors(&globals.errors);
+    let functions = assemble_functions(&globals.functions, true);
+
+    quote! {
+        #(#imports)*
+        use scale::{
+            Decode,
+            Encode,
+        };
+        _blank_!();
+        #signature
+        #[derive(Debug, Encode, Decode, PartialEq, Eq)]
+        #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+        pub enum Error {
+            Custom(String),
+            #errors
+        }
+        _blank_!();
+        #constants
+        #enums
+        #structs
+        #value_types
+        #functions
+    }
+}
+
+/// Assembles the TokenStream of the signed 256 bit integer `int256` is translated to
+/// ink! has no signed 256 bit integer, so the value is stored as a `U256` in two's complement
+pub fn assemble_i256() -> TokenStream {
+    let signature = signature();
+    let operators = [
+        ("Add", "add", "checked_add"),
+        ("Sub", "sub", "checked_sub"),
+        ("Mul", "mul", "checked_mul"),
+        ("Div", "div", "checked_div"),
+        ("Rem", "rem", "checked_rem"),
+    ]
+    .map(|(operator, method, checked)| {
+        let (operator, method, checked) = (
+            format_ident!("{operator}"),
+            format_ident!("{method}"),
+            format_ident!("{checked}"),
+        );
+        quote! {
+            impl core::ops:: #operator for I256 {
+                type Output = I256;
+
+                fn #method(self, other: I256) -> I256 {
+                    self. #checked (other).expect("Arithmetic overflow")
+                }
+            }
+            _blank_!();
+        }
+    });
+    // signed integers are sign extended
+    let signed_conversions = ["i8", "i16", "i32", "i64", "i128"].map(|integer| {
+        let integer = format_ident!("{integer}");
+        quote! {
+            impl From<#integer> for I256 {
+                fn from(value: #integer) -> Self {
+                    let value = value as i128;
+                    if value < 0 {
+                        I256(!U256::from(!value as u128))
+                    } else {
+                        I256(U256::from(value as u128))
+                    }
+                }
+            }
+            _blank_!();
+        }
+    });
+    // unsigned integers are zero extended, casting them to `i128` would make large `u128` values negative
+    let unsigned_conversions = ["u8", "u16", "u32", "u64", "u128"].map(|integer| {
+        let integer = format_ident!("{integer}");
+        quote! {
+            impl From<#integer> for I256 {
+                fn from(value: #integer) -> Self {
+                    I256(U256::from(value))
+                }
+            }
+            _blank_!();
+        }
+    });
+
+    quote! {
+        use primitive_types::U256;
+        use scale::{
+            Decode,
+            Encode,
+        };
+        _blank_!();
+        #signature
+        /// Signed 256 bit integer in two's complement
+        #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+        #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+        pub struct I256(pub U256);
+        _blank_!();
+        impl I256 {
+            pub const MAX: I256 = I256(U256([u64::MAX, u64::MAX, u64::MAX, i64::MAX as u64]));
+            pub const MIN: I256 = I256(U256([0, 0, 0, 1 << 63]));
+            _blank_!();
+            pub fn zero() -> Self {
+                I256(U256::zero())
+            }
+            _blank_!();
+            pub fn is_negative(&self) -> bool {
+                self.0.bit(255)
+            }
+            _blank_!();
+            pub fn low_u128(&self) -> u128 {
+                self.0.low_u128()
+            }
+            _blank_!();
+            fn unsigned_abs(self) -> U256 {
+                if self.is_negative() {
+                    (!self.0).overflowing_add(U256::one()).0
+                } else {
+                    self.0
+                }
+            }
+            _blank_!();
+            fn from_sign(negative: bool, value: U256) -> Option<Self> {
+                if !negative {
+                    return (!value.bit(255)).then_some(I256(value))
+                }
+                (value <= U256::one() << 255).then_some(I256((!value).overflowing_add(U256::one()).0))
+            }
+            _blank_!();
+            pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+                let result = I256(self.0.overflowing_add(other.0).0);
+                let overflow = self.is_negative() == other.is_negative()
+                    && result.is_negative() != self.is_negative();
+                (result, overflow)
+            }
+            _blank_!();
+            pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+                let result = I256(self.0.overflowing_sub(other.0).0);
+                let overflow = self.is_negative() != other.is_negative()
+                    && result.is_negative() != self.is_negative();
+                (result, overflow)
+            }
+            _blank_!();
+            pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+                let result = I256(self.0.overflowing_mul(other.0).0);
+                let (value, overflow) = self.unsigned_abs().overflowing_mul(other.unsigned_abs());
+                let negative = self.is_negative() != other.is_negative();
+                (result, overflow || I256::from_sign(negative, value).is_none())
+            }
+            _blank_!();
+            pub fn overflowing_pow(self, exponent: impl Into<U256>) -> (Self, bool) {
+                let mut exponent = exponent.into();
+                let mut base = self;
+                let mut result = I256::from(1);
+                let mut overflow = false;
+                while !exponent.is_zero() {
+                    if exponent.bit(0) {
+                        let (value, overflowed) = result.overflowing_mul(base);
+                        result = value;
+                        overflow |= overflowed;
+                    }
+                    exponent = exponent >> 1;
+                    if !exponent.is_zero() {
+                        let (value, overflowed) = base.overflowing_mul(base);
+                        base = value;
+                        overflow |= overflowed;
+                    }
+                }
+                (result, overflow)
+            }
+            _blank_!();
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                let (result, overflow) = self.overflowing_add(other);
+                (!overflow).then_some(result)
+            }
+            _blank_!();
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                let (result, overflow) = self.overflowing_sub(other);
+                (!overflow).then_some(result)
+            }
+            _blank_!();
+            pub fn checked_mul(self, other: Self) -> Option<Self> {
+                let (result, overflow) = self.overflowing_mul(other);
+                (!overflow).then_some(result)
+            }
+            _blank_!();
+            pub fn checked_pow(self, exponent: impl Into<U256>) -> Option<Self> {
+                let (result, overflow) = self.overflowing_pow(exponent);
+                (!overflow).then_some(result)
+            }
+            _blank_!();
+            pub fn checked_div(self, other: Self) -> Option<Self> {
+                if other.0.is_zero() {
+                    return None
+                }
+                let negative = self.is_negative() != other.is_negative();
+                I256::from_sign(negative, self.unsigned_abs() / other.unsigned_abs())
+            }
+            _blank_!();
+            pub fn checked_rem(self, other: Self) -> Option<Self> {
+                if other.0.is_zero() {
+                    return None
+                }
+                I256::from_sign(self.is_negative(), self.unsigned_abs() % other.unsigned_abs())
+            }
+        }
+        _blank_!();
+        impl Ord for I256 {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                match (self.is_negative(), other.is_negative()) {
+                    (true, false) => core::cmp::Ordering::Less,
+                    (false, true) => core::cmp::Ordering::Greater,
+                    _ => self.0.cmp(&other.0),
+                }
+            }
+        }
+        _blank_!();
+        impl PartialOrd for I256 {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        _blank_!();
+        #(#operators)*
+        impl<T: Into<U256>> core::ops::Shl<T> for I256 {
+            type Output = I256;
+
+            fn shl(self, shift: T) -> I256 {
+                let shift = shift.into();
+                if shift >= U256::from(256) {
+                    return I256::zero()
+                }
+                I256(self.0 << shift.as_usize())
+            }
+        }
+        _blank_!();
+        /// the shift to the right is arithmetic, negative values stay negative
+        impl<T: Into<U256>> core::ops::Shr<T> for I256 {
+            type Output = I256;
+
+            fn shr(self, shift: T) -> I256 {
+                let shift = shift.into().min(U256::from(255));
+                if self.is_negative() {
+                    I256(!(!self.0 >> shift.as_usize()))
+                } else {
+                    I256(self.0 >> shift.as_usize())
+                }
+            }
+        }
+        _blank_!();
+        impl core::ops::Neg for I256 {
+            type Output = I256;
+
+            fn neg(self) -> I256 {
+                I256::zero().checked_sub(self).expect("Arithmetic overflow")
+            }
+        }
+        _blank_!();
+        #(#signed_conversions)*
+        #(#unsigned_conversions)*
+        /// `int256(x)` keeps the bits of `x`
+        impl From<U256> for I256 {
+            fn from(value: U256) -> Self {
+                I256(value)
+            }
+        }
+        _blank_!();
+        /// `uint256(x)` keeps the bits of `x`
+        impl From<I256> for U256 {
+            fn from(value: I256) -> Self {
+                value.0
+            }
+        }
+    }
+}
+
+/// Assembles the conversion of the `Error` enum of the global items of a file to the `Error` enum
+/// of a contract, so the errors returned by free functions can be propagated in the contract
+/// The errors defined in the file are the first variants of both enums, so their encoding is the same
+fn assemble_globals_error(imports: &HashSet<Import>, errors: &[Error]) -> TokenStream {
+    let mut output = TokenStream::new();
+
+    for import in imports.iter() {
+        if let Import::Globals(globals) = import {
+            let globals = format_ident!("{}", globals);
+            // the errors of the file are the first variants of the `Error` enum of the contract
+            let variants = errors.iter().map(|error| {
+                let error_name = format_ident!("{}", error.name);
+                if error.fields.is_empty() {
+                    quote!(crate::libs:: #globals ::Error:: #error_name => Error:: #error_name)
+                } else if error
+                    .fields
+                    .iter()
+                    .all(|error_field| error_field.name != "_")
+                {
+                    let field_names = error
+                        .fields
+                        .iter()
+                        .map(|error_field| {
+                            format_ident!("{}", format_expression(&error_field.name, Snake))
+                        })
+                        .collect::<Vec<_>>();
+                    quote!(
+                        crate::libs:: #globals ::Error:: #error_name { #(#field_names),* } =>
+                            Error:: #error_name { #(#field_names),* }
+                    )
+                } else {
+                    let field_names = (0..error.fields.len())
+                        .map(|index| format_ident!("field_{}", index))
+                        .collect::<Vec<_>>();
+                    quote!(
+                        crate::libs:: #globals ::Error:: #error_name ( #(#field_names),* ) =>
+                            Error:: #error_name ( #(#field_names),* )
+                    )
+                }
+            });
+            output.extend(quote! {
+                impl From<crate::libs:: #globals ::Error> for Error {
+                    fn from(error: crate::libs:: #globals ::Error) -> Self {
+                        match error {
+                            crate::libs:: #globals ::Error::Custom(message) => Error::Custom(message),
+                            #(#variants),*
+                        }
+                    }
+                }
+                _blank_!();
+            });
+        }
+    }
+
+    output
+}
+
 /// Assembles the TokenStream of Enums from the parsed Enum structs
 fn assemble_enums(enums: &[Enum]) -> TokenStream {
     let mut output = TokenStream::new();
@@ -365,6 +750,55 @@ fn assemble_enums(enums: &[Enum]) -> TokenStream {
     output
 }
 
+/// Assembles the TokenStream of `Error` enum variants from the parsed Error structs
+/// Errors with named fields become struct variants, otherwise we produce tuple variants
+fn assemble_errors(errors: &[Error]) -> TokenStream {
+    let mut output = TokenStream::new();
+
+    for error in errors.iter() {
+        let error_name = TokenStream::from_str(&error.name).unwrap();
+        let mut error_comments = TokenStream::new();
+
+        // assemble comments
+        for comment in error.comments.iter() {
+            error_comments.extend(quote! {
+                #[doc = #comment]
+            });
+        }
+
+        let field_types = error
+            .fields
+            .iter()
+            .map(|error_field| &error_field.field_type)
+            .collect::<Vec<_>>();
+
+        // assemble error fields
+        let error_fields = if error.fields.is_empty() {
+            quote!()
+        } else if error
+            .fields
+            .iter()
+            .all(|error_field| error_field.name != "_")
+        {
+            let field_names = error
+                .fields
+                .iter()
+                .map(|error_field| format_ident!("{}", format_expression(&error_field.name, Snake)))
+                .collect::<Vec<_>>();
+            quote!( { #(#field_names: #field_types),* } )
+        } else {
+            quote!( ( #(#field_types),* ) )
+        };
+
+        output.extend(quote! {
+            #error_comments
+            #error_name #error_fields,
+        });
+    }
+
+    output
+}
+
 /// Assembles the TokenStream of ink! events from the parsed Event structs
 fn assemble_events(events: &[Event]) -> TokenStream {
     let mut output = TokenStream::new();
@@ -425,6 +859,12 @@ fn assemble_events(events: &[Event]) -> TokenStream {
 fn assemble_data_struct(fields: &[ContractField]) -> TokenStream {
     let mut output = TokenStream::new();
     let mut storage_fields = TokenStream::new();
+    let storage = fields
+        .iter()
+        .filter(|field| !field.constant)
+        .map(|field| (field.name.to_case(Snake), &field.field_type))
+        .collect::<Vec<_>>();
+    let (derive_default, default_impl) = assemble_default(&quote!(Data), &storage, true);
 
     // assemble storage fields
     for field in fields.iter().filter(|field| !field.constant) {
@@ -444,12 +884,13 @@ fn assemble_data_struct(fields: &[ContractField]) -> TokenStream {
     output.extend(quote! {
         pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
         _blank_!();
-        #[derive(Default, Debug)]
+        #[derive(#derive_default Debug)]
         #[openbrush::upgradeable_storage(STORAGE_KEY)]
         pub struct Data {
             #storage_fields
             pub _reserved: Option<()>,
         }
+        #default_impl
     });
 
     output
@@ -533,6 +974,11 @@ fn assemble_constants(fields: &[ContractField]) -> TokenStream {
         let field_name = format_ident!("{}", format_expression(&field.name, UpperSnake));
         let field_type = &field.field_type;
         let initial_value = field.initial_value.clone().unwrap();
+        // `U256::from` is not a const function, so we build the constant from its limbs
+        let initial_value = match u256_constant(&initial_value) {
+            Some(constant) => constant,
+            None => quote!( #initial_value ),
+        };
 
         for comment in field.comments.iter() {
             output.extend(quote! {
@@ -568,6 +1014,13 @@ fn assemble_structs(structs: &[Struct]) -> TokenStream {
             });
         }
 
+        let fields = structure
+            .fields
+            .iter()
+            .map(|field| (format_expression(&field.name, Snake), &field.field_type))
+            .collect::<Vec<_>>();
+        let (derive_default, default_impl) = assemble_default(&struct_name, &fields, false);
+
         // assemble struct fields
         for struct_field in structure.fields.iter() {
             let mut struct_field_comments = TokenStream::new();
@@ -589,15 +1042,229 @@ fn assemble_structs(structs: &[Struct]) -> TokenStream {
 
         output.extend(quote! {
             #struct_comments
-            #[derive(Default, Encode, Decode)]
+            #[derive(#derive_default Encode, Decode)]
             #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
             pub struct #struct_name {
                 #struct_fields
             }
+            #default_impl
+        });
+
+        output.extend(quote! {
+            _blank_!();
         });
+    }
+
+    output
+}
+
+/// Assembles the `Default` derive of a struct, or its `Default` implementation if the struct contains
+/// fixed size arrays longer than 32 elements, which do not implement `Default`
+///
+/// `name` the name of the struct
+/// `fields` the names and the types of the fields
+/// `storage` whether the struct is the storage struct with the `_reserved` field
+///
+/// Returns the derive and the implementation, only one of them is not empty
+fn assemble_default(
+    name: &TokenStream,
+    fields: &[(String, &Type)],
+    storage: bool,
+) -> (TokenStream, TokenStream) {
+    if !fields.iter().any(|(_, ty)| long_array(ty)) {
+        return (quote!(Default,), TokenStream::new())
+    }
+    let names = fields
+        .iter()
+        .map(|(name, _)| format_ident!("{}", name))
+        .collect::<Vec<_>>();
+    let values = fields.iter().map(|(_, ty)| default_value(ty));
+    let reserved = storage.then(|| quote!(_reserved: None,));
+    let default_impl = quote! {
+        _blank_!();
+        impl Default for #name {
+            fn default() -> Self {
+                Self {
+                    #(#names: #values,)*
+                    #reserved
+                }
+            }
+        }
+    };
+    (TokenStream::new(), default_impl)
+}
+
+/// Returns true if the type is a fixed size array longer than 32 elements,
+/// which does not implement `Default`
+fn long_array(ty: &Type) -> bool {
+    match ty {
+        Type::Array(_, Some(Expression::NumberLiteral(size))) => {
+            size.parse::<usize>().is_ok_and(|size| size > 32)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the TokenStream of the default value of a type
+fn default_value(ty: &Type) -> TokenStream {
+    if long_array(ty) {
+        quote!(core::array::from_fn(|_| Default::default()))
+    } else {
+        quote!(Default::default())
+    }
+}
+
+/// Assembles the TokenStream of user defined value types from the parsed ValueType structs
+/// Value types become newtypes convertible from and to the underlying type,
+/// operators bound to the value type are implemented with the `core::ops` and `core::cmp` traits
+fn assemble_value_types(value_types: &[ValueType]) -> TokenStream {
+    let mut output = TokenStream::new();
+
+    for value_type in value_types.iter() {
+        let value_type_name =
+            TokenStream::from_str(&format_expression(&value_type.name, Pascal)).unwrap();
+        let ty = &value_type.ty;
+        let mut value_type_comments = TokenStream::new();
+        let mut operators = TokenStream::new();
+        let mut comparisons = TokenStream::new();
+        let mut orderings = TokenStream::new();
+
+        // assemble comments
+        for comment in value_type.comments.iter() {
+            value_type_comments.extend(quote! {
+                #[doc = #comment]
+            });
+        }
+
+        // assemble operators
+        for binding in value_type.operators.iter() {
+            let function = format_ident!("{}", format_expression(&binding.function, Snake));
+            let (operator_trait, method) = match (binding.operator.as_str(), binding.unary) {
+                ("-", true) => ("Neg", "neg"),
+                ("~", _) => ("Not", "not"),
+                ("+", _) => ("Add", "add"),
+                ("-", _) => ("Sub", "sub"),
+                ("*", _) => ("Mul", "mul"),
+                ("/", _) => ("Div", "div"),
+                ("%", _) => ("Rem", "rem"),
+                ("&", _) => ("BitAnd", "bitand"),
+                ("|", _) => ("BitOr", "bitor"),
+                ("^", _) => ("BitXor", "bitxor"),
+                ("==", _) => ("PartialEq", "eq"),
+                ("!=", _) => ("PartialEq", "ne"),
+                ("<", _) => ("PartialOrd", "lt"),
+                ("<=", _) => ("PartialOrd", "le"),
+                (">", _) => ("PartialOrd", "gt"),
+                (">=", _) => ("PartialOrd", "ge"),
+                (operator, _) => {
+                    let comment = format!("Operator `{operator}` can not be bound in Rust");
+                    operators.extend(quote! {
+                        _comment_!(#comment);
+                    });
+                    continue
+                }
+            };
+            let operator_trait = format_ident!("{}", operator_trait);
+            let method = format_ident!("{}", method);
+            match operator_trait.to_string().as_str() {
+                "PartialEq" => {
+                    comparisons.extend(quote! {
+                        fn #method(&self, other: &Self) -> bool {
+                            #function(*self, *other).unwrap()
+                        }
+                    })
+                }
+                "PartialOrd" => {
+                    orderings.extend(quote! {
+                        fn #method(&self, other: &Self) -> bool {
+                            #function(*self, *other).unwrap()
+                        }
+                    })
+                }
+                "Neg" | "Not" => {
+                    operators.extend(quote! {
+                        impl core::ops:: #operator_trait for #value_type_name {
+                            type Output = #value_type_name;
+                            _blank_!();
+                            fn #method(self) -> Self::Output {
+                                #function(self).unwrap()
+                            }
+                        }
+                        _blank_!();
+                    })
+                }
+                _ => {
+                    operators.extend(quote! {
+                        impl core::ops:: #operator_trait for #value_type_name {
+                            type Output = #value_type_name;
+                            _blank_!();
+                            fn #method(self, other: Self) -> Self::Output {
+                                #function(self, other).unwrap()
+                            }
+                        }
+                        _blank_!();
+                    })
+                }
+            }
+        }
+
+        // `PartialEq` requires `eq`, so if only `!=` is bound we derive `eq` from it
+        let equality = if comparisons.is_empty() {
+            quote!(PartialEq, Eq,)
+        } else {
+            if !value_type
+                .operators
+                .iter()
+                .any(|binding| binding.operator == "==")
+            {
+                comparisons.extend(quote! {
+                    fn eq(&self, other: &Self) -> bool {
+                        !self.ne(other)
+                    }
+                });
+            }
+            operators.extend(quote! {
+                impl PartialEq for #value_type_name {
+                    #comparisons
+                }
+                _blank_!();
+            });
+            quote!()
+        };
+
+        // `PartialOrd` requires `partial_cmp`, for which we compare the underlying values
+        if !orderings.is_empty() {
+            operators.extend(quote! {
+                impl PartialOrd for #value_type_name {
+                    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                        self.0.partial_cmp(&other.0)
+                    }
+                    _blank_!();
+                    #orderings
+                }
+                _blank_!();
+            });
+        }
 
         output.extend(quote! {
+            #value_type_comments
+            #[derive(Default, Debug, Clone, Copy, #equality Encode, Decode)]
+            #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+            pub struct #value_type_name(pub #ty);
             _blank_!();
+            impl From<#ty> for #value_type_name {
+                fn from(value: #ty) -> Self {
+                    #value_type_name(value)
+                }
+            }
+            _blank_!();
+            impl From<#value_type_name> for #ty {
+                fn from(value: #value_type_name) -> Self {
+                    value.0
+                }
+            }
+            _blank_!();
+            #operators
         });
     }
 
@@ -713,19 +1380,24 @@ fn assemble_functions(functions: &[Function], is_library: bool) -> TokenStream {
             .unwrap(),
         );
 
-        // assemble view
-        view.extend(match function.header.view {
-            true => quote!(&self),
-            false => quote!(&mut self),
+        // assemble view, functions of libraries are free functions
+        view.extend(match (is_library, function.header.view) {
+            (true, _) => quote!(),
+            (false, true) => quote!(&self),
+            (false, false) => quote!(&mut self),
         });
 
         // assemble params
-        for param in function.header.params.iter() {
+        for (i, param) in function.header.params.iter().enumerate() {
             let param_name = format_ident!("{}", &format_expression(&param.name, Snake));
-            let param_type = &param.param_type;
+            let param_type = assemble_param_type(param, function.header.external || is_library);
+
+            if i > 0 || !is_library {
+                params.extend(quote! {,});
+            }
 
             params.extend(quote! {
-                , #param_name: #param_type
+                #param_name: #param_type
             });
         }
 
@@ -851,6 +1523,15 @@ fn assemble_functions(functions: &[Function], is_library: bool) -> TokenStream {
     output
 }
 
+/// Returns the TokenStream of the type returned by a function with the given return types
+fn assemble_return_type(returns: &[Type]) -> TokenStream {
+    match returns {
+        [] => quote!(()),
+        [ty] => quote!(#ty),
+        _ => quote!( (#(#returns),*) ),
+    }
+}
+
 /// Helper function which returns true if the given statement contains a return statement
 fn has_return_statement(statement: &Option<Statement>) -> bool {
     match statement {
@@ -1037,7 +1718,7 @@ fn assemble_function_headers(function_headers: &[FunctionHeader]) -> TokenStream
         // assemble params
         for param in header.params.iter() {
             let param_name = format_ident!("{}", format_expression(&param.name, Snake));
-            let param_type = &param.param_type;
+            let param_type = assemble_param_type(param, header.external);
 
             params.extend(quote! {
                 , #param_name: #param_type
@@ -1087,6 +1768,465 @@ fn assemble_function_headers(function_headers: &[FunctionHeader]) -> TokenStream
     output
 }
 
+/// Returns the TokenStream of a number literal converted to `U256` or `I256` in a const context
+fn u256_constant(expression: &Expression) -> Option<TokenStream> {
+    if let Expression::FunctionCall(function, args, _) = expression {
+        if let (Expression::Type(ty), [literal]) = (&**function, &args[..]) {
+            match **ty {
+                Type::Uint(256) => {
+                    let limbs = u256_limbs(literal)?.map(Literal::u64_unsuffixed);
+                    return Some(quote!( U256([ #(#limbs),* ]) ))
+                }
+                Type::Int(256) => {
+                    let limbs = u256_limbs(literal)?.map(Literal::u64_unsuffixed);
+                    return Some(quote!( I256(U256([ #(#limbs),* ])) ))
+                }
+                _ => (),
+            }
+        }
+    }
+    None
+}
+
+/// Returns the TokenStream of `type(T).max` or `type(T).min` of an integer type
+///
+/// `expression` the expression of which we access the member
+/// `member` the accessed member
+fn integer_bound(expression: &Expression, member: &str) -> Option<TokenStream> {
+    if let Expression::FunctionCall(function, args, _) = expression {
+        if let (Expression::Variable(name, ..), [Expression::Type(ty)]) = (&**function, &args[..]) {
+            return match (name.as_str(), &**ty, member) {
+                ("type", Type::Uint(256), "min") => Some(quote!(U256::zero())),
+                ("type", Type::Uint(_) | Type::Int(_), "max") => Some(quote!( #ty::MAX )),
+                ("type", Type::Uint(_) | Type::Int(_), "min") => Some(quote!( #ty::MIN )),
+                _ => None,
+            }
+        }
+    }
+    None
+}
+
+/// Returns the receiver, the method and the argument of an arithmetic operation called as a method,
+/// like `a.checked_add(b)`
+///
+/// `operation` the arithmetic operation
+/// `prefix` the prefix of the method, `checked` or `overflowing`
+fn arithmetic_method<'a>(
+    operation: &'a Expression,
+    prefix: &str,
+) -> (TokenStream, Ident, &'a Expression) {
+    let (left, operator, right) = match operation {
+        Expression::Add(left, right) => (left, "add", right),
+        Expression::Subtract(left, right) => (left, "sub", right),
+        Expression::Multiply(left, right) => (left, "mul", right),
+        Expression::Power(left, right) => (left, "pow", right),
+        _ => unreachable!("{operation:?} is not an arithmetic operation"),
+    };
+    (
+        method_receiver(left),
+        format_ident!("{prefix}_{operator}"),
+        right,
+    )
+}
+
+/// Returns the value of a constant string, like `"MINTER_ROLE"` or `bytes("MINTER_ROLE")`
+fn constant_string(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::StringLiteral(parts) => Some(parts.concat()),
+        Expression::FunctionCall(function, args, _)
+            if let (Expression::Type(ty), [argument]) = (&**function, &args[..]) =>
+        {
+            match **ty {
+                Type::DynamicBytes | Type::String => constant_string(argument),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the TokenStream of an expression a method is called on,
+/// operators bind weaker than method calls so they are wrapped in parentheses
+fn method_receiver(expression: &Expression) -> TokenStream {
+    match expression {
+        Expression::Variable(..)
+        | Expression::BoolLiteral(_)
+        | Expression::NumberLiteral(_)
+        | Expression::FunctionCall(..)
+        | Expression::MemberAccess(..)
+        | Expression::MappingSubscript(..)
+        | Expression::ArraySubscript(..)
+        | Expression::Parenthesis(_)
+        | Expression::Checked(_)
+        | Expression::Clone(_)
+        | Expression::Wrapping(_)
+        | Expression::Truncate(..)
+        | Expression::Balance(..)
+        | Expression::Cast(..)
+        | Expression::Length(_) => quote!( #expression ),
+        _ => quote!( (#expression) ),
+    }
+}
+
+/// Returns the TokenStream of a call of a function of a deployed contract through the wrapper
+/// of its trait, the errors of the called contract are wrapped in `Error::Custom` of the caller,
+/// since the variants of both `Error` enums do not match
+///
+/// `contract` the name of the called contract or interface
+/// `address` the address of the called contract
+/// `function` the name of the called function
+/// `args` the arguments of the call
+/// `value` the value transferred with the call
+fn assemble_contract_call(
+    contract: &str,
+    address: &Expression,
+    function: &str,
+    args: &[Expression],
+    value: &Option<Box<Expression>>,
+) -> TokenStream {
+    let contract_ref = TokenStream::from_str(&format!("{contract}Ref")).unwrap();
+    let call = match value {
+        Some(value) => {
+            let builder =
+                format_ident!("{}_builder", format_expression(&function.to_owned(), Snake));
+            quote!( #contract_ref :: #builder (& #address #(, #args)*).transferred_value( #value ).invoke() )
+        }
+        None => {
+            let function =
+                TokenStream::from_str(&format_expression(&function.to_owned(), Snake)).unwrap();
+            quote!( #contract_ref :: #function (& #address #(, #args)*) )
+        }
+    };
+    quote!(
+        #call .map_err(|error| {
+            Error::Custom(ink::prelude::format!("Cross contract call failed: {error:?}"))
+        })
+    )
+}
+
+/// Returns the TokenStream of values encoded with `abi.encode`, `abi.encodeWithSelector`
+/// or `abi.encodeWithSignature`
+///
+/// `encoding` the selected encoding
+/// `selector` the selector preceding the encoded values
+/// `values` the encoded values together with their types
+fn assemble_abi_encode(
+    encoding: AbiEncoding,
+    selector: &Option<Box<Expression>>,
+    values: &[(Expression, Type)],
+) -> TokenStream {
+    let encoded = match encoding {
+        AbiEncoding::Scale => {
+            let values = values.iter().map(|(value, ty)| typed_literal(value, ty));
+            quote!( scale::Encode::encode(&( #(&#values,)* )) )
+        }
+        AbiEncoding::Ethereum => {
+            let tokens = values.iter().map(|(value, ty)| abi_token(value, ty));
+            quote!( ethabi::encode(&[ #(#tokens),* ]) )
+        }
+    };
+    match selector {
+        Some(selector) => quote!( [#selector .to_vec(), #encoded].concat() ),
+        None => encoded,
+    }
+}
+
+/// Returns the TokenStream of a value encoded as in `abi.encodePacked`, the values are
+/// concatenated without padding and integers are big-endian, so hashes of the packed values
+/// match the hashes computed by Solidity. Values of unknown types are expected to be strings or bytes
+///
+/// `value` the encoded value
+/// `ty` the type of the value
+fn assemble_packed_value(value: &Expression, ty: &Type) -> TokenStream {
+    match ty {
+        // the integers wider than 128 bits are `U256`, or `I256` holding a `U256`
+        Type::Uint(bits) | Type::Int(bits) if *bits > 128 => {
+            let value = match ty {
+                Type::Int(_) => quote!( #value.0 ),
+                _ => quote!( #value ),
+            };
+            let bytes = match bits {
+                256 => quote!(bytes),
+                _ => {
+                    let start = Literal::usize_unsuffixed(32 - *bits as usize / 8);
+                    quote!( bytes[#start..] )
+                }
+            };
+            quote!({
+                let mut bytes = [0u8; 32];
+                #value.to_big_endian(&mut bytes);
+                #bytes.to_vec()
+            })
+        }
+        // the integers are stored in the nearest Rust integer, so the leading bytes of wider integers are skipped
+        Type::Uint(bits) | Type::Int(bits) => {
+            let value = parenthesized(value);
+            let width = [8, 16, 32, 64, 128]
+                .into_iter()
+                .find(|width| width >= bits)
+                .unwrap_or(128);
+            let rust_type = match ty {
+                Type::Int(_) => format_ident!("i{}", width),
+                _ => format_ident!("u{}", width),
+            };
+            if width == *bits {
+                quote!( (#value as #rust_type).to_be_bytes().to_vec() )
+            } else {
+                let start = Literal::usize_unsuffixed(((width - *bits) / 8) as usize);
+                quote!( (#value as #rust_type).to_be_bytes()[#start..].to_vec() )
+            }
+        }
+        Type::Bool => {
+            let value = parenthesized(value);
+            quote!( [#value as u8].to_vec() )
+        }
+        Type::AccountId | Type::PayableAccountId | Type::Contract(_) => {
+            quote!( AsRef::<[u8]>::as_ref(&#value).to_vec() )
+        }
+        Type::Bytes(_) | Type::DynamicBytes => quote!( #value.to_vec() ),
+        Type::String => quote!( #value.as_bytes().to_vec() ),
+        _ => quote!( AsRef::<[u8]>::as_ref(&#value).to_vec() ),
+    }
+}
+
+/// Returns the TokenStream of a value, wrapped in parentheses unless it is a variable or a literal,
+/// so it can be cast with `as`
+///
+/// `value` the value
+fn parenthesized(value: &Expression) -> TokenStream {
+    match value {
+        Expression::NumberLiteral(_) | Expression::BoolLiteral(_) => quote!( #value ),
+        _ if is_place(value) => quote!( #value ),
+        _ => quote!( (#value) ),
+    }
+}
+
+/// Returns the TokenStream of a value, number literals are cast to their type
+/// so they are not encoded as `i32`
+///
+/// `value` the value
+/// `ty` the type of the value
+fn typed_literal(value: &Expression, ty: &Type) -> TokenStream {
+    match (value, ty) {
+        (Expression::NumberLiteral(_), Type::Uint(_) | Type::Int(_)) => quote!( (#value as #ty) ),
+        _ => quote!( #value ),
+    }
+}
+
+/// Returns the TokenStream of the `ethabi::Token` of a value, integers are encoded as 32 byte words
+/// and signed integers are sign-extended, values of unknown types are expected to be integers
+///
+/// `value` the encoded value
+/// `ty` the type of the value
+fn abi_token(value: &Expression, ty: &Type) -> TokenStream {
+    match ty {
+        Type::Uint(256) => quote!( ethabi::Token::Uint(#value) ),
+        Type::Int(256) => quote!( ethabi::Token::Int(#value.0) ),
+        Type::Int(_) => {
+            let value = parenthesized(value);
+            quote!(ethabi::Token::Int({
+                let value = #value as i128;
+                if value < 0 {
+                    U256::MAX - U256::from(!value as u128)
+                } else {
+                    U256::from(value as u128)
+                }
+            }))
+        }
+        Type::Bool => quote!( ethabi::Token::Bool(#value) ),
+        Type::AccountId | Type::PayableAccountId | Type::Contract(_) => {
+            quote!( ethabi::Token::FixedBytes(AsRef::<[u8]>::as_ref(&#value).to_vec()) )
+        }
+        Type::Bytes(_) => quote!( ethabi::Token::FixedBytes(#value.to_vec()) ),
+        Type::DynamicBytes => quote!( ethabi::Token::Bytes(#value.to_vec()) ),
+        Type::String => quote!( ethabi::Token::String(#value.to_string()) ),
+        _ => quote!( ethabi::Token::Uint(U256::from(#value)) ),
+    }
+}
+
+/// Returns the TokenStream of `abi.decode`, which returns a tuple of the decoded values
+/// or the decoded value if only one type is decoded
+///
+/// `encoding` the selected encoding
+/// `data` the decoded bytes
+/// `types` the types of the decoded values
+fn assemble_abi_decode(encoding: AbiEncoding, data: &Expression, types: &[Type]) -> TokenStream {
+    let error = quote!(Error::Custom(String::from("Decoding failed")));
+    match encoding {
+        AbiEncoding::Scale => {
+            let ty = match types {
+                [ty] => quote!( #ty ),
+                types => quote!( (#(#types),*) ),
+            };
+            quote!( <#ty as scale::Decode>::decode(&mut &#data[..]).map_err(|_| #error)? )
+        }
+        AbiEncoding::Ethereum => {
+            let param_types = types.iter().map(abi_param_type);
+            let values = types.iter().enumerate().map(|(index, ty)| {
+                let index = Literal::usize_unsuffixed(index);
+                from_abi_token(quote!( tokens[#index].clone() ), ty)
+            });
+            let values = match types {
+                [_] => quote!( #(#values)* ),
+                _ => quote!( (#(#values),*) ),
+            };
+            quote!({
+                let tokens = ethabi::decode(&[ #(#param_types),* ], &#data).map_err(|_| #error)?;
+                #values
+            })
+        }
+    }
+}
+
+/// Returns the TokenStream of the `ethabi::ParamType` of a type, unknown types are decoded as `uint256`
+///
+/// `ty` the decoded type
+fn abi_param_type(ty: &Type) -> TokenStream {
+    match ty {
+        Type::Uint(size) => {
+            let size = Literal::usize_unsuffixed(*size as usize);
+            quote!( ethabi::ParamType::Uint(#size) )
+        }
+        Type::Int(size) => {
+            let size = Literal::usize_unsuffixed(*size as usize);
+            quote!( ethabi::ParamType::Int(#size) )
+        }
+        Type::Bool => quote!(ethabi::ParamType::Bool),
+        Type::AccountId | Type::PayableAccountId | Type::Contract(_) => {
+            quote!(ethabi::ParamType::FixedBytes(32))
+        }
+        Type::Bytes(size) => {
+            let size = Literal::usize_unsuffixed(*size as usize);
+            quote!( ethabi::ParamType::FixedBytes(#size) )
+        }
+        Type::DynamicBytes => quote!(ethabi::ParamType::Bytes),
+        Type::String => quote!(ethabi::ParamType::String),
+        _ => quote!(ethabi::ParamType::Uint(256)),
+    }
+}
+
+/// Returns the TokenStream converting a decoded `ethabi::Token` to a value of its type
+///
+/// `token` the decoded token
+/// `ty` the type of the value
+fn from_abi_token(token: TokenStream, ty: &Type) -> TokenStream {
+    match ty {
+        Type::Uint(256) => quote!( #token.into_uint().unwrap_or_default() ),
+        Type::Uint(_) => quote!( #token.into_uint().unwrap_or_default().low_u128() as #ty ),
+        Type::Int(256) => quote!( I256(#token.into_int().unwrap_or_default()) ),
+        Type::Int(_) => {
+            quote!( #token.into_int().unwrap_or_default().low_u128() as i128 as #ty )
+        }
+        Type::Bool => quote!( #token.into_bool().unwrap_or_default() ),
+        Type::AccountId | Type::PayableAccountId | Type::Contract(_) => {
+            quote!(AccountId::from(
+                <[u8; 32]>::try_from(#token.into_fixed_bytes().unwrap_or_default()).unwrap_or_default()
+            ))
+        }
+        Type::Bytes(_) => {
+            quote!( <#ty>::try_from(#token.into_fixed_bytes().unwrap_or_default()).unwrap_or_default() )
+        }
+        Type::DynamicBytes => quote!( #token.into_bytes().unwrap_or_default() ),
+        Type::String => quote!( #token.into_string().unwrap_or_default() ),
+        _ => quote!( #token.into_uint().unwrap_or_default() ),
+    }
+}
+
+/// Returns true if the expression is a variable or a part of it, which is moved unless its type is `Copy`
+fn is_place(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Variable(..) | Expression::MemberAccess(..) | Expression::ArraySubscript(..)
+    )
+}
+
+/// Returns the TokenStream of the type of a function parameter,
+/// `calldata` parameters of internal functions are borrowed instead of copied
+/// and `storage` parameters are borrowed mutably, so the changes are seen by the caller
+///
+/// `param` the parsed function parameter
+/// `message` whether the function is a message or a library function, which own their parameters
+fn assemble_param_type(param: &FunctionParam, message: bool) -> TokenStream {
+    let ty = &param.param_type;
+    match (param.location, ty) {
+        (Some(DataLocation::Storage), _) => quote!( &mut #ty ),
+        (Some(DataLocation::Calldata), _) if message => quote!( #ty ),
+        (Some(DataLocation::Calldata), Type::Array(element, None)) => quote!( &[#element] ),
+        (Some(DataLocation::Calldata), Type::DynamicBytes) => quote!(&[u8]),
+        (Some(DataLocation::Calldata), Type::String) => quote!(&str),
+        (Some(DataLocation::Calldata), _) => quote!( &#ty ),
+        _ => quote!( #ty ),
+    }
+}
+
+/// Returns the storage field holding the length of a lazy array together with the type of the length,
+/// lazy arrays are the only mappings which have a length and can be pushed to or popped from
+///
+/// `array` the array expression
+fn lazy_array_length(array: &Expression) -> Option<(Expression, Type)> {
+    if let Expression::Variable(name, MemberType::Variable(ty), location) = array {
+        if let Type::LazyArray(index, _) = &**ty {
+            let length = Expression::Variable(
+                format!("{name}_length"),
+                MemberType::Variable(index.clone()),
+                location.clone(),
+            );
+            return Some((length, *index.clone()))
+        }
+    }
+    None
+}
+
+/// Assembles the assignment to an element of a lazy array, indices out of bounds revert
+///
+/// `array` the lazy array
+/// `length` the storage field holding the length of the array
+/// `index` the index of the assigned element
+/// `value` the assigned value
+/// `operator` the operator of a compound assignment
+fn assemble_lazy_array_assign(
+    array: &Expression,
+    length: &Expression,
+    index: &Expression,
+    value: &Expression,
+    operator: Option<TokenStream>,
+) -> TokenStream {
+    let new_value = operator.map(
+        |operator| quote!( let value = #array .get(&index).unwrap_or_default() #operator value; ),
+    );
+    quote!({
+        let (index, value) = (#index, #value);
+        if index >= #length {
+            return Err(Error::Custom(String::from("Index out of bounds")))
+        }
+        #new_value
+        #array .insert(&index, &value);
+    })
+}
+
+/// Returns the little endian 64 bit limbs of a number literal converted to `U256`
+/// Returns `None` if the expression is not a number literal or does not fit into 256 bits
+fn u256_limbs(literal: &Expression) -> Option<[u64; 4]> {
+    let (digits, radix) = match literal {
+        Expression::NumberLiteral(number) => (number.as_str(), 10),
+        Expression::HexLiteral(hex) => (hex.trim_start_matches("0x"), 16),
+        _ => return None,
+    };
+    let mut limbs = [0_u64; 4];
+    for digit in digits.chars().filter(|digit| *digit != '_') {
+        let mut carry = digit.to_digit(radix)? as u128;
+        for limb in limbs.iter_mut() {
+            let value = *limb as u128 * radix as u128 + carry;
+            *limb = value as u64;
+            carry = value >> 64;
+        }
+        if carry != 0 {
+            return None
+        }
+    }
+    Some(limbs)
+}
+
 /// Assembles the TokenStream of the signature which is then added to the beginning of the file :)
 fn signature() -> TokenStream {
     const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -1117,16 +2257,21 @@ fn format_expression(expression_raw: &String, case: Case) -> String {
 impl ToTokens for Type {
     fn to_tokens(&self, stream: &mut TokenStream) {
         stream.extend(match self {
-            Type::AccountId => quote!(AccountId),
+            Type::AccountId | Type::PayableAccountId => quote!(AccountId),
             Type::Bool => quote!(bool),
             Type::String => quote!(String),
+            Type::Int(256) => quote!(I256),
             Type::Int(size) => TokenStream::from_str(&format!("i{size}")).unwrap(),
+            Type::Uint(256) => quote!(U256),
             Type::Uint(size) => TokenStream::from_str(&format!("u{size}")).unwrap(),
+            Type::Usize => quote!(usize),
             Type::Bytes(size) => TokenStream::from_str(&format!("[u8; {size}]")).unwrap(),
             Type::DynamicBytes => quote!(Vec<u8>),
             Type::Variable(name) => {
                 TokenStream::from_str(&format_expression(name, Pascal)).unwrap()
             }
+            // contracts are referred to by their address
+            Type::Contract(_) => quote!(AccountId),
             Type::Mapping(keys, value) => {
                 if keys.len() == 1 {
                     let key = &keys[0];
@@ -1135,7 +2280,21 @@ impl ToTokens for Type {
                     quote!(Mapping <(#(#keys,)*), #value>)
                 }
             }
+            Type::LazyArray(index, element) => quote!( Mapping <#index, #element>),
+            Type::Array(ty, Some(Expression::NumberLiteral(size))) => {
+                let size = Literal::usize_unsuffixed(size.parse().unwrap());
+                quote!([ #ty ; #size ])
+            }
             Type::Array(ty, _) => quote!(Vec< #ty >),
+            Type::Function(params, returns, view) => {
+                let returns = assemble_return_type(returns);
+                let instance = match view {
+                    true => quote!(&Self),
+                    false => quote!(&mut Self),
+                };
+                quote!( fn( #instance, #(#params),* ) -> Result<#returns, Error> )
+            }
+            Type::ExternalFunction(..) => quote!((AccountId, [u8; 4])),
             Type::None => quote!(),
             Type::MemberAccess(from, identifeir) => {
                 let parsed_identifier =
@@ -1150,7 +2309,17 @@ impl ToTokens for Type {
 impl ToTokens for Statement {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(match self {
-            Statement::Assembly => quote!(__comment__!("Assembly block here. Parsing assembly is not implemented yet");),
+            Statement::Assembly(statements) => quote!(#(#statements)*),
+            // the parameters of the base constructors are scoped, so they do not shadow the parameters of the contract
+            Statement::BaseConstructor(base, body) => {
+                let comment = format!("The constructor of {base}");
+                quote!(
+                    _comment_!(#comment);
+                    {
+                        #(#body)*
+                    }
+                )
+            }
             Statement::Block(body) => quote!(#(#body)*),
             Statement::Break => quote!(break),
             Statement::Continue => quote!(continue),
@@ -1178,7 +2347,6 @@ impl ToTokens for Statement {
                     _ => unreachable!("Emit can be only function call"),
                 }
             }
-            Statement::Error => todo!(),
             Statement::Expression(expression) => quote!(#expression;),
             Statement::For(declaration, condition, on_pass, body) => {
                 quote!(
@@ -1210,28 +2378,213 @@ impl ToTokens for Statement {
                 }
             }
             Statement::Return(expression) => quote!(return Ok(#expression)),
-            Statement::Revert(reason, _) => {
-                quote!( return Err( Error::Custom(String::from(#reason) )) )},
-            Statement::RevertNamedArgs => todo!(),
-            Statement::Try(expression) => {
-                quote!(
-                    if #expression .is_err() {
-                        return Err(Error::Custom("Try failed"))
+            Statement::Revert(error, args) if error == "_" => {
+                match args.first() {
+                    Some(reason @ Expression::StringLiteral(_)) => {
+                        quote!( return Err( Error::Custom(String::from(#reason) )); )
                     }
-                )
+                    Some(reason) => quote!( return Err( Error::Custom(#reason) ); ),
+                    None => quote!( return Err( Error::Custom(String::from(#error) )); ),
+                }
             }
-            Statement::UncheckedBlock(statements) => {
+            Statement::Revert(error, args) => {
+                let error_name = TokenStream::from_str(error).unwrap();
+                if args.is_empty() {
+                    quote!( return Err( Error:: #error_name ); )
+                } else {
+                    quote!( return Err( Error:: #error_name ( #(#args),* ) ); )
+                }
+            }
+            Statement::RevertNamedArgs(error, args) => {
+                let error_name = TokenStream::from_str(error).unwrap();
+                let names = args
+                    .iter()
+                    .map(|arg| format_ident!("{}", format_expression(&arg.0, Snake)))
+                    .collect::<Vec<_>>();
+                let values = args.iter().map(|arg| &arg.1).collect::<Vec<_>>();
+                quote!( return Err( Error:: #error_name { #( #names : #values ),* } ); )
+            }
+            Statement::Try(expression, returns, body, catch_clauses) => {
+                // we match on the result of the call, so we do not propagate the error with `?`
+                let call = match expression {
+                    Expression::FunctionCall(function, args, None) => quote!( #function ( #(#args),* ) ),
+                    // the value is transferred by the call builder, the called contract is only known by its address
+                    Expression::FunctionCall(function, args, Some(value))
+                        if let Expression::List(message) = &**function
+                            && let [receiver, selector] = &message[..] =>
+                    {
+                        let account = match receiver {
+                            Expression::This(location) => {
+                                let location = match location {
+                                    VariableAccessLocation::Constructor => quote!(instance.),
+                                    VariableAccessLocation::Modifier => quote!(T::),
+                                    VariableAccessLocation::Any => quote!(Self::),
+                                };
+                                quote!( #location env().account_id() )
+                            }
+                            receiver => quote!( #receiver ),
+                        };
+                        let return_types = returns
+                            .iter()
+                            .map(|param| param.param_type.clone())
+                            .collect::<Vec<_>>();
+                        let return_type = assemble_return_type(&return_types);
+                        quote!(
+                            ink::env::call::build_call::<ink::env::DefaultEnvironment>()
+                                .call(#account)
+                                .transferred_value(#value)
+                                .exec_input(
+                                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(#selector))
+                                        #(.push_arg(#args))*
+                                )
+                                .returns::<Result<#return_type, Error>>()
+                                .try_invoke()
+                                .map_err(|_| Error::Custom(String::from("Cross contract call failed")))
+                                .and_then(|result| {
+                                    result.map_err(|_| Error::Custom(String::from("Cross contract call failed")))
+                                })
+                                .and_then(|result| result)
+                        )
+                    }
+                    Expression::ContractCall(contract, address, function, args, value) => {
+                        assemble_contract_call(contract, address, function, args, value)
+                    }
+                    _ => quote!( #expression ),
+                };
+                let return_names = returns
+                    .iter()
+                    .map(|param| format_ident!("{}", format_expression(&param.name, Snake)))
+                    .collect::<Vec<_>>();
+                let ok_binding = if return_names.len() == 1 {
+                    quote!( #(#return_names)* )
+                } else {
+                    quote!( ( #(#return_names),* ) )
+                };
+                let mut catch_arms = TokenStream::new();
+                let mut generic_arm = None;
+                let mut other_clauses = Vec::new();
+                for catch_clause in catch_clauses.iter() {
+                    match catch_clause {
+                        CatchClause::Named(name, param, body) if name == "Error" => {
+                            let reason = format_ident!("{}", format_expression(&param.name, Snake));
+                            catch_arms.extend(quote!(
+                                Err(Error::Custom(#reason)) => {
+                                    #body
+                                }
+                            ));
+                        }
+                        CatchClause::Named(name, param, body) => other_clauses.push((name, param, body)),
+                        CatchClause::Simple(Some(param), body) if param.name != "_" => {
+                            let data = format_ident!("{}", format_expression(&param.name, Snake));
+                            generic_arm = Some(quote!(
+                                Err(#data) => {
+                                    let #data: Vec<u8> = scale::Encode::encode(&#data);
+                                    #body
+                                }
+                            ));
+                        }
+                        CatchClause::Simple(_, body) => {
+                            generic_arm = Some(quote!(
+                                Err(_) => {
+                                    #body
+                                }
+                            ));
+                        }
+                    }
+                }
+                // ink! does not tell panics apart from other failures, so `catch Panic(uint code)`
+                // and other named clauses are caught by the generic arm
+                let mut other_clauses = other_clauses.into_iter();
+                if generic_arm.is_none() {
+                    if let Some((name, param, body)) = other_clauses.next() {
+                        let comment = format!("`catch {name}` catches every failure other than `Error` in ink!");
+                        let binding = (param.name != "_").then(|| {
+                            let name = format_ident!("{}", format_expression(&param.name, Snake));
+                            let ty = &param.param_type;
+                            quote!( let #name: #ty = Default::default(); )
+                        });
+                        generic_arm = Some(quote!(
+                            Err(_) => {
+                                _comment_!(#comment);
+                                #binding
+                                #body
+                            }
+                        ));
+                    }
+                }
+                let mut unreachable_clauses = TokenStream::new();
+                for (name, ..) in other_clauses {
+                    let comment = format!("`catch {name}` can not be told apart from other failures in ink!, they are caught by the generic catch clause");
+                    unreachable_clauses.extend(quote!(
+                        _comment_!(#comment);
+                    ));
+                }
+                catch_arms.extend(generic_arm.unwrap_or_else(|| {
+                    quote!(
+                        Err(error) => return Err(error),
+                    )
+                }));
                 quote!(
-                    // _comment_!("Please handle unchecked blocks manually >>>");
-                    #(#statements)*
-                    // _comment_!("<<< Please handle unchecked blocks manually");
+                    #unreachable_clauses
+                    match #call {
+                        Ok(#ok_binding) => {
+                            #body
+                        }
+                        #catch_arms
+                    }
                 )
             }
+            // the operations in unchecked blocks are parsed as wrapping
+            Statement::UncheckedBlock(statements) => quote!( #(#statements)* ),
             Statement::VariableDefinition(definition, initial_value) => {
-                if let Some(initial_value) = initial_value {
-                    quote!( #definition = #initial_value; )
-                } else {
-                    quote!( #initial_value; )
+                match (initial_value, definition) {
+                    // memory variables are copies, `storage` variables are copies written back by the parser
+                    (Some(initial_value), Expression::VariableDeclaration(ty, ..))
+                        if is_place(initial_value) && !ty.is_copy() =>
+                    {
+                        quote!( #definition = #initial_value.clone(); )
+                    }
+                    // destructured tuples, omitted slots are ignored
+                    (Some(initial_value), Expression::List(slots)) => {
+                        let patterns = slots.iter().map(|slot| {
+                            match slot {
+                                Expression::VariableDeclaration(ty, name, _) => {
+                                    let name = TokenStream::from_str(name).unwrap();
+                                    match **ty {
+                                        Type::None => quote!(#name),
+                                        _ => quote!(mut #name),
+                                    }
+                                }
+                                _ => quote!(_),
+                            }
+                        });
+                        let types = slots
+                            .iter()
+                            .map(|slot| {
+                                match slot {
+                                    Expression::VariableDeclaration(ty, ..) if **ty != Type::None => {
+                                        Some(quote!(#ty))
+                                    }
+                                    _ => None,
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        if types.iter().all(Option::is_none) {
+                            quote!( let (#(#patterns),*) = #initial_value; )
+                        } else {
+                            let types = types
+                                .into_iter()
+                                .map(|ty| ty.unwrap_or_else(|| quote!(_)));
+                            quote!( let (#(#patterns),*): (#(#types),*) = #initial_value; )
+                        }
+                    }
+                    (Some(initial_value), _) => quote!( #definition = #initial_value; ),
+                    // Solidity initializes the variables to their default values
+                    (None, Expression::VariableDeclaration(ty, ..)) => {
+                        let default_value = default_value(ty);
+                        quote!( #definition = #default_value; )
+                    }
+                    (None, _) => quote!( #definition; ),
                 }
             }
             Statement::While(condition, body) => {
@@ -1258,10 +2611,86 @@ impl ToTokens for Expression {
             }
         }
         tokens.extend(match self {
+            Expression::AbiDecode(encoding, data, types) => assemble_abi_decode(*encoding, data, types),
+            Expression::AbiEncode(encoding, selector, values) => {
+                assemble_abi_encode(*encoding, selector, values)
+            }
+            Expression::AbiEncodePacked(values) => {
+                if values.is_empty() {
+                    quote!(Vec::<u8>::new())
+                } else {
+                    let values = values.iter().map(|(value, ty)| assemble_packed_value(value, ty));
+                    quote!( [#(#values),*].concat() )
+                }
+            }
             Expression::Add(left, right) => quote!( #left + #right),
             Expression::ArraySubscript(expression, index) => {
                 quote!( #expression [ #index ])
             }
+            Expression::AssemblyCall(function, args, location) => {
+                transform_location!(location, location);
+                match (function.as_str(), &args[..]) {
+                    ("extcodesize", [account]) => quote!( #location env().is_contract(& #account) ),
+                    ("selfbalance", _) => quote!( #location env().balance() ),
+                    // addresses are read as big endian words
+                    ("address_word", [account, Expression::Type(ty)]) => {
+                        match **ty {
+                            Type::Uint(256) => {
+                                quote!( U256::from_big_endian(AsRef::<[u8]>::as_ref(& #account)) )
+                            }
+                            Type::Uint(bits) => {
+                                let start = 32 - bits as usize / 8;
+                                quote!(
+                                    #ty ::from_be_bytes(
+                                        AsRef::<[u8]>::as_ref(& #account)[#start..].try_into().unwrap()
+                                    )
+                                )
+                            }
+                            _ => unreachable!("Addresses are converted to unsigned words"),
+                        }
+                    }
+                    ("mstore", [array, length]) => {
+                        quote!( #array .truncate(usize::try_from(#length).unwrap_or(usize::MAX)) )
+                    }
+                    // division by zero results in zero, the overflow of `sdiv(MIN, -1)` results in `MIN`
+                    ("sdiv", [left, right]) => {
+                        quote!({
+                            let (left, right) = (#left, #right);
+                            left.checked_div(right)
+                                .unwrap_or(if right == Default::default() { right } else { left })
+                        })
+                    }
+                    ("smod", [left, right]) => quote!( #left .checked_rem(#right).unwrap_or_default() ),
+                    ("revert", [data]) => {
+                        quote!(
+                            return Err(<Error as scale::Decode>::decode(&mut & #data [..])
+                                .unwrap_or(Error::Custom(String::from("Reverted in assembly"))))
+                        )
+                    }
+                    _ => unreachable!("Assembly call {function} can not be translated"),
+                }
+            }
+            // elements of lazy arrays are bounds checked before they are written
+            Expression::Assign(variable, value)
+            | Expression::AssignAdd(variable, value)
+            | Expression::AssignDivide(variable, value)
+            | Expression::AssignModulo(variable, value)
+            | Expression::AssignMultiply(variable, value)
+            | Expression::AssignSubtract(variable, value)
+                if let Expression::MappingSubscript(array, indices) = &**variable
+                    && let (Some((length, _)), [index]) =
+                        (lazy_array_length(array), &indices[..]) =>
+            {
+                let operator = match self {
+                    Expression::AssignAdd(..) => Some(quote!(+)),
+                    Expression::AssignDivide(..) => Some(quote!(/)),
+                    Expression::AssignModulo(..) => Some(quote!(%)),
+                    Expression::AssignMultiply(..) => Some(quote!(*)),
+                    Expression::AssignSubtract(..) => Some(quote!(-)),
+                    _ => None,
+                };
+                assemble_lazy_array_assign(array, &length, index, value, operator)
+            }
             Expression::Assign(variable, value) => {
                 match *variable.clone() {
                     Expression::MappingSubscript(mapping, indices) => {
@@ -1328,25 +2757,127 @@ impl ToTokens for Expression {
             Expression::BoolLiteral(value) => {
                 quote!(#value)
             }
+            Expression::Cast(ty, expression) => {
+                let expression = method_receiver(expression);
+                quote!( (#expression as #ty) )
+            }
+            Expression::Clone(expression) => {
+                let expression = method_receiver(expression);
+                quote!( #expression .clone() )
+            }
+            Expression::Checked(operation) => {
+                let (left, method, right) = arithmetic_method(operation, "checked");
+                quote!(
+                    #left . #method (#right).ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                )
+            }
+            Expression::ContractCall(contract, address, function, args, value) => {
+                let call = assemble_contract_call(contract, address, function, args, value);
+                quote!( #call? )
+            }
             Expression::Delete(expression) => {
-                match *expression.clone() {
+                match &**expression {
+                    // the elements of a lazy array can not be cleared, they become unreachable
+                    // once the length is reset and are overwritten by the next pushes
+                    array if let Some((length, _)) = lazy_array_length(array) => {
+                        quote!( #length = Default::default() )
+                    }
+                    Expression::MappingSubscript(array, indices)
+                        if let (Some((length, _)), [index]) =
+                            (lazy_array_length(array), &indices[..]) =>
+                    {
+                        quote!({
+                            let index = #index;
+                            if index >= #length {
+                                return Err(Error::Custom(String::from("Index out of bounds")))
+                            }
+                            #array .remove(&index);
+                        })
+                    }
                     Expression::MappingSubscript(mapping, indices) => {
                         quote! (#mapping .remove(&(#(#indices),*)) )
                     }
+                    Expression::ArraySubscript(..) | Expression::MemberAccess(..) => {
+                        quote!( #expression = Default::default() )
+                    }
                     _ => quote!(_comment_!("Deletion of storage member")),
                 }
             }
             Expression::Divide(left, right) => quote!( #left / #right ),
+            // the recovered address is stored in the first 20 bytes of `AccountId`,
+            // invalid signatures recover the zero address like in Solidity
+            Expression::EcRecover(digest, v, r, s, location) => {
+                transform_location!(location, location);
+                let v = parenthesized(v);
+                quote!({
+                    let mut signature = [0u8; 65];
+                    signature[..32].copy_from_slice(&#r);
+                    signature[32..64].copy_from_slice(&#s);
+                    signature[64] = #v % 27;
+                    #location env()
+                        .ecdsa_recover(&signature, &#digest)
+                        .and_then(|public_key| #location env().ecdsa_to_eth_address(&public_key))
+                        .map(|address| {
+                            let mut account = [0u8; 32];
+                            account[..20].copy_from_slice(&address);
+                            AccountId::from(account)
+                        })
+                        .unwrap_or(AccountId::from([0u8; 32]))
+                })
+            }
             Expression::Equal(left, right) => {
                 quote!(
                     #left == #right
                 )
             }
+            Expression::ExternalFunctionCall(function, args, returns) => {
+                let returns = assemble_return_type(returns);
+                quote!(
+                    ink::env::call::build_call::<ink::env::DefaultEnvironment>()
+                        .call(#function.0)
+                        .exec_input(
+                            ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(#function.1))
+                                #(.push_arg(#args))*
+                        )
+                        .returns::<Result<#returns, Error>>()
+                        .try_invoke()
+                        .map_err(|_| Error::Custom(String::from("Cross contract call failed")))?
+                        .map_err(|_| Error::Custom(String::from("Cross contract call failed")))??
+                )
+            }
+            Expression::FunctionPointer(function, arity) => {
+                let name = match &**function {
+                    Expression::Variable(name, MemberType::FunctionPrivate, _) => {
+                        format!("_{}", name.to_case(Snake))
+                    }
+                    Expression::Variable(name, ..) => format_expression(name, Snake),
+                    _ => unreachable!("Only functions are used as function pointers"),
+                };
+                let name = TokenStream::from_str(&name).unwrap();
+                // the type of the contract is inferred from the function pointer, which borrows it
+                // mutably or immutably depending on the mutability of the function type
+                match (&**function, arity) {
+                    // free functions do not take the contract
+                    (Expression::Variable(_, MemberType::None(_), _), Some(arity)) => {
+                        let args = (0..*arity)
+                            .map(|i| format_ident!("__{}", i))
+                            .collect::<Vec<_>>();
+                        quote!( |_, #(#args),*| #name( #(#args),* ) )
+                    }
+                    (_, Some(arity)) => {
+                        let args = (0..*arity)
+                            .map(|i| format_ident!("__{}", i))
+                            .collect::<Vec<_>>();
+                        quote!( |instance, #(#args),*| instance.#name( #(#args),* ) )
+                    }
+                    (_, None) => quote!( Self::#name ),
+                }
+            }
+            Expression::FunctionPointerCall(function, args, location) => {
+                quote!( (#function)( #location, #(#args),* )? )
+            }
             Expression::FunctionCall(function, args,value) => {
                 match *function.clone() {
-                    Expression::MemberAccess(_,name)if name == "decode" =>{
-                        quote!(  #function ( __comment__!(#(#args),*) )? )
-                    }
                     Expression::Variable(name, ..) if name == "require" => {
                         let condition = &args[0];
                         if args.len() > 1 {
@@ -1375,7 +2906,9 @@ impl ToTokens for Expression {
                             )
                         }
                     }
-                    Expression::Type(ty) if let Type::AccountId = *ty.clone() => {
+                    Expression::Type(ty)
+                        if let Type::AccountId | Type::PayableAccountId = *ty.clone() =>
+                    {
                         if args.len() > 1 {
                             unreachable!("Multiple parameters were provided to `address` call")
                         }
@@ -1396,9 +2929,37 @@ impl ToTokens for Expression {
                         }
                     }
                     Expression::Type(ty) => {
-                        match *ty {
-                            Type::DynamicBytes => quote!( Vec::<u8>::from ( #(#args),* ) ),
-                            _ => quote!( <#ty> :: from ( #(#args),* ) ),
+                        match (*ty, &args[..]) {
+                            (Type::DynamicBytes, _) => quote!( Vec::<u8>::from ( #(#args),* ) ),
+                            (Type::Uint(256), [Expression::NumberLiteral(number)])
+                                if number.parse::<u128>().is_err() =>
+                            {
+                                quote!( U256::from_dec_str(#number).unwrap() )
+                            }
+                            (Type::Uint(256), [Expression::HexLiteral(hex)]) => {
+                                let hex = hex.trim_start_matches("0x");
+                                quote!( U256::from_str_radix(#hex, 16).unwrap() )
+                            }
+                            (Type::Uint(256), [Expression::Cast(ty, condition)])
+                                if let Type::Uint(8) = **ty =>
+                            {
+                                let condition = method_receiver(condition);
+                                quote!( U256::from(#condition as u8) )
+                            }
+                            (Type::Uint(256), _) => quote!( U256::from ( #(#args),* ) ),
+                            (Type::Int(256), [Expression::NumberLiteral(number)])
+                                if number.parse::<u128>().is_err() =>
+                            {
+                                quote!( I256(U256::from_dec_str(#number).unwrap()) )
+                            }
+                            (Type::Int(256), [Expression::HexLiteral(hex)]) => {
+                                let hex = hex.trim_start_matches("0x");
+                                quote!( I256(U256::from_str_radix(#hex, 16).unwrap()) )
+                            }
+                            (Type::Int(256), _) => quote!( I256::from ( #(#args),* ) ),
+                            // values of contract types are the addresses of the contracts
+                            (Type::Contract(_), [address]) => quote!( #address ),
+                            (ty, _) => quote!( <#ty> :: from ( #(#args),* ) ),
                         }
                     }
                     Expression::Variable(name, ..) if name == "type" => {
@@ -1418,13 +2979,75 @@ impl ToTokens for Expression {
                     }
                 }
             }
+            Expression::Length(array) => {
+                match lazy_array_length(array) {
+                    Some((length, _)) => quote!( #length ),
+                    None => quote!( #array .len() ),
+                }
+            }
             Expression::Less(left, right) => {
                 quote!( #left < #right )
             }
             Expression::LessEqual(left, right) => {
                 quote!( #left <= #right )
             }
+            Expression::LibraryCall(library, function, args) => {
+                let library = TokenStream::from_str(&library.to_case(Snake)).unwrap();
+                let function = TokenStream::from_str(&format_expression(function, Snake)).unwrap();
+                quote!( libs:: #library :: #function ( #(#args),* )? )
+            }
+            Expression::LowLevelCall(call_type, address, selector, args, value, location) => {
+                transform_location!(location, location);
+                // ink! has no read-only calls, so `staticcall` is a normal call
+                let callee = match call_type {
+                    CallType::DelegateCall => {
+                        quote!( .delegate(#location env().code_hash(&#address).unwrap_or_default()) )
+                    }
+                    CallType::Call | CallType::StaticCall => quote!( .call(#address) ),
+                };
+                let value = value.as_ref().map(|value| quote!( .transferred_value(#value) ));
+                quote!({
+                    /// The output of the called message, kept encoded
+                    struct Output(Vec<u8>);
+                    _blank_!();
+                    impl scale::Decode for Output {
+                        fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+                            let mut data = Vec::new();
+                            while let Ok(byte) = input.read_byte() {
+                                data.push(byte);
+                            }
+                            Ok(Output(data))
+                        }
+                    }
+                    _blank_!();
+                    _comment_!("the messages return a `Result`, the returned data is the encoded `Ok` value");
+                    match ink::env::call::build_call::<ink::env::DefaultEnvironment>()
+                        #callee
+                        #value
+                        .exec_input(
+                            ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(#selector))
+                                #(.push_arg(#args))*
+                        )
+                        .returns::<Output>()
+                        .try_invoke()
+                    {
+                        Ok(Ok(Output(data))) if data.first() == Some(&0) => (true, data[1..].to_vec()),
+                        _ => (false, Vec::<u8>::new()),
+                    }
+                })
+            }
             Expression::List(list) => quote!( (#(#list),*) ),
+            Expression::MappingSubscript(array, indices)
+                if let (Some((length, _)), [index]) = (lazy_array_length(array), &indices[..]) =>
+            {
+                quote!({
+                    let index = #index;
+                    if index >= #length {
+                        return Err(Error::Custom(String::from("Index out of bounds")))
+                    }
+                    #array .get(&index).unwrap_or_default()
+                })
+            }
             Expression::MappingSubscript(array, indices) => {
                 if indices.len() > 1 {
                     quote! (#array.get(&(#(#indices),*)).unwrap_or_default())
@@ -1440,9 +3063,10 @@ impl ToTokens for Expression {
                             "sender" => quote!(#location env().caller()),
                             "value" => quote!(#location env().transferred_value()),
                             "gas" => quote!(#location env().gas_left()),
-                            "sig" => todo!("Function selector (msg.sig)"),
-                            "data" => todo!("Function data (msg.data)"),
-                            _ => panic!("msg.{member} is not implemented!"),
+                            _ => {
+                                let global = format!("Unknown global: msg.{member}");
+                                quote!( __comment__!(#global) )
+                            }
                         }
                     }
                     Expression::Variable(name, _,location) if name == "block" => {
@@ -1450,14 +3074,13 @@ impl ToTokens for Expression {
                         match member.as_str() {
                             "number" => quote!(#location env().block_number()),
                             "timestamp" => quote!(#location env().block_timestamp()),
-                            "basefee" => todo!("block.basefee"),
-                            "chainid" => todo!("block.chainid"),
-                            "coinbase" => todo!("block.coinbase"),
-                            "difficulty" => todo!("block.difficulty"),
-                            "gaslimit" => todo!("block.gaslimit"),
-                            _ => panic!("block.{member} is not implemented!"),
+                            _ => {
+                                let global = format!("Unknown global: block.{member}");
+                                quote!( __comment__!(#global) )
+                            }
                         }
                     }
+                    _ if let Some(bound) = integer_bound(left, member) => bound,
                     _ => {
                         let ident = TokenStream::from_str(&member.to_case(Snake)).unwrap();
                         quote!( #left . #ident)
@@ -1512,6 +3135,27 @@ impl ToTokens for Expression {
             Expression::Parenthesis(expression) => {
                 quote! ( (#expression) )
             }
+            Expression::Pop(array) => {
+                match lazy_array_length(array) {
+                    Some((length, ty)) => {
+                        let one = match ty {
+                            Type::Uint(256) => quote!(U256::from(1)),
+                            _ => quote!(1),
+                        };
+                        quote!({
+                            let length = #length .checked_sub(#one)
+                                .ok_or(Error::Custom(String::from("Pop from empty array")))?;
+                            #array .remove(&length);
+                            #length = length;
+                        })
+                    }
+                    None => {
+                        quote!(
+                            #array .pop().ok_or(Error::Custom(String::from("Pop from empty array")))?
+                        )
+                    }
+                }
+            }
             Expression::PostDecrement(expression) => {
                 quote!(
                     #expression -= 1
@@ -1533,10 +3177,48 @@ impl ToTokens for Expression {
                     #expression += 1
                 )
             }
+            Expression::Reference(expression) => quote!( &#expression ),
+            Expression::MutableReference(expression) => quote!( &mut #expression ),
+            Expression::Selector(name) => quote!( ink::selector_bytes!(#name) ),
+            Expression::Push(array, value) => {
+                let value = match value {
+                    Some(value) => quote!( #value ),
+                    None => quote!(Default::default()),
+                };
+                match lazy_array_length(array) {
+                    Some((length, ty)) => {
+                        let one = match ty {
+                            Type::Uint(256) => quote!(U256::from(1)),
+                            _ => quote!(1),
+                        };
+                        quote!({
+                            let (length, value) = (#length, #value);
+                            #array .insert(&length, &value);
+                            #length = length + #one;
+                        })
+                    }
+                    None => quote!( #array .push(#value) ),
+                }
+            }
+            Expression::Send(to, amount, location) => {
+                transform_location!(location, location);
+                quote!( #location env().transfer(#to, #amount).is_ok() )
+            }
             Expression::StringLiteral(strings) => {
                 let joined = &strings.join(" ");
                 quote!(#joined)
             }
+            // values without an equivalent in ink! are marked for review
+            Expression::Substitute(note, ty, value) => {
+                let value = match value {
+                    Some(value) => quote!( #value ),
+                    None => quote!( <#ty>::default() ),
+                };
+                quote!({
+                    _comment_!(#note);
+                    #value
+                })
+            }
             Expression::Subtract(left, right) => {
                 quote!(
                     #left - #right
@@ -1545,6 +3227,71 @@ impl ToTokens for Expression {
             Expression::Ternary(condition, if_true, if_false) => {
                 quote!( if #condition { #if_true } else { #if_false } )
             }
+            Expression::Wrapping(operation) => {
+                // `U256` has no wrapping operations, but both `U256` and Rust integers have overflowing ones
+                let (left, method, right) = arithmetic_method(operation, "overflowing");
+                quote!( #left . #method (#right).0 )
+            }
+            // the copies of storage values are borrowed by the call and written back after it
+            Expression::WriteBack(copies, call, write_backs) => {
+                quote!({
+                    #(#copies)*
+                    let result = #call;
+                    #(#write_backs)*
+                    result
+                })
+            }
+            // hashes of constant strings, like the roles of access control, are computed while transpiling,
+            // so they can be used in constants
+            Expression::Hash(function, input, _) if let Some(value) = constant_string(input) => {
+                let hash = match function {
+                    HashFunction::Keccak256 => {
+                        let mut output = [0u8; 32];
+                        let mut keccak = Keccak::v256();
+                        keccak.update(value.as_bytes());
+                        keccak.finalize(&mut output);
+                        output.to_vec()
+                    }
+                    HashFunction::Sha256 => Sha256::digest(value.as_bytes()).to_vec(),
+                };
+                let bytes = hash
+                    .iter()
+                    .map(|byte| TokenStream::from_str(&format!("0x{byte:02x}")).unwrap());
+                quote!( [#(#bytes),*] )
+            }
+            Expression::Hash(function, input, location) => {
+                transform_location!(location, location);
+                let hash = match function {
+                    HashFunction::Keccak256 => quote!(ink::env::hash::Keccak256),
+                    HashFunction::Sha256 => quote!(ink::env::hash::Sha2x256),
+                };
+                let input = match **input {
+                    Expression::StringLiteral(_) => quote!( #input.as_bytes() ),
+                    _ => quote!( &#input ),
+                };
+                quote!( #location env().hash_bytes::<#hash>(#input) )
+            }
+            Expression::Transfer(to, amount, location) => {
+                transform_location!(location, location);
+                quote!(
+                    #location env().transfer(#to, #amount).map_err(|_| Error::Custom(String::from("Transfer failed")))?
+                )
+            }
+            Expression::Balance(expression) => {
+                // amounts which do not fit the balance revert instead of being truncated
+                quote!(
+                    u128::try_from(#expression).map_err(|_| Error::Custom(String::from("Amount exceeds the balance type")))?
+                )
+            }
+            Expression::Truncate(ty, expression) => {
+                let expression = method_receiver(expression);
+                match **ty {
+                    Type::Uint(128) => quote!( #expression .low_u128() ),
+                    // indices out of bounds revert
+                    Type::Usize => quote!( #expression .as_usize() ),
+                    _ => quote!( (#expression .low_u128() as #ty) ),
+                }
+            }
             Expression::Type(ty) => quote!( #ty ),
             Expression::Variable(name, member_type,location) => {
                 match member_type {
@@ -1573,7 +3320,7 @@ impl ToTokens for Expression {
                     MemberType::None(_) => TokenStream::from_str(&format_expression(name, Snake)).unwrap(),
                 }
             }
-            Expression::VariableDeclaration(ty, name) => {
+            Expression::VariableDeclaration(ty, name, _) => {
                 let name = TokenStream::from_str(name).unwrap();
                 quote!(let mut #name : #ty)
             }
@@ -1647,6 +3394,47 @@ impl ToTokens for Expression {
                 )
             }
             Expression::ArrayLiteral(expressions) => quote!(vec![#(#expressions),*]),
+            Expression::Instantiate(_, code_hash, args, value, salt) => {
+                // contracts created without a salt share the empty salt
+                let value = match value {
+                    Some(value) => quote!( #value ),
+                    None => quote!(0),
+                };
+                let salt = match salt {
+                    Some(salt) => quote!( #salt ),
+                    None => quote!([0u8; 0]),
+                };
+                // the created contract is referred to by its address, so the crate of the contract is not needed
+                quote!({
+                    /// The address of the created contract
+                    struct Created(AccountId);
+                    _blank_!();
+                    impl ink::env::ContractEnv for Created {
+                        type Env = ink::env::DefaultEnvironment;
+                    }
+                    _blank_!();
+                    impl ink::env::call::FromAccountId<ink::env::DefaultEnvironment> for Created {
+                        fn from_account_id(account_id: AccountId) -> Self {
+                            Created(account_id)
+                        }
+                    }
+                    _blank_!();
+                    ink::env::call::build_create::<Created>()
+                        .code_hash(#code_hash.into())
+                        .gas_limit(0)
+                        .endowment(#value)
+                        .exec_input(
+                            ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(ink::selector_bytes!("new")))
+                                #(.push_arg(#args))*
+                        )
+                        .salt_bytes(#salt)
+                        .returns::<Created>()
+                        .try_instantiate()
+                        .map_err(|_| Error::Custom(String::from("Contract instantiation failed")))?
+                        .map_err(|_| Error::Custom(String::from("Contract instantiation failed")))?
+                        .0
+                })
+            }
             Expression::InvalidModifier(_, _) => quote!(),
             Expression::This(_) => quote!(),
             Expression::UnaryMinus(expression) => {
@@ -1665,6 +3453,11 @@ impl ToTokens for Expression {
             Expression::ArraySlice(expression, start, end) => {
                 quote!( #expression[#start..#end] )
             }
+            Expression::Untranslatable(source) => {
+                let source = format!("Untranslatable assembly: {source}");
+                quote!( __comment__!(#source) )
+            }
+            Expression::Unsupported(note) => quote!( __comment__!(#note) ),
             Expression::None => quote!()
         })
     }
@@ -1710,6 +3503,22 @@ impl ToTokens for Import {
                     pub use openbrush::traits::AccountId;
                 )
             }
+            Import::Globals(globals) | Import::ImportedGlobals(globals) => {
+                let globals = format_ident!("{}", globals);
+                quote!(
+                    pub use crate::libs:: #globals ::*;
+                )
+            }
+            Import::I256 => {
+                quote!(
+                    pub use crate::libs::i_256::I256;
+                )
+            }
+            Import::Libs => {
+                quote!(
+                    pub use crate::libs;
+                )
+            }
             Import::Mapping => {
                 quote!(
                     pub use openbrush::storage::Mapping;
@@ -1720,6 +3529,11 @@ impl ToTokens for Import {
                     pub use openbrush::traits::String;
                 )
             }
+            Import::U256 => {
+                quote!(
+                    pub use primitive_types::U256;
+                )
+            }
             Import::Vec => {
                 quote!(
                     pub use ink::prelude::vec::*;
@@ -1734,3 +3548,275 @@ impl ToTokens for Import {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_format::{
+        Formatter,
+        RustFmt,
+    };
+
+    #[test]
+    fn constant_hashes() {
+        let role = Expression::Hash(
+            HashFunction::Keccak256,
+            Box::new(Expression::StringLiteral(vec![String::from("MINTER_ROLE")])),
+            VariableAccessLocation::Any,
+        );
+        let expected = TokenStream::from_str(
+            "[0x9f, 0x2d, 0xf0, 0xfe, 0xd2, 0xc7, 0x76, 0x48, 0xde, 0x58, 0x60, 0xa4, 0xcc, 0x50, 0x8c, 0xd0, \
+             0x81, 0x8c, 0x85, 0xb8, 0xb8, 0xa1, 0xab, 0x4c, 0xee, 0xef, 0x8d, 0x98, 0x1c, 0x89, 0x56, 0xa6]",
+        )
+        .unwrap();
+        assert_eq!(quote!(#role).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn reverts_end_the_statement() {
+        let reverts = [
+            Statement::Revert(String::from("Unauthorized"), Vec::default()),
+            Statement::Revert(
+                String::from("InsufficientBalance"),
+                vec![Expression::NumberLiteral(String::from("1"))],
+            ),
+            Statement::RevertNamedArgs(
+                String::from("InsufficientBalance"),
+                vec![(
+                    String::from("needed"),
+                    Expression::NumberLiteral(String::from("1")),
+                )],
+            ),
+        ];
+        let expected = [
+            "return Err(Error::Unauthorized);",
+            "return Err(Error::InsufficientBalance(1));",
+            "return Err(Error::InsufficientBalance { needed: 1 });",
+        ];
+        for (revert, expected) in reverts.iter().zip(expected) {
+            assert_eq!(
+                quote!(#revert).to_string(),
+                TokenStream::from_str(expected).unwrap().to_string()
+            );
+        }
+
+        // an unconditional revert followed by other statements is valid Rust
+        let block = Statement::Block(vec![
+            reverts[0].clone(),
+            Statement::Expression(Expression::NumberLiteral(String::from("1"))),
+        ]);
+        assert!(RustFmt::default()
+            .format_tokens(quote!(fn f() { #block }))
+            .is_ok());
+    }
+
+    #[test]
+    fn packed_integers() {
+        let variable = |name: &str| {
+            Expression::Variable(
+                String::from(name),
+                MemberType::None(Box::new(Type::None)),
+                VariableAccessLocation::Any,
+            )
+        };
+        let packed = [
+            assemble_packed_value(&variable("a"), &Type::Uint(128)),
+            assemble_packed_value(&variable("b"), &Type::Uint(96)),
+            assemble_packed_value(&variable("c"), &Type::Uint(32)),
+            assemble_packed_value(&variable("d"), &Type::Uint(160)),
+        ];
+        let expected = [
+            "(a as u128).to_be_bytes().to_vec()",
+            "(b as u128).to_be_bytes()[4..].to_vec()",
+            "(c as u32).to_be_bytes().to_vec()",
+            "{ let mut bytes = [0u8; 32]; d.to_big_endian(&mut bytes); bytes[12..].to_vec() }",
+        ];
+        for (packed, expected) in packed.iter().zip(expected) {
+            assert_eq!(
+                packed.to_string(),
+                TokenStream::from_str(expected).unwrap().to_string()
+            );
+        }
+
+        // `abi.encodePacked(uint128(0), uint96(0), uint32(1))` is the word of `abi.encode(uint256(1))`
+        let (a, b, c) = (0u128, 0u128, 1u32);
+        let bytes = [
+            a.to_be_bytes().to_vec(),
+            b.to_be_bytes()[4..].to_vec(),
+            c.to_be_bytes().to_vec(),
+        ]
+        .concat();
+        let mut hash = [0u8; 32];
+        let mut keccak = Keccak::v256();
+        keccak.update(&bytes);
+        keccak.finalize(&mut hash);
+        assert_eq!(
+            hash.iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>(),
+            "b10e2d527612073b26eecdfd717e6a320cf44b4afac2b0732d9fcbe2b7fa0cf6"
+        );
+    }
+
+    #[test]
+    fn lazy_arrays() {
+        let orders = Box::new(Expression::Variable(
+            String::from("orders"),
+            MemberType::Variable(Box::new(Type::LazyArray(
+                Box::new(Type::Uint(256)),
+                Box::new(Type::Uint(256)),
+            ))),
+            VariableAccessLocation::Any,
+        ));
+        let value = Box::new(Expression::Variable(
+            String::from("value"),
+            MemberType::Variable(Box::new(Type::Uint(256))),
+            VariableAccessLocation::Any,
+        ));
+        let element = Box::new(Expression::MappingSubscript(
+            orders.clone(),
+            vec![Expression::NumberLiteral(String::from("1"))],
+        ));
+        let statements = [
+            Statement::Expression(Expression::Push(orders.clone(), Some(value.clone()))),
+            Statement::Expression(Expression::Pop(orders.clone())),
+            Statement::Expression(Expression::Assign(element.clone(), value.clone())),
+            Statement::Expression(Expression::AssignAdd(element.clone(), value.clone())),
+            Statement::Expression(Expression::Delete(element.clone())),
+            Statement::Expression(Expression::Delete(orders)),
+            Statement::Return(Some(*element)),
+        ];
+        let expected = [
+            "{ \
+                let (length, value) = (self.data().orders_length, self.data().value); \
+                self.data().orders.insert(&length, &value); \
+                self.data().orders_length = length + U256::from(1); \
+            };",
+            "{ \
+                let length = self.data().orders_length.checked_sub(U256::from(1)) \
+                    .ok_or(Error::Custom(String::from(\"Pop from empty array\")))?; \
+                self.data().orders.remove(&length); \
+                self.data().orders_length = length; \
+            };",
+            "{ \
+                let (index, value) = (1, self.data().value); \
+                if index >= self.data().orders_length { \
+                    return Err(Error::Custom(String::from(\"Index out of bounds\"))) \
+                } \
+                self.data().orders.insert(&index, &value); \
+            };",
+            "{ \
+                let (index, value) = (1, self.data().value); \
+                if index >= self.data().orders_length { \
+                    return Err(Error::Custom(String::from(\"Index out of bounds\"))) \
+                } \
+                let value = self.data().orders.get(&index).unwrap_or_default() + value; \
+                self.data().orders.insert(&index, &value); \
+            };",
+            "{ \
+                let index = 1; \
+                if index >= self.data().orders_length { \
+                    return Err(Error::Custom(String::from(\"Index out of bounds\"))) \
+                } \
+                self.data().orders.remove(&index); \
+            };",
+            "self.data().orders_length = Default::default();",
+            "return Ok({ \
+                let index = 1; \
+                if index >= self.data().orders_length { \
+                    return Err(Error::Custom(String::from(\"Index out of bounds\"))) \
+                } \
+                self.data().orders.get(&index).unwrap_or_default() \
+            })",
+        ];
+        for (statement, expected) in statements.iter().zip(expected) {
+            // the spacing of the tokens depends on how they were quoted
+            assert_eq!(
+                quote!(#statement).to_string().replace(' ', ""),
+                expected.replace(' ', "")
+            );
+        }
+
+        let block = Statement::Block(statements.to_vec());
+        assert!(RustFmt::default()
+            .format_tokens(quote!(fn f() { #block }))
+            .is_ok());
+
+        // mappings have no length to check against
+        let balances = Expression::MappingSubscript(
+            Box::new(Expression::Variable(
+                String::from("balances"),
+                MemberType::Variable(Box::new(Type::Mapping(
+                    vec![Type::Uint(256)],
+                    Box::new(Type::Uint(256)),
+                ))),
+                VariableAccessLocation::Any,
+            )),
+            vec![Expression::NumberLiteral(String::from("1"))],
+        );
+        assert_eq!(
+            quote!(#balances).to_string().replace(' ', ""),
+            "self.data().balances.get(&1).unwrap_or_default()"
+        );
+    }
+
+    #[test]
+    fn yul_words() {
+        let variable = |name: &str| {
+            Expression::Variable(
+                String::from(name),
+                MemberType::None(Box::new(Type::None)),
+                VariableAccessLocation::Any,
+            )
+        };
+        let word = |ty: Type, expression: Expression| {
+            Expression::FunctionCall(
+                Box::new(Expression::Type(Box::new(ty))),
+                vec![expression],
+                None,
+            )
+        };
+        let address_word = |ty: Type| {
+            Expression::AssemblyCall(
+                String::from("address_word"),
+                vec![variable("who"), Expression::Type(Box::new(ty))],
+                VariableAccessLocation::Any,
+            )
+        };
+        let words = [
+            word(
+                Type::Uint(256),
+                Expression::HexLiteral(String::from("0xff")),
+            ),
+            word(
+                Type::Uint(256),
+                Expression::Cast(Box::new(Type::Uint(8)), Box::new(variable("flag"))),
+            ),
+            word(
+                Type::Uint(256),
+                Expression::Cast(
+                    Box::new(Type::Uint(8)),
+                    Box::new(Expression::Less(
+                        Box::new(variable("a")),
+                        Box::new(variable("b")),
+                    )),
+                ),
+            ),
+            address_word(Type::Uint(256)),
+            address_word(Type::Uint(128)),
+        ];
+        let expected = [
+            "U256::from_str_radix(\"ff\", 16).unwrap()",
+            "U256::from(flag as u8)",
+            "U256::from((a < b) as u8)",
+            "U256::from_big_endian(AsRef::<[u8]>::as_ref(&who))",
+            "u128::from_be_bytes(AsRef::<[u8]>::as_ref(&who)[16usize..].try_into().unwrap())",
+        ];
+        for (word, expected) in words.iter().zip(expected) {
+            assert_eq!(
+                quote!(#word).to_string().replace(' ', ""),
+                expected.replace(' ', "")
+            );
+        }
+    }
+}
@@ -20,6 +20,10 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::{
+    import_resolver::Remapping,
+    structures::AbiEncoding,
+};
 use clap::{
     command,
     Parser,
@@ -39,6 +43,24 @@ pub struct Args {
     /// Path to files or directories
     #[arg(value_parser = sol_file_parser)]
     pub(crate) files: Option<Vec<CliInput>>,
+    /// Remapping of imported paths, like `@openzeppelin/=lib/openzeppelin-contracts/`
+    #[arg(long = "remap")]
+    pub(crate) remappings: Vec<Remapping>,
+    /// Directories searched for imported files
+    #[arg(long = "include", short = 'I')]
+    pub(crate) include_paths: Vec<String>,
+    /// Narrow integers wider than 128 bits to `u128` and `i128` instead of using `U256`
+    #[arg(long = "narrow-integers")]
+    pub(crate) narrow_integers: bool,
+    /// Storage arrays stored as a mapping with a separate length, as `field` or `Contract.field`
+    #[arg(long = "lazy-array")]
+    pub(crate) lazy_arrays: Vec<String>,
+    /// Encoding of the values encoded and decoded with the `abi` functions, `scale` or `ethereum`
+    #[arg(long = "abi-encoding", default_value = "scale")]
+    pub(crate) abi_encoding: AbiEncoding,
+    /// Chain id substituted for `block.chainid`, which is not available in ink!
+    #[arg(long = "chain-id")]
+    pub(crate) chain_id: Option<u64>,
 }
 
 fn sol_file_parser(s: &str) -> Result<CliInput, String> {
@@ -20,7 +20,18 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::toml_builder;
+use crate::{
+    import_resolver::{
+        normalize_path,
+        ImportResolver,
+    },
+    parser::{
+        file_imports,
+        ParserError,
+    },
+    structures::AbiEncoding,
+    toml_builder,
+};
 use convert_case::{
     Case::Snake,
     Casing,
@@ -33,6 +44,7 @@ use rust_format::{
     RustFmt,
 };
 use std::{
+    collections::HashSet,
     fs::{
         create_dir_all,
         metadata,
@@ -42,7 +54,10 @@ use std::{
         prelude::*,
         BufReader,
     },
-    path::Path,
+    path::{
+        Path,
+        PathBuf,
+    },
 };
 
 static CONTRACTS_DIR: &str = "/generated/contracts";
@@ -102,6 +117,56 @@ pub fn get_solidity_files_from_directory(dir: &str) -> std::io::Result<Vec<Strin
     Ok(paths)
 }
 
+/// Orders the files so every file comes after the files it imports,
+/// imported files which were not provided are added to the list
+///
+/// `paths` the paths to the files we want to parse
+/// `resolver` the resolver of the imported paths
+pub fn order_by_imports(
+    paths: &[String],
+    resolver: &ImportResolver,
+) -> Result<Vec<String>, ParserError> {
+    let mut visited = HashSet::new();
+    let mut ordered = Vec::default();
+    for path in paths {
+        visit_imports(
+            normalize_path(Path::new(path)),
+            resolver,
+            &mut visited,
+            &mut ordered,
+        )?;
+    }
+    Ok(ordered)
+}
+
+/// Adds the files imported by a file to the ordered list, followed by the file itself
+///
+/// `path` the path to the file
+/// `resolver` the resolver of the imported paths
+/// `visited` the files which were already visited, imports may be cyclic
+/// `ordered` the ordered list of files
+fn visit_imports(
+    path: PathBuf,
+    resolver: &ImportResolver,
+    visited: &mut HashSet<PathBuf>,
+    ordered: &mut Vec<String>,
+) -> Result<(), ParserError> {
+    if !visited.insert(path.clone()) {
+        return Ok(())
+    }
+    let path = path.to_str().unwrap().to_owned();
+    let content = read_file(&path)?;
+    for import in file_imports(&path, &content, resolver)? {
+        if import.is_file() {
+            visit_imports(import, resolver, visited, ordered)?;
+        } else {
+            println!("Imported file {} not found", import.display());
+        }
+    }
+    ordered.push(path);
+    Ok(())
+}
+
 /// writes the output trait to a file
 ///
 /// `tokens` the transpiled file in the form of TokenStream
@@ -131,12 +196,14 @@ pub fn write_trait(
 /// `traits` the mod file of the traits folder in the form of TokenStream
 /// `libs` the mod file of the libs folder in the form of TokenStream
 /// `lib` the main lib file in the form of TokenStream
+/// `abi_encoding` the encoding of the values encoded and decoded with the `abi` functions
 pub fn write_mod_files(
     file_home: &str,
     impls: TokenStream,
     traits: TokenStream,
     libs: TokenStream,
     lib: TokenStream,
+    abi_encoding: AbiEncoding,
 ) -> std::io::Result<()> {
     let config = Config::new_str().post_proc(PostProcess::ReplaceMarkersAndDocBlocks);
 
@@ -173,7 +240,8 @@ pub fn write_mod_files(
     )?;
 
     let mut main_cargo_toml = File::create(format!("{file_home}/generated/src/Cargo.toml"))?;
-    main_cargo_toml.write_all(toml_builder::generate_cargo_toml("generated", None).as_bytes())?;
+    main_cargo_toml
+        .write_all(toml_builder::generate_cargo_toml("generated", None, abi_encoding).as_bytes())?;
 
     Ok(())
 }
@@ -207,12 +275,14 @@ pub fn write_library(
 /// `trait_definition` the trait definition file of ink! contract in the for of TokenStream
 /// `contract_name_raw` the name of the original contract
 /// `home_path` the home directory of the file we are parsing, or the directory we are parsing
+/// `abi_encoding` the encoding of the values encoded and decoded with the `abi` functions
 pub fn write_contract_files(
     contract: TokenStream,
     implementation: TokenStream,
     trait_definition: TokenStream,
     contract_name_raw: &String,
     home_path: &str,
+    abi_encoding: AbiEncoding,
 ) -> std::io::Result<()> {
     let contract_name = contract_name_raw.to_case(Snake);
     let config = Config::new_str().post_proc(PostProcess::ReplaceMarkersAndDocBlocks);
@@ -227,8 +297,12 @@ pub fn write_contract_files(
 
     let mut cargo_toml = File::create(format!("{contract_folder_path}/Cargo.toml"))?;
     cargo_toml.write_all(
-        toml_builder::generate_cargo_toml(&contract_name, Some(String::from("generated")))
-            .as_bytes(),
+        toml_builder::generate_cargo_toml(
+            &contract_name,
+            Some(String::from("generated")),
+            abi_encoding,
+        )
+        .as_bytes(),
     )?;
 
     // impl
@@ -0,0 +1,265 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use std::{
+    env,
+    fs,
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
+};
+
+static REMAPPINGS_FILE: &str = "remappings.txt";
+static FOUNDRY_CONFIG_FILE: &str = "foundry.toml";
+static FOUNDRY_PROFILE: &str = "FOUNDRY_PROFILE";
+
+/// A remapping of an import prefix to a directory, like `@openzeppelin/=lib/openzeppelin-contracts/`
+/// The context of a remapping (`context:prefix=target`) is ignored
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Remapping {
+    pub prefix: String,
+    pub target: String,
+}
+
+impl FromStr for Remapping {
+    type Err = String;
+
+    fn from_str(remapping: &str) -> Result<Self, Self::Err> {
+        let (prefix, target) = remapping
+            .trim()
+            .split_once('=')
+            .ok_or_else(|| format!("{remapping} is not a remapping of the form prefix=target"))?;
+        let prefix = prefix.split_once(':').map_or(prefix, |(_, prefix)| prefix);
+        if prefix.is_empty() {
+            return Err(format!("{remapping} has an empty prefix"))
+        }
+        Ok(Remapping {
+            prefix: prefix.to_owned(),
+            target: target.to_owned(),
+        })
+    }
+}
+
+/// Resolves the paths of imported files
+/// Paths starting with `.` are relative to the importing file, other paths are remapped
+/// and looked up in the root of the project and in the include paths
+#[derive(Clone, Debug, Default)]
+pub struct ImportResolver {
+    root: PathBuf,
+    remappings: Vec<Remapping>,
+    include_paths: Vec<PathBuf>,
+}
+
+impl ImportResolver {
+    /// Creates a resolver of the project in `root`, the remappings and include paths
+    /// defined in `remappings.txt` and `foundry.toml` of the project are added to the provided ones
+    ///
+    /// `root` the root directory of the project
+    /// `remappings` the remappings provided by the user, relative to the root
+    /// `include_paths` the directories searched for imported files, relative to the root
+    pub fn new(root: &str, remappings: &[Remapping], include_paths: &[String]) -> Self {
+        let root = PathBuf::from(root);
+        let mut resolver = ImportResolver {
+            remappings: remappings.to_vec(),
+            include_paths: include_paths.iter().map(|path| root.join(path)).collect(),
+            root,
+        };
+
+        if let Ok(content) = fs::read_to_string(resolver.root.join(REMAPPINGS_FILE)) {
+            resolver.remappings.extend(
+                content
+                    .lines()
+                    .filter_map(|remapping| Remapping::from_str(remapping).ok()),
+            );
+        }
+        if let Ok(content) = fs::read_to_string(resolver.root.join(FOUNDRY_CONFIG_FILE)) {
+            let profile = env::var(FOUNDRY_PROFILE).unwrap_or_else(|_| String::from("default"));
+            resolver.remappings.extend(
+                foundry_string_array(&content, &profile, "remappings")
+                    .iter()
+                    .filter_map(|remapping| Remapping::from_str(remapping).ok()),
+            );
+            resolver.include_paths.extend(
+                foundry_string_array(&content, &profile, "libs")
+                    .iter()
+                    .map(|path| resolver.root.join(path)),
+            );
+        }
+
+        resolver
+    }
+
+    /// Resolves the path of an imported file
+    ///
+    /// `path` the path to the importing file
+    /// `import` the imported path
+    pub fn resolve(&self, path: &str, import: &str) -> PathBuf {
+        if import.starts_with('.') {
+            let directory = Path::new(path).parent().unwrap_or_else(|| Path::new(""));
+            return normalize_path(&directory.join(import))
+        }
+
+        // the remapping with the longest matching prefix is applied, of remappings with the same
+        // prefix the first one wins, so the remappings of the user override those of the project
+        let remapped = self
+            .remappings
+            .iter()
+            .rev()
+            .filter(|remapping| import.starts_with(&remapping.prefix))
+            .max_by_key(|remapping| remapping.prefix.len())
+            .map(|remapping| format!("{}{}", remapping.target, &import[remapping.prefix.len()..]));
+        let import = remapped.as_deref().unwrap_or(import);
+
+        let in_root = normalize_path(&self.root.join(import));
+        if in_root.is_file() {
+            return in_root
+        }
+        self.include_paths
+            .iter()
+            .map(|include_path| normalize_path(&include_path.join(import)))
+            .find(|path| path.is_file())
+            .unwrap_or(in_root)
+    }
+}
+
+/// Normalizes a path without accessing the file system, so `a/./b/../c.sol` becomes `a/c.sol`
+///
+/// `path` the path to normalize
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Returns the strings of an array in the Foundry config, like `remappings = ["a=b", "c=d"]`
+/// The array is read from `[profile.<profile>]`, falling back to `[profile.default]` like Foundry does
+///
+/// `content` the content of the toml file
+/// `profile` the selected Foundry profile
+/// `key` the key of the array
+fn foundry_string_array(content: &str, profile: &str, key: &str) -> Vec<String> {
+    let Ok(config) = content.parse::<toml::Table>() else {
+        return Vec::default()
+    };
+    let Some(profiles) = config.get("profile").and_then(toml::Value::as_table) else {
+        return Vec::default()
+    };
+    [profile, "default"]
+        .iter()
+        .find_map(|profile| profiles.get(*profile)?.get(key)?.as_array())
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(toml::Value::as_str)
+                .map(str::to_owned)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn foundry_config_arrays() {
+        let config = r#"
+            # remappings = ["ignored/=ignored/"]
+            [profile.default]
+            src = "src"
+            libs = ["lib", "node_modules"] # the dependencies
+            remappings = [
+                "@openzeppelin/=lib/openzeppelin-contracts/", # OpenZeppelin
+                "solmate/=lib/solmate/src/",
+                "hash#tag/=lib/hash#tag/",
+            ]
+
+            [profile.ci]
+            remappings = ["@openzeppelin/=lib/oz/"]
+
+            [fmt]
+            remappings = ["fmt/=fmt/"]
+        "#;
+
+        assert_eq!(
+            foundry_string_array(config, "default", "remappings"),
+            vec![
+                "@openzeppelin/=lib/openzeppelin-contracts/",
+                "solmate/=lib/solmate/src/",
+                "hash#tag/=lib/hash#tag/",
+            ]
+        );
+        assert_eq!(
+            foundry_string_array(config, "default", "libs"),
+            vec!["lib", "node_modules"]
+        );
+        // the selected profile falls back to the default profile
+        assert_eq!(
+            foundry_string_array(config, "ci", "remappings"),
+            vec!["@openzeppelin/=lib/oz/"]
+        );
+        assert_eq!(
+            foundry_string_array(config, "ci", "libs"),
+            vec!["lib", "node_modules"]
+        );
+    }
+
+    #[test]
+    fn first_longest_remapping_wins() {
+        let remappings = [
+            "@openzeppelin/=lib/user/",
+            "@openzeppelin/=lib/project/",
+            "@openzeppelin/contracts/=lib/contracts/",
+            "@=lib/short/",
+        ]
+        .map(|remapping| Remapping::from_str(remapping).unwrap());
+        let resolver = ImportResolver::new("", &remappings, &[]);
+
+        assert_eq!(
+            resolver.resolve("A.sol", "@openzeppelin/token/ERC20.sol"),
+            PathBuf::from("lib/user/token/ERC20.sol")
+        );
+        assert_eq!(
+            resolver.resolve("A.sol", "@openzeppelin/contracts/token/ERC20.sol"),
+            PathBuf::from("lib/contracts/token/ERC20.sol")
+        );
+    }
+
+    #[test]
+    fn foundry_config_without_profile() {
+        assert!(
+            foundry_string_array("remappings = [\"a/=b/\"]", "default", "remappings").is_empty()
+        );
+        assert!(foundry_string_array("[profile.default", "default", "remappings").is_empty());
+    }
+}
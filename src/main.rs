@@ -30,6 +30,7 @@ extern crate core;
 pub mod assembler;
 pub mod cli;
 pub mod file_utils;
+pub mod import_resolver;
 pub mod parser;
 pub mod structures;
 pub mod toml_builder;
@@ -43,7 +44,11 @@ use file_utils::{
     get_solidity_files_from_directory,
     write_mod_files,
 };
-use parser::Parser;
+use import_resolver::ImportResolver;
+use parser::{
+    Parser,
+    SymbolTable,
+};
 
 use crate::{
     cli::{
@@ -63,6 +68,10 @@ use std::{
     },
     path::Path,
 };
+use structures::{
+    AbiEncoding,
+    Import,
+};
 
 /// main function
 fn main() {
@@ -83,7 +92,17 @@ fn main() {
             CliInput::SolidityFile(file) => {
                 let file_path = Path::new(&file).canonicalize().unwrap();
                 let file_home = file_path.parent().unwrap().to_str().unwrap();
-                match run(file_home, &[file.clone()]) {
+                let root = Path::new(&file).parent().unwrap().to_str().unwrap();
+                let resolver = ImportResolver::new(root, &args.remappings, &args.include_paths);
+                match run(
+                    file_home,
+                    &[file.clone()],
+                    resolver,
+                    args.narrow_integers,
+                    &args.lazy_arrays,
+                    args.abi_encoding,
+                    args.chain_id,
+                ) {
                     Ok(_) => {
                         println!("Successfully parsed {file}");
                     }
@@ -97,7 +116,16 @@ fn main() {
                 let paths = get_solidity_files_from_directory(&dir)
                     .unwrap_or_else(|err| panic!("error: {err:?}"));
 
-                match run(&dir, &paths) {
+                let resolver = ImportResolver::new(&dir, &args.remappings, &args.include_paths);
+                match run(
+                    &dir,
+                    &paths,
+                    resolver,
+                    args.narrow_integers,
+                    &args.lazy_arrays,
+                    args.abi_encoding,
+                    args.chain_id,
+                ) {
                     Ok(_) => {}
                     Err(err) => {
                         eprintln!("error: {err:?}");
@@ -113,21 +141,43 @@ fn main() {
 ///
 /// `home` the home directory of a single file, or the directory we are parsing
 /// `path` the paths to the files we want to parse
-fn run(home: &str, path: &[String]) -> Result<(), ParserError> {
+/// `resolver` the resolver of the imported paths
+/// `narrow_integers` whether integers wider than 128 bits are narrowed to 128 bits
+/// `lazy_arrays` the storage arrays stored as a mapping with a separate length
+/// `abi_encoding` the encoding of the values encoded and decoded with the `abi` functions
+/// `chain_id` the chain id substituted for `block.chainid`
+fn run(
+    home: &str,
+    path: &[String],
+    resolver: ImportResolver,
+    narrow_integers: bool,
+    lazy_arrays: &[String],
+    abi_encoding: AbiEncoding,
+    chain_id: Option<u64>,
+) -> Result<(), ParserError> {
     initialize_parser!(parser);
 
     create_structure(home)?;
+    parser.set_import_resolver(resolver.clone());
+    parser.set_narrow_integers(narrow_integers);
+    parser.set_lazy_arrays(lazy_arrays);
+    parser.set_abi_encoding(abi_encoding);
+    parser.set_chain_id(chain_id);
     let mut impls = Vec::default();
     let mut traits = Vec::default();
     let mut libs = Vec::default();
+    // `I256` is generated once for all files using `int256`
+    let mut signed_256 = false;
 
-    for file in path {
+    // imported files are parsed first, so their symbols are known in the importing files
+    for file in file_utils::order_by_imports(path, &resolver)?.iter() {
         let content = file_utils::read_file(file)?;
-        let output = parser.parse_file(&content)?;
+        let output = parser.parse_file(file, &content)?;
 
         for output in output {
             match output {
                 ParserOutput::Contract(name, contract) => {
+                    signed_256 |= contract.imports.contains(&Import::I256);
                     let ink_contract = assembler::assemble_contract(&contract);
                     let implementation = assembler::assemble_impl(&contract);
                     let trait_definition = assembler::assemble_trait(&contract);
@@ -141,10 +191,12 @@ fn run(home: &str, path: &[String]) -> Result<(), ParserError> {
                         trait_definition,
                         &contract.name,
                         home,
+                        abi_encoding,
                     )?;
                     println!("File saved!");
                 }
                 ParserOutput::Interface(name, interface) => {
+                    signed_256 |= interface.imports.contains(&Import::I256);
                     let ink_trait = assembler::assemble_interface(interface);
 
                     traits.push(name.clone());
@@ -153,6 +205,7 @@ fn run(home: &str, path: &[String]) -> Result<(), ParserError> {
                     println!("File saved!");
                 }
                 ParserOutput::Library(name, library) => {
+                    signed_256 |= library.imports.contains(&Import::I256);
                     let lib = assembler::assemble_library(library);
 
                     libs.push(name.clone());
@@ -160,6 +213,15 @@ fn run(home: &str, path: &[String]) -> Result<(), ParserError> {
                     file_utils::write_library(lib, home, &name)?;
                     println!("File saved!");
                 }
+                ParserOutput::Globals(name, globals) => {
+                    signed_256 |= globals.imports.contains(&Import::I256);
+                    let globals = assembler::assemble_globals(globals);
+
+                    libs.push(name.clone());
+
+                    file_utils::write_library(globals, home, &name)?;
+                    println!("File saved!");
+                }
                 _ => {}
             }
         }
@@ -167,12 +229,18 @@ fn run(home: &str, path: &[String]) -> Result<(), ParserError> {
         parser.clear();
     }
 
+    if signed_256 {
+        let name = String::from("i_256");
+        file_utils::write_library(assembler::assemble_i256(), home, &name)?;
+        libs.push(name);
+    }
+
     let impls_mod = assemble_mod(&impls);
     let traits_mod = assemble_mod(&traits);
     let libs_mod = assemble_mod(&libs);
     let lib = assemble_lib();
 
-    write_mod_files(home, impls_mod, traits_mod, libs_mod, lib)?;
+    write_mod_files(home, impls_mod, traits_mod, libs_mod, lib, abi_encoding)?;
 
     Ok(())
 }
@@ -20,7 +20,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::structures::*;
+use crate::{
+    import_resolver::{
+        normalize_path,
+        ImportResolver,
+    },
+    structures::*,
+};
 use convert_case::{
     Case::Snake,
     Casing,
@@ -29,11 +35,13 @@ use rbtree::RBTree;
 use solang_parser::{
     parse,
     pt::{
+        CatchClause as SolangCatchClause,
         Comment as SolangComment,
         ContractDefinition,
         ContractPart,
         ContractTy,
         EnumDefinition,
+        ErrorDefinition,
         EventDefinition,
         Expression as SolangExpression,
         FunctionAttribute,
@@ -41,21 +49,38 @@ use solang_parser::{
         FunctionTy,
         Identifier,
         IdentifierPath,
+        Import as SolangImport,
+        Loc,
         Mutability,
+        Parameter,
         SourceUnitPart,
         Statement as SolangStatement,
+        StorageLocation,
         StructDefinition,
         Type as SolangType,
+        TypeDefinition,
         Unit,
+        Using,
+        UsingList,
         VariableAttribute,
         VariableDefinition,
         Visibility,
+        YulBlock,
+        YulExpression,
+        YulFunctionCall,
+        YulStatement,
+        YulSwitchOptions,
     },
 };
-use std::collections::{
-    HashMap,
-    HashSet,
-    VecDeque,
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
 };
 
 #[derive(Clone, Debug)]
@@ -63,6 +88,7 @@ pub enum ParserOutput {
     Contract(String, Contract),
     Interface(String, Interface),
     Library(String, Library),
+    Globals(String, Globals),
     None,
 }
 
@@ -78,6 +104,9 @@ pub enum ParserError {
     EnumValueNotDefined,
 
     IncorrectTypeOfVariable,
+    FunctionTypedField(String),
+    LinearizationImpossible(String),
+    CyclicInheritance(String),
 }
 
 impl From<std::io::Error> for ParserError {
@@ -89,30 +118,245 @@ impl From<std::io::Error> for ParserError {
 pub struct Parser<'a> {
     members_map: &'a mut HashMap<String, MemberType>,
     modifiers_map: &'a mut HashMap<String, FunctionDefinition>,
+    errors_map: &'a mut HashMap<String, Vec<ErrorField>>,
+    overloads_map: &'a mut HashMap<String, Vec<FunctionOverload>>,
+    locals_map: &'a mut HashMap<String, Type>,
+    libraries_map: &'a mut HashMap<String, HashSet<String>>,
+    value_types: &'a mut HashSet<String>,
+    usings: &'a mut Vec<UsingDirective>,
+    symbols: &'a mut SymbolTable,
     imports: &'a mut HashSet<Import>,
     comments: &'a mut RBTree<usize, String>,
+    /// integers wider than 128 bits are narrowed to 128 bits instead of using `U256`
+    narrow_integers: bool,
+    /// the encoding of the values encoded and decoded with the `abi` functions
+    abi_encoding: AbiEncoding,
+    /// the chain id substituted for `block.chainid`, which is not available in ink!
+    chain_id: Option<u64>,
+    /// how the arithmetic operations of the parsed code are translated
+    arithmetic: Arithmetic,
+    /// values of the constants which can be evaluated, used as the sizes of fixed size arrays
+    constant_values: HashMap<String, u128>,
+    /// storage arrays stored as a mapping with a separate length, as `field` or `Contract.field`
+    lazy_arrays: HashSet<String>,
+    /// `storage` locals of the parsed function and the storage values they point to
+    storage_refs: HashMap<String, Expression>,
+    /// `storage` parameters of the parsed function, which are mutable references
+    storage_params: HashSet<String>,
+    /// types of the fields of the parsed structs, kept between files so imported structs are known
+    struct_fields: HashMap<String, HashMap<String, Type>>,
+    /// internal functions and which of their parameters are `calldata`, those are passed borrowed
+    calldata_params: HashMap<String, Vec<bool>>,
+    /// numbers of parameters of the functions, the functions used as values are wrapped in closures
+    function_arities: HashMap<String, usize>,
+    /// numbers of parameters of the functions defined outside of contracts in the parsed file
+    free_functions: HashMap<String, usize>,
+    /// contracts and interfaces defined in the parsed file, their values are addresses of deployed contracts
+    contract_types: HashSet<String>,
+    /// contracts created with `new` in the parsed contract, the contract stores the hashes of their code
+    created_contracts: Vec<String>,
+    /// the parsed contract, `super` calls are resolved within its bases
+    contract_name: String,
+    /// functions called through `super` in the parsed contract
+    super_calls: Vec<String>,
+    /// return types of the parsed function, the returned literals are converted to them
+    return_types: Vec<Type>,
+    /// locals of the parsed function holding the creation code of a contract, and the contract
+    creation_codes: HashMap<String, String>,
+    /// declared widths of the integer locals of the parsed function, like 24 of `uint24`
+    int_bits: HashMap<String, u16>,
+    /// declared widths of the integer storage fields of the parsed contract
+    field_int_bits: HashMap<String, u16>,
+}
+
+/// The translation of the arithmetic operations, which depends on the Solidity version
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Arithmetic {
+    /// before Solidity 0.8 the operations are not checked, contracts use `SafeMath` instead
+    Plain,
+    /// since Solidity 0.8 the operations revert on overflow
+    Checked,
+    /// operations in `unchecked` blocks and in assembly wrap around
+    Wrapping,
+}
+
+/// One function of an overload set, registered before the functions are parsed
+/// so calls to overloaded functions can be resolved to the mangled name
+/// and their arguments and returned values can be converted to the declared types
+#[derive(Clone, Debug)]
+pub struct FunctionOverload {
+    name: String,
+    params: Vec<Type>,
+    returns: Vec<Type>,
+    external: bool,
+    /// which of the parameters are `storage` references
+    storage: Vec<bool>,
+}
+
+/// A `using Library for Type` directive, attaching the functions of a library to a type
+/// `ty` is `None` for `using Library for *`, `function` is `Some` for `using {Library.function} for Type`
+#[derive(Clone, Debug)]
+pub struct UsingDirective {
+    library: String,
+    function: Option<String>,
+    ty: Option<Type>,
+}
+
+/// The symbols exported by the parsed files, kept between files so imports can be resolved
+/// `aliases` and `namespaces` belong to the parsed file, `current` collects the symbols
+/// visible in the parsed file, which are exported once the file is parsed
+#[derive(Clone, Debug, Default)]
+pub struct SymbolTable {
+    files: HashMap<PathBuf, FileSymbols>,
+    current: FileSymbols,
+    /// `import {A as B} from "A.sol"` maps `B` to `A`
+    aliases: HashMap<String, String>,
+    /// `import "A.sol" as A` makes the symbols of `A.sol` accessible as `A.symbol`
+    namespaces: HashSet<String>,
+    resolver: ImportResolver,
+}
+
+/// The symbols a file exports to the files importing it
+#[derive(Clone, Debug, Default)]
+pub struct FileSymbols {
+    contracts: HashMap<String, ContractSymbols>,
+    constants: HashSet<String>,
+    errors: Vec<Error>,
+    value_types: HashSet<String>,
+    globals: HashSet<String>,
+}
+
+/// The members of a contract, interface or library, including the members of its bases
+/// `library_functions` is `Some` for libraries, `definition` is `Some` for parsed contracts
+#[derive(Clone, Debug, Default)]
+pub struct ContractSymbols {
+    members: HashMap<String, MemberType>,
+    modifiers: HashMap<String, FunctionDefinition>,
+    errors: HashMap<String, Vec<ErrorField>>,
+    library_functions: Option<HashSet<String>>,
+    /// the contract followed by its bases in the order of the C3 linearization
+    linearization: Vec<String>,
+    /// the parsed contract without its inherited members, merged into the contracts deriving from it
+    definition: Option<Box<Contract>>,
+    /// the functions called through `super` in the contract
+    super_calls: Vec<String>,
+    /// the overload sets of the functions, so calls from other contracts use the mangled names
+    overloads: HashMap<String, Vec<FunctionOverload>>,
+    /// the functions by their names, or by the mangled names if they are overloaded
+    functions: HashMap<String, FunctionOverload>,
 }
 
 impl<'a> Parser<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         members_map: &'a mut HashMap<String, MemberType>,
         modifiers_map: &'a mut HashMap<String, FunctionDefinition>,
+        errors_map: &'a mut HashMap<String, Vec<ErrorField>>,
+        overloads_map: &'a mut HashMap<String, Vec<FunctionOverload>>,
+        locals_map: &'a mut HashMap<String, Type>,
+        libraries_map: &'a mut HashMap<String, HashSet<String>>,
+        value_types: &'a mut HashSet<String>,
+        usings: &'a mut Vec<UsingDirective>,
+        symbols: &'a mut SymbolTable,
         imports: &'a mut HashSet<Import>,
         comments: &'a mut RBTree<usize, String>,
     ) -> Self {
         Parser {
             members_map,
             modifiers_map,
+            errors_map,
+            overloads_map,
+            locals_map,
+            libraries_map,
+            value_types,
+            usings,
+            symbols,
             imports,
             comments,
+            narrow_integers: false,
+            abi_encoding: AbiEncoding::Scale,
+            chain_id: None,
+            arithmetic: Arithmetic::Checked,
+            constant_values: HashMap::new(),
+            lazy_arrays: HashSet::new(),
+            storage_refs: HashMap::new(),
+            storage_params: HashSet::new(),
+            struct_fields: HashMap::new(),
+            calldata_params: HashMap::new(),
+            function_arities: HashMap::new(),
+            free_functions: HashMap::new(),
+            contract_types: HashSet::new(),
+            created_contracts: Vec::new(),
+            contract_name: String::new(),
+            super_calls: Vec::new(),
+            return_types: Vec::new(),
+            creation_codes: HashMap::new(),
+            int_bits: HashMap::new(),
+            field_int_bits: HashMap::new(),
         }
     }
 
     pub fn clear(&mut self) {
         self.members_map.clear();
         self.modifiers_map.clear();
+        self.errors_map.clear();
+        self.overloads_map.clear();
+        self.locals_map.clear();
+        self.libraries_map.clear();
+        self.value_types.clear();
+        self.usings.clear();
+        self.symbols.current = Default::default();
+        self.symbols.aliases.clear();
+        self.symbols.namespaces.clear();
         self.imports.clear();
         self.comments.clear();
+        self.arithmetic = Arithmetic::Checked;
+        self.constant_values.clear();
+        self.storage_refs.clear();
+        self.storage_params.clear();
+        self.calldata_params.clear();
+        self.function_arities.clear();
+        self.free_functions.clear();
+        self.contract_types.clear();
+        self.created_contracts.clear();
+        self.contract_name.clear();
+        self.super_calls.clear();
+    }
+
+    /// Sets the resolver of the imported paths, the resolver is kept when the parser is cleared
+    ///
+    /// `resolver` the resolver of the imported paths
+    pub fn set_import_resolver(&mut self, resolver: ImportResolver) {
+        self.symbols.resolver = resolver;
+    }
+
+    /// Narrows integers wider than 128 bits to 128 bits instead of translating them to `U256`
+    ///
+    /// `narrow_integers` whether the integers should be narrowed
+    pub fn set_narrow_integers(&mut self, narrow_integers: bool) {
+        self.narrow_integers = narrow_integers;
+    }
+
+    /// Selects the encoding of the values encoded and decoded with the `abi` functions
+    ///
+    /// `abi_encoding` the selected encoding
+    pub fn set_abi_encoding(&mut self, abi_encoding: AbiEncoding) {
+        self.abi_encoding = abi_encoding;
+    }
+
+    /// Sets the chain id substituted for `block.chainid`
+    ///
+    /// `chain_id` the chain id, `None` if the chain id is not known
+    pub fn set_chain_id(&mut self, chain_id: Option<u64>) {
+        self.chain_id = chain_id;
+    }
+
+    /// Selects the storage arrays which are stored as a mapping from indices to elements
+    /// together with a separate length, so the elements are loaded lazily
+    ///
+    /// `lazy_arrays` the selected fields, as `field` or `Contract.field`
+    pub fn set_lazy_arrays(&mut self, lazy_arrays: &[String]) {
+        self.lazy_arrays = lazy_arrays.iter().cloned().collect();
     }
 
     /// Removes slashes and asterisks from a comment and returns it as a String
@@ -140,9 +384,15 @@ impl<'a> Parser<'a> {
 
     /// Parses a fil and returns the vec of ParserOutput or a ParserError
     ///
+    /// `path` the path to the solidity file, used to name the module of its global items
     /// `content` the content of a solidity file
-    pub fn parse_file(&mut self, content: &str) -> Result<Vec<ParserOutput>, ParserError> {
-        let token_tree = parse(content, 0).map_err(|errors| {
+    pub fn parse_file(
+        &mut self,
+        path: &str,
+        content: &str,
+    ) -> Result<Vec<ParserOutput>, ParserError> {
+        let (content, operators) = extract_operator_bindings(content);
+        let token_tree = parse(&content, 0).map_err(|errors| {
             ParserError::FileCorrupted(errors.iter().map(|error| error.message.clone()).collect())
         })?;
 
@@ -162,16 +412,152 @@ impl<'a> Parser<'a> {
             }
         });
 
+        let globals_name = format!(
+            "{}_globals",
+            Path::new(path)
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default()
+                .to_case(Snake)
+        );
+        let mut has_globals = false;
+
+        // first we register the imported symbols, the libraries and the items defined
+        // outside of contracts, so their usage can be resolved
+        for source_unit_part in source_unit.0.iter() {
+            match &source_unit_part {
+                SourceUnitPart::ImportDirective(import) => self.register_import(path, import),
+                SourceUnitPart::PragmaDirective(_, Some(name), Some(version))
+                    if name.name == "solidity" =>
+                {
+                    if !checked_arithmetic(&version.string) {
+                        self.arithmetic = Arithmetic::Plain;
+                    }
+                }
+                SourceUnitPart::ContractDefinition(contract) => {
+                    if let ContractTy::Library(_) = contract.ty {
+                        self.register_library(contract);
+                    } else {
+                        self.contract_types
+                            .insert(self.parse_identifier(&contract.name));
+                    }
+                }
+                SourceUnitPart::TypeDefinition(type_definition) => {
+                    self.value_types.insert(type_definition.name.name.clone());
+                    has_globals = true;
+                }
+                SourceUnitPart::VariableDefinition(variable_definition) => {
+                    self.members_map.insert(
+                        self.parse_identifier(&variable_definition.name),
+                        MemberType::Constant,
+                    );
+                    has_globals = true;
+                }
+                SourceUnitPart::ErrorDefinition(error_definition) => {
+                    self.register_error(error_definition)?;
+                    has_globals = true;
+                }
+                SourceUnitPart::FunctionDefinition(function_definition) => {
+                    self.free_functions.insert(
+                        self.parse_identifier(&function_definition.name),
+                        function_definition.params.len(),
+                    );
+                    has_globals = true;
+                }
+                SourceUnitPart::StructDefinition(_) | SourceUnitPart::EnumDefinition(_) => {
+                    has_globals = true
+                }
+                _ => (),
+            }
+        }
+
+        if has_globals {
+            self.imports.insert(Import::Globals(globals_name.clone()));
+        }
+
+        let mut globals: Globals = Default::default();
+
         for source_unit_part in source_unit.0.iter() {
             match &source_unit_part {
                 SourceUnitPart::ContractDefinition(contract) => {
                     output.push(self.handle_contract_definition(contract)?);
                 }
+                SourceUnitPart::TypeDefinition(type_definition) => {
+                    let parsed_value_type = self.parse_value_type(type_definition)?;
+                    globals.value_types.push(parsed_value_type);
+                }
+                SourceUnitPart::VariableDefinition(variable_definition) => {
+                    let parsed_field = self.parse_storage_field(variable_definition)?;
+                    globals.fields.push(parsed_field);
+                }
+                SourceUnitPart::ErrorDefinition(error_definition) => {
+                    let parsed_error = self.parse_error(error_definition)?;
+                    globals.errors.push(parsed_error);
+                }
+                SourceUnitPart::FunctionDefinition(function_definition) => {
+                    let parsed_function = self.parse_function(function_definition)?;
+                    globals.functions.push(parsed_function);
+                }
+                SourceUnitPart::StructDefinition(struct_definition) => {
+                    let parsed_struct = self.parse_struct(struct_definition)?;
+                    globals.structs.push(parsed_struct);
+                }
+                SourceUnitPart::EnumDefinition(enum_definition) => {
+                    let parsed_enum = self.parse_enum(enum_definition)?;
+                    globals.enums.push(parsed_enum);
+                }
+                SourceUnitPart::Using(using) => self.register_using(using)?,
                 SourceUnitPart::ImportDirective(_) | SourceUnitPart::PragmaDirective(..) => {}
                 _ => println!("Found a source unit outside of contract"),
             }
         }
 
+        // operators can only be bound globally, so we bind them once all value types are parsed
+        for source_unit_part in source_unit.0.iter() {
+            if let SourceUnitPart::Using(using) = &source_unit_part {
+                if using.global.is_some() {
+                    self.bind_operators(
+                        using,
+                        &operators,
+                        &source_unit.0,
+                        &mut globals.value_types,
+                    );
+                }
+            }
+        }
+
+        // errors defined in the file can be used in the contracts, so we add them
+        // as the first variants of the `Error` enum of each contract,
+        // errors defined in the imported files are added as the last variants
+        for parsed_contract in output.iter_mut() {
+            if let ParserOutput::Contract(_, contract) = parsed_contract {
+                contract.globals_errors = globals.errors.clone();
+            }
+            match parsed_contract {
+                ParserOutput::Contract(_, Contract { errors, .. })
+                | ParserOutput::Interface(_, Interface { errors, .. })
+                | ParserOutput::Library(_, Library { errors, .. }) => {
+                    errors.splice(0..0, globals.errors.clone());
+                    for imported_error in self.symbols.current.errors.iter() {
+                        if !errors.iter().any(|error| error.name == imported_error.name) {
+                            errors.push(imported_error.clone());
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        self.export_symbols(path, &globals, has_globals.then_some(&globals_name));
+
+        if has_globals {
+            globals.imports = self.imports.clone();
+            globals
+                .imports
+                .remove(&Import::Globals(globals_name.clone()));
+            output.push(ParserOutput::Globals(globals_name, globals));
+        }
+
         Ok(output)
     }
 
@@ -245,63 +631,40 @@ impl<'a> Parser<'a> {
         let base = contract_definition
             .base
             .iter()
-            .map(|base| self.parse_identifier_path(&base.name))
+            .map(|base| self.resolve_symbol(&self.parse_identifier_path(&base.name)))
             .collect();
 
         let mut structs: Vec<Struct> = Default::default();
+        let mut value_types: Vec<ValueType> = Default::default();
         let mut events: Vec<Event> = Default::default();
         let mut enums: Vec<Enum> = Default::default();
+        let mut errors: Vec<Error> = Default::default();
         let mut fields: Vec<ContractField> = Default::default();
         let mut functions: Vec<Function> = Default::default();
         let mut constructor: Function = Default::default();
         let mut modifiers: Vec<Function> = Default::default();
 
-        // first we register all members of the contract
+        self.register_overloads(contract_definition)?;
+        let file_usings = self.usings.len();
+        self.created_contracts.clear();
+        self.contract_name = name.clone();
+        self.super_calls.clear();
+
+        // first we register all members of the contract, including the inherited ones
+        self.register_contract_symbols(contract_definition)?;
+        self.field_int_bits.clear();
         for part in contract_definition.parts.iter() {
             match part {
                 ContractPart::VariableDefinition(variable_definition) => {
-                    let field_type = self.parse_type(&variable_definition.ty)?;
-                    let name = self.parse_identifier(&variable_definition.name);
-                    let constant = variable_definition
-                        .attrs
-                        .iter()
-                        .any(|item| matches!(item, VariableAttribute::Constant(_)));
-                    self.members_map.insert(
-                        name,
-                        if constant {
-                            MemberType::Constant
-                        } else {
-                            MemberType::Variable(Box::new(field_type))
-                        },
-                    );
-                }
-                ContractPart::FunctionDefinition(function_definition) => {
-                    let fn_name = self.parse_identifier(&function_definition.name);
-                    let external = function_definition.attributes.iter().any(|attribute| {
-                        matches!(
-                            attribute,
-                            FunctionAttribute::Visibility(Visibility::External(_))
-                                | FunctionAttribute::Visibility(Visibility::Public(_))
-                        )
-                    });
-                    match function_definition.ty {
-                        FunctionTy::Function => {
-                            self.members_map.insert(
-                                fn_name.clone(),
-                                if external {
-                                    MemberType::Function
-                                } else {
-                                    MemberType::FunctionPrivate
-                                },
-                            );
-                        }
-                        FunctionTy::Modifier => {
-                            self.modifiers_map
-                                .insert(fn_name.clone(), *function_definition.clone());
-                        }
-                        _ => (),
+                    if let Some(bits) = declared_int_bits(&variable_definition.ty) {
+                        self.field_int_bits
+                            .insert(self.parse_identifier(&variable_definition.name), bits);
                     }
                 }
+                ContractPart::Using(using) => self.register_using(using)?,
+                ContractPart::TypeDefinition(type_definition) => {
+                    self.value_types.insert(type_definition.name.name.clone());
+                }
                 _ => (),
             }
         }
@@ -310,10 +673,44 @@ impl<'a> Parser<'a> {
             match part {
                 ContractPart::Annotation(_) => println!("Anottation: {part:?}"),
                 ContractPart::VariableDefinition(variable_definition) => {
-                    let parsed_field = self.parse_storage_field(variable_definition)?;
+                    let mut parsed_field = self.parse_storage_field(variable_definition)?;
+                    parsed_field.field_type =
+                        self.storage_array_type(&name, &parsed_field.name, parsed_field.field_type);
+                    // function pointers are addresses of code, which can not be encoded to the storage
+                    if let Type::Function(..) = parsed_field.field_type {
+                        return Err(ParserError::FunctionTypedField(format!(
+                            "{name}.{}",
+                            parsed_field.name
+                        )))
+                    }
+                    // array sizes may depend on constants, which are only evaluated now
+                    if !parsed_field.constant {
+                        self.members_map.insert(
+                            parsed_field.name.clone(),
+                            MemberType::Variable(Box::new(parsed_field.field_type.clone())),
+                        );
+                    }
+                    // lazy arrays keep their length in a separate field
+                    let length_field = match &parsed_field.field_type {
+                        Type::LazyArray(index, _) => {
+                            Some(ContractField {
+                                field_type: *index.clone(),
+                                name: format!("{}_length", parsed_field.name),
+                                comments: Vec::default(),
+                                initial_value: None,
+                                constant: false,
+                                public: false,
+                            })
+                        }
+                        _ => None,
+                    };
                     fields.push(parsed_field);
+                    fields.extend(length_field);
+                }
+                ContractPart::ErrorDefinition(error_definition) => {
+                    let parsed_error = self.parse_error(error_definition)?;
+                    errors.push(parsed_error);
                 }
-                ContractPart::ErrorDefinition(_) => {}
                 ContractPart::FunctionDefinition(function_definition) => {
                     let parsed_function = self.parse_function(function_definition)?;
                     match function_definition.ty {
@@ -335,17 +732,76 @@ impl<'a> Parser<'a> {
                     let parsed_enum = self.parse_enum(enum_definition)?;
                     enums.push(parsed_enum);
                 }
-                ContractPart::TypeDefinition(_) => {}
+                ContractPart::TypeDefinition(type_definition) => {
+                    let parsed_value_type = self.parse_value_type(type_definition)?;
+                    value_types.push(parsed_value_type);
+                }
                 ContractPart::Using(_) => {}
                 ContractPart::StraySemicolon(_) => {}
             }
         }
 
-        Ok(Contract {
+        // `contract A is B(x)` passes the arguments to the constructor of `B`, as `constructor() B(x)` does
+        for base in contract_definition.base.iter() {
+            if let Some(args) = &base.args {
+                let parsed_args =
+                    self.parse_expression_vec(args, VariableAccessLocation::Constructor);
+                constructor.header.modifiers.push(Expression::Modifier(
+                    self.resolve_symbol(&self.parse_identifier_path(&base.name)),
+                    parsed_args,
+                ));
+            }
+        }
+
+        self.usings.truncate(file_usings);
+
+        // the hashes of the code of the created contracts are provided to the constructor
+        for created_contract in self.created_contracts.iter() {
+            let code_hash = code_hash_field(created_contract);
+            fields.push(ContractField {
+                field_type: Type::Bytes(32),
+                name: code_hash.clone(),
+                comments: vec![format!(
+                    " The code hash of `{created_contract}`, used to instantiate it"
+                )],
+                initial_value: None,
+                constant: false,
+                public: false,
+            });
+            constructor.header.params.push(FunctionParam {
+                name: code_hash.clone(),
+                param_type: Type::Bytes(32),
+                location: None,
+            });
+            let assignment = Statement::Expression(Expression::Assign(
+                Box::new(Expression::Variable(
+                    code_hash.clone(),
+                    MemberType::Variable(Box::new(Type::Bytes(32))),
+                    VariableAccessLocation::Constructor,
+                )),
+                Box::new(Expression::Variable(
+                    code_hash,
+                    MemberType::None(Box::new(Type::Bytes(32))),
+                    VariableAccessLocation::Constructor,
+                )),
+            ));
+            constructor.body = match constructor.body.take() {
+                Some(Statement::Block(mut statements)) => {
+                    statements.insert(0, assignment);
+                    Some(Statement::Block(statements))
+                }
+                _ => Some(Statement::Block(vec![assignment])),
+            };
+        }
+
+        let contract = Contract {
             name,
             structs,
+            value_types,
             events,
             enums,
+            errors,
+            globals_errors: Vec::default(),
             fields,
             functions,
             constructor,
@@ -353,7 +809,254 @@ impl<'a> Parser<'a> {
             imports: self.imports.clone(),
             contract_doc: comments.to_vec(),
             base,
-        })
+            interfaces: Vec::default(),
+        };
+        if let Some(symbols) = self.symbols.current.contracts.get_mut(&contract.name) {
+            symbols.definition = Some(Box::new(contract.clone()));
+            symbols.super_calls = self.super_calls.clone();
+        }
+        Ok(self.inherit(contract))
+    }
+
+    /// Returns the C3 linearization of a contract, the contract itself followed by its bases
+    /// from the most derived to the most base-like one, as Solidity orders the bases
+    /// The linearizations of the bases were computed when the bases were registered,
+    /// a base whose linearization contains the contract makes the inheritance cyclic
+    ///
+    /// `name` the name of the contract
+    /// `bases` the direct bases of the contract, as listed in its definition
+    fn linearize(&self, name: &str, bases: &[String]) -> Result<Vec<String>, ParserError> {
+        // the bases are listed from the most base-like one, so they are merged in reverse
+        let mut sequences = Vec::new();
+        for base in bases.iter().rev() {
+            let base_linearization = self
+                .symbols
+                .current
+                .contracts
+                .get(base)
+                .map(|symbols| symbols.linearization.clone())
+                .unwrap_or_else(|| vec![base.clone()]);
+            if base_linearization.iter().any(|ancestor| ancestor == name) {
+                return Err(ParserError::CyclicInheritance(name.to_owned()))
+            }
+            sequences.push(base_linearization);
+        }
+        sequences.push(bases.iter().rev().cloned().collect());
+
+        let mut linearization = vec![name.to_owned()];
+        loop {
+            sequences.retain(|sequence| !sequence.is_empty());
+            if sequences.is_empty() {
+                return Ok(linearization)
+            }
+            let head = sequences
+                .iter()
+                .map(|sequence| sequence[0].clone())
+                .find(|candidate| {
+                    !sequences
+                        .iter()
+                        .any(|sequence| sequence[1..].contains(candidate))
+                })
+                .ok_or_else(|| ParserError::LinearizationImpossible(name.to_owned()))?;
+            for sequence in sequences.iter_mut() {
+                if sequence[0] == head {
+                    sequence.remove(0);
+                }
+            }
+            linearization.push(head);
+        }
+    }
+
+    /// Returns the linearization of a registered contract
+    ///
+    /// `name` the name of the contract
+    fn linearization(&self, name: &str) -> Vec<String> {
+        self.symbols
+            .current
+            .contracts
+            .get(name)
+            .map(|symbols| symbols.linearization.clone())
+            .unwrap_or_else(|| vec![name.to_owned()])
+    }
+
+    /// Merges the parsed bases of a contract into the contract, following the linearization of its bases
+    /// The storage of the most base-like contract comes first, functions, modifiers, events and errors
+    /// come from the most derived contract defining them, so overridden functions are replaced.
+    /// The functions called through `super` are added as internal functions implemented
+    /// by the next contract of the linearization, so `super` resolves as in the most derived contract.
+    /// Interfaces and bases which were not parsed are implemented as traits by the contract.
+    ///
+    /// `contract` the parsed contract
+    ///
+    /// Returns the contract with the inherited members
+    fn inherit(&self, mut contract: Contract) -> Contract {
+        let linearization = self.linearization(&contract.name);
+        let mut definitions = vec![(contract.clone(), self.super_calls.clone())];
+        for base in linearization.iter().skip(1) {
+            match self.symbols.current.contracts.get(base) {
+                Some(ContractSymbols {
+                    definition: Some(definition),
+                    super_calls,
+                    ..
+                }) => definitions.push((*definition.clone(), super_calls.clone())),
+                _ => contract.interfaces.insert(0, base.clone()),
+            }
+        }
+        if definitions.len() == 1 {
+            return contract
+        }
+
+        contract.fields.clear();
+        for (definition, _) in definitions.iter().rev() {
+            for field in definition.fields.iter() {
+                if !contract
+                    .fields
+                    .iter()
+                    .any(|merged| merged.name == field.name)
+                {
+                    contract.fields.push(field.clone());
+                }
+            }
+        }
+        for (definition, _) in definitions.iter().skip(1) {
+            for function in definition.functions.iter() {
+                if !contract
+                    .functions
+                    .iter()
+                    .any(|merged| merged.header.name == function.header.name)
+                {
+                    contract.functions.push(function.clone());
+                }
+            }
+            for modifier in definition.modifiers.iter() {
+                if !contract
+                    .modifiers
+                    .iter()
+                    .any(|merged| merged.header.name == modifier.header.name)
+                {
+                    contract.modifiers.push(modifier.clone());
+                }
+            }
+            for event in definition.events.iter() {
+                if !contract
+                    .events
+                    .iter()
+                    .any(|merged| merged.name == event.name)
+                {
+                    contract.events.push(event.clone());
+                }
+            }
+            for error in definition.errors.iter() {
+                if !contract
+                    .errors
+                    .iter()
+                    .any(|merged| merged.name == error.name)
+                {
+                    contract.errors.push(error.clone());
+                }
+            }
+            contract.imports.extend(definition.imports.iter().cloned());
+        }
+        for (index, (caller, super_calls)) in definitions.iter().enumerate() {
+            for function_name in super_calls.iter() {
+                let implementation = definitions[index + 1..].iter().find_map(|(base, _)| {
+                    base.functions
+                        .iter()
+                        .find(|function| &function.header.name == function_name)
+                });
+                if let Some(implementation) = implementation {
+                    let mut function = implementation.clone();
+                    function.header.name = super_function(function_name, &caller.name);
+                    function.header.external = false;
+                    contract.functions.push(function);
+                }
+            }
+        }
+
+        // the constructors of the bases run before the constructor of the contract,
+        // starting with the most base-like one
+        let mut base_args = HashMap::new();
+        for (definition, _) in definitions.iter() {
+            for modifier in definition.constructor.header.modifiers.iter() {
+                if let Expression::Modifier(base, args) | Expression::InvalidModifier(base, args) =
+                    modifier
+                {
+                    if definitions.iter().any(|(parsed, _)| &parsed.name == base) {
+                        base_args
+                            .entry(base.clone())
+                            .or_insert_with(|| args.clone());
+                    }
+                }
+            }
+        }
+        let mut statements = Vec::new();
+        for (definition, _) in definitions.iter().skip(1).rev() {
+            let base_constructor = &definition.constructor;
+            if base_constructor.body.is_none() && base_constructor.header.params.is_empty() {
+                continue
+            }
+            let args = base_args.get(&definition.name).cloned().unwrap_or_default();
+            // parameters without arguments are left to the derived contract in Solidity,
+            // they get their default value here
+            let mut base_statements = base_constructor
+                .header
+                .params
+                .iter()
+                .enumerate()
+                .map(|(index, param)| {
+                    Statement::VariableDefinition(
+                        Expression::VariableDeclaration(
+                            Box::new(param.param_type.clone()),
+                            param.name.to_case(Snake),
+                            param.location,
+                        ),
+                        args.get(index)
+                            .cloned()
+                            .map(|arg| self.widen(arg, Some(param.param_type.clone()))),
+                    )
+                })
+                .collect::<Vec<_>>();
+            match &base_constructor.body {
+                Some(Statement::Block(body)) => base_statements.extend(body.iter().cloned()),
+                Some(body) => base_statements.push(body.clone()),
+                None => {}
+            }
+            statements.push(Statement::BaseConstructor(
+                definition.name.clone(),
+                base_statements,
+            ));
+        }
+        contract.constructor.header.modifiers.retain(|modifier| {
+            !matches!(modifier, Expression::Modifier(base, _) | Expression::InvalidModifier(base, _)
+                if base_args.contains_key(base))
+        });
+        if !statements.is_empty() {
+            match contract.constructor.body.take() {
+                Some(Statement::Block(body)) => statements.extend(body),
+                Some(body) => statements.push(body),
+                None => {}
+            }
+            contract.constructor.body = Some(Statement::Block(statements));
+        }
+        contract
+    }
+
+    /// Returns true if a base of the parsed contract implements the function,
+    /// so calling it through `super` can be resolved
+    ///
+    /// `function` the name of the function called through `super`
+    fn super_implementation(&self, function: &str) -> bool {
+        self.linearization(&self.contract_name)
+            .iter()
+            .skip(1)
+            .filter_map(|base| self.symbols.current.contracts.get(base))
+            .filter_map(|symbols| symbols.definition.as_ref())
+            .any(|definition| {
+                definition
+                    .functions
+                    .iter()
+                    .any(|implementation| implementation.header.name == function)
+            })
     }
 
     /// Parses an interface
@@ -370,10 +1073,28 @@ impl<'a> Parser<'a> {
         let name = self.parse_identifier(&contract_definition.name);
 
         let mut structs: Vec<Struct> = Default::default();
+        let mut value_types: Vec<ValueType> = Default::default();
         let mut events: Vec<Event> = Default::default();
         let mut enums: Vec<Enum> = Default::default();
+        let mut errors: Vec<Error> = Default::default();
         let mut function_headers: Vec<FunctionHeader> = Default::default();
 
+        self.register_overloads(contract_definition)?;
+        let symbols = self.contract_symbols(contract_definition)?;
+        self.symbols.current.contracts.insert(name.clone(), symbols);
+
+        for part in contract_definition.parts.iter() {
+            match part {
+                ContractPart::ErrorDefinition(error_definition) => {
+                    self.register_error(error_definition)?;
+                }
+                ContractPart::TypeDefinition(type_definition) => {
+                    self.value_types.insert(type_definition.name.name.clone());
+                }
+                _ => (),
+            }
+        }
+
         for part in contract_definition.parts.iter() {
             match part {
                 ContractPart::Annotation(_) => println!("Anottation: {part:?}"),
@@ -389,14 +1110,20 @@ impl<'a> Parser<'a> {
                     let parsed_enum = self.parse_enum(enum_definition)?;
                     enums.push(parsed_enum);
                 }
-                ContractPart::ErrorDefinition(_) => {}
+                ContractPart::ErrorDefinition(error_definition) => {
+                    let parsed_error = self.parse_error(error_definition)?;
+                    errors.push(parsed_error);
+                }
                 ContractPart::FunctionDefinition(function_definition) => {
                     if function_definition.ty == FunctionTy::Function {
                         let header = self.parse_function_header(function_definition);
                         function_headers.push(header);
                     }
                 }
-                ContractPart::TypeDefinition(_) => {}
+                ContractPart::TypeDefinition(type_definition) => {
+                    let parsed_value_type = self.parse_value_type(type_definition)?;
+                    value_types.push(parsed_value_type);
+                }
                 ContractPart::Using(_) => {}
                 ContractPart::StraySemicolon(_) => {}
                 _ => {}
@@ -408,6 +1135,8 @@ impl<'a> Parser<'a> {
             events,
             enums,
             structs,
+            value_types,
+            errors,
             function_headers,
             imports: self.imports.clone(),
             comments: comments.to_vec(),
@@ -431,27 +1160,22 @@ impl<'a> Parser<'a> {
         let mut events: Vec<Event> = Default::default();
         let mut enums: Vec<Enum> = Default::default();
         let mut structs: Vec<Struct> = Default::default();
+        let mut value_types: Vec<ValueType> = Default::default();
+        let mut errors: Vec<Error> = Default::default();
         let mut functions: Vec<Function> = Default::default();
 
-        // first we register all members of the contract
+        self.register_overloads(contract_definition)?;
+        let file_usings = self.usings.len();
+
+        // first we register all members of the library
+        self.register_contract_symbols(contract_definition)?;
         for part in contract_definition.parts.iter() {
-            if let ContractPart::FunctionDefinition(function_definition) = part {
-                let fn_name = self.parse_identifier(&function_definition.name);
-                let external = function_definition.attributes.iter().any(|attribute| {
-                    matches!(
-                        attribute,
-                        FunctionAttribute::Visibility(Visibility::External(_))
-                            | FunctionAttribute::Visibility(Visibility::Public(_))
-                    )
-                });
-                self.members_map.insert(
-                    fn_name.clone(),
-                    if external {
-                        MemberType::Function
-                    } else {
-                        MemberType::FunctionPrivate
-                    },
-                );
+            match part {
+                ContractPart::Using(using) => self.register_using(using)?,
+                ContractPart::TypeDefinition(type_definition) => {
+                    self.value_types.insert(type_definition.name.name.clone());
+                }
+                _ => (),
             }
         }
 
@@ -470,14 +1194,20 @@ impl<'a> Parser<'a> {
                     let parsed_enum = self.parse_enum(enum_definition)?;
                     enums.push(parsed_enum);
                 }
-                ContractPart::ErrorDefinition(_) => {}
+                ContractPart::ErrorDefinition(error_definition) => {
+                    let parsed_error = self.parse_error(error_definition)?;
+                    errors.push(parsed_error);
+                }
                 ContractPart::FunctionDefinition(function_definition) => {
                     if function_definition.ty == FunctionTy::Function {
                         let parsed_function = self.parse_function(function_definition)?;
                         functions.push(parsed_function)
                     }
                 }
-                ContractPart::TypeDefinition(_) => {}
+                ContractPart::TypeDefinition(type_definition) => {
+                    let parsed_value_type = self.parse_value_type(type_definition)?;
+                    value_types.push(parsed_value_type);
+                }
                 ContractPart::Using(_) => {}
                 ContractPart::StraySemicolon(_) => {}
                 ContractPart::VariableDefinition(variable_definition) => {
@@ -491,1539 +1221,7781 @@ impl<'a> Parser<'a> {
             }
         }
 
+        self.usings.truncate(file_usings);
+
         Ok(Library {
             name,
             fields,
             events,
             enums,
             structs,
+            value_types,
+            errors,
             functions,
             imports: self.imports.clone(),
             libraray_doc: comments.to_vec(),
         })
     }
 
-    /// Parses a Solang struct definition to Sol2Ink struct definition
-    ///
-    /// `struct_definition` the Solang struct definition
+    /// Registers the symbols exported by an imported file, so they can be used in the parsed file
+    /// The imported file must be parsed before the importing file
     ///
-    /// Returns the parsed `Struct` struct
-    fn parse_struct(
-        &mut self,
-        struct_definition: &StructDefinition,
-    ) -> Result<Struct, ParserError> {
-        let name = self.parse_identifier(&struct_definition.name);
-
-        let comments = self.get_comments(struct_definition.loc.end());
-
-        let fields: Vec<StructField> = struct_definition
-            .fields
-            .iter()
-            .filter_map(|variable_declaration| {
-                let name = self.parse_identifier(&variable_declaration.name);
-                let ty = self.parse_type(&variable_declaration.ty).ok()?;
-                self.members_map
-                    .insert(name.clone(), MemberType::None(Box::new(ty.clone())));
-                let struct_field = StructField {
-                    name,
-                    field_type: ty,
-                    comments: Default::default(),
-                };
-                Some(struct_field)
-            })
-            .collect();
+    /// `path` the path to the importing file
+    /// `import` the Solang import directive
+    fn register_import(&mut self, path: &str, import: &SolangImport) {
+        let (file, renamed) = match import {
+            SolangImport::Plain(file, _) => (file, None),
+            SolangImport::GlobalSymbol(file, namespace, _) => {
+                self.symbols.namespaces.insert(namespace.name.clone());
+                (file, None)
+            }
+            SolangImport::Rename(file, symbols, _) => (file, Some(symbols)),
+        };
+        let imported = match self
+            .symbols
+            .files
+            .get(&self.symbols.resolver.resolve(path, &file.string))
+        {
+            Some(imported) => imported.clone(),
+            None => {
+                println!("Imported file {} was not parsed", file.string);
+                return
+            }
+        };
 
-        let parsed_struct = Struct {
-            name,
-            fields,
-            comments,
+        // only the listed symbols are imported by `import {A, B as C} from "A.sol"`
+        let is_imported = |name: &String| {
+            renamed.is_none_or(|symbols| symbols.iter().any(|(symbol, _)| &symbol.name == name))
         };
+        for (symbol, alias) in renamed.into_iter().flatten() {
+            if let Some(alias) = alias {
+                self.symbols
+                    .aliases
+                    .insert(alias.name.clone(), symbol.name.clone());
+            }
+        }
 
-        Ok(parsed_struct)
+        for (name, contract) in imported.contracts.into_iter() {
+            if is_imported(&name) {
+                if let Some(functions) = &contract.library_functions {
+                    self.libraries_map.insert(name.clone(), functions.clone());
+                }
+                self.symbols.current.contracts.insert(name, contract);
+            }
+        }
+        for constant in imported.constants.into_iter().filter(is_imported) {
+            self.members_map
+                .insert(constant.clone(), MemberType::Constant);
+            self.symbols.current.constants.insert(constant);
+        }
+        for value_type in imported.value_types.into_iter().filter(is_imported) {
+            self.value_types.insert(value_type.clone());
+            self.symbols.current.value_types.insert(value_type);
+        }
+        for error in imported
+            .errors
+            .into_iter()
+            .filter(|error| is_imported(&error.name))
+        {
+            self.errors_map
+                .insert(error.name.clone(), error.fields.clone());
+            if !self
+                .symbols
+                .current
+                .errors
+                .iter()
+                .any(|current| current.name == error.name)
+            {
+                self.symbols.current.errors.push(error);
+            }
+        }
+        // free functions and structs are not tracked, so we import the global items of the file
+        for globals in imported.globals.into_iter() {
+            self.imports
+                .insert(Import::ImportedGlobals(globals.clone()));
+            self.symbols.current.globals.insert(globals);
+        }
     }
 
-    /// Parses a Solang event definition to Sol2Ink evet definition
+    /// Exports the symbols visible in the parsed file to the files importing it
     ///
-    /// `event_definition` the Solang event definition
+    /// `path` the path to the parsed file
+    /// `globals` the items defined outside of contracts in the file
+    /// `globals_name` the name of the module of the global items, if there are any
+    fn export_symbols(&mut self, path: &str, globals: &Globals, globals_name: Option<&String>) {
+        let mut symbols = std::mem::take(&mut self.symbols.current);
+        symbols
+            .constants
+            .extend(globals.fields.iter().map(|field| field.name.clone()));
+        symbols.value_types.extend(
+            globals
+                .value_types
+                .iter()
+                .map(|value_type| value_type.name.clone()),
+        );
+        symbols.errors.splice(0..0, globals.errors.clone());
+        symbols.globals.extend(globals_name.cloned());
+        self.symbols
+            .files
+            .insert(normalize_path(Path::new(path)), symbols);
+    }
+
+    /// Collects the members of a contract, interface or library together with the members
+    /// inherited from its base contracts
     ///
-    /// Returns the parsed `Event` struct
-    fn parse_event(&mut self, event_definition: &EventDefinition) -> Result<Event, ParserError> {
-        let name = self.parse_identifier(&event_definition.name);
+    /// `contract_definition` the Solang contract definition
+    ///
+    /// Returns the collected `ContractSymbols`
+    fn contract_symbols(
+        &mut self,
+        contract_definition: &ContractDefinition,
+    ) -> Result<ContractSymbols, ParserError> {
+        let mut symbols = ContractSymbols::default();
+        let contract_name = self.parse_identifier(&contract_definition.name);
+        let mut bases = Vec::new();
 
-        let comments = self.get_comments(event_definition.loc.end());
-        let fields: Vec<EventField> = event_definition
-            .fields
-            .iter()
-            .filter_map(|variable_declaration| {
-                let event_field_name = self.parse_identifier(&variable_declaration.name);
-                let event_field = EventField {
-                    name: if event_field_name == "_" {
-                        String::from("anonymous")
-                    } else {
-                        event_field_name
-                    },
-                    field_type: self.parse_type(&variable_declaration.ty).ok()?,
-                    indexed: variable_declaration.indexed,
-                    comments: self.get_comments(variable_declaration.loc.end()),
-                };
-                Some(event_field)
-            })
-            .collect();
+        for base in contract_definition.base.iter() {
+            let base_name = self.resolve_symbol(&self.parse_identifier_path(&base.name));
+            if let Some(base_symbols) = self.symbols.current.contracts.get(&base_name) {
+                symbols.members.extend(base_symbols.members.clone());
+                symbols.modifiers.extend(base_symbols.modifiers.clone());
+                symbols.errors.extend(base_symbols.errors.clone());
+                symbols.overloads.extend(base_symbols.overloads.clone());
+                symbols.functions.extend(base_symbols.functions.clone());
+            }
+            bases.push(base_name);
+        }
+        symbols.linearization = self.linearize(&contract_name, &bases)?;
+        let mut overloads: HashMap<String, Vec<FunctionOverload>> = HashMap::new();
 
-        let parsed_event = Event {
-            name,
-            fields,
-            comments,
-        };
+        for part in contract_definition.parts.iter() {
+            match part {
+                ContractPart::VariableDefinition(variable_definition) => {
+                    let field_name = self.parse_identifier(&variable_definition.name);
+                    let field_type = self.parse_type(&variable_definition.ty)?;
+                    let field_type =
+                        self.storage_array_type(&contract_name, &field_name, field_type);
+                    let constant = variable_definition
+                        .attrs
+                        .iter()
+                        .any(|item| matches!(item, VariableAttribute::Constant(_)));
+                    symbols.members.insert(
+                        field_name,
+                        if constant {
+                            MemberType::Constant
+                        } else {
+                            MemberType::Variable(Box::new(field_type))
+                        },
+                    );
+                }
+                ContractPart::FunctionDefinition(function_definition) => {
+                    let fn_name = self.parse_identifier(&function_definition.name);
+                    if function_definition.ty == FunctionTy::Function {
+                        let overload = self.function_overload(&fn_name, function_definition)?;
+                        overloads.entry(fn_name.clone()).or_default().push(overload);
+                    }
+                    let external = function_definition.attributes.iter().any(|attribute| {
+                        matches!(
+                            attribute,
+                            FunctionAttribute::Visibility(Visibility::External(_))
+                                | FunctionAttribute::Visibility(Visibility::Public(_))
+                        )
+                    });
+                    match function_definition.ty {
+                        FunctionTy::Function => {
+                            symbols.members.insert(
+                                fn_name,
+                                if external {
+                                    MemberType::Function
+                                } else {
+                                    MemberType::FunctionPrivate
+                                },
+                            );
+                        }
+                        FunctionTy::Modifier => {
+                            symbols
+                                .modifiers
+                                .insert(fn_name, *function_definition.clone());
+                        }
+                        _ => (),
+                    }
+                }
+                ContractPart::ErrorDefinition(error_definition) => {
+                    symbols.errors.insert(
+                        error_name(self.parse_identifier(&error_definition.name)),
+                        self.parse_error_fields(error_definition)?,
+                    );
+                }
+                _ => (),
+            }
+        }
 
-        Ok(parsed_event)
+        // the functions defined in the contract replace the overload sets of its bases
+        for (fn_name, overload_set) in overloads {
+            if let [overload] = &overload_set[..] {
+                symbols.functions.insert(fn_name.clone(), overload.clone());
+            } else {
+                for overload in overload_set.iter() {
+                    symbols
+                        .functions
+                        .insert(overload.name.clone(), overload.clone());
+                }
+            }
+            if overload_set.len() > 1 {
+                symbols.overloads.insert(fn_name, overload_set);
+            } else {
+                symbols.overloads.remove(&fn_name);
+            }
+        }
+
+        if let ContractTy::Library(_) = contract_definition.ty {
+            symbols.library_functions = self
+                .libraries_map
+                .get(&self.parse_identifier(&contract_definition.name))
+                .cloned();
+        }
+
+        Ok(symbols)
     }
 
-    /// Parses a Solang enum definition to Sol2Ink enum definition
+    /// Registers the members of a contract, including the inherited ones, in the Parser's maps
+    /// and records them in the symbols of the parsed file
     ///
-    /// `enum_definition` the Solang enum definition
+    /// `contract_definition` the Solang contract definition
+    fn register_contract_symbols(
+        &mut self,
+        contract_definition: &ContractDefinition,
+    ) -> Result<(), ParserError> {
+        let symbols = self.contract_symbols(contract_definition)?;
+        self.members_map.extend(symbols.members.clone());
+        self.modifiers_map.extend(symbols.modifiers.clone());
+        self.errors_map.extend(symbols.errors.clone());
+        self.symbols
+            .current
+            .contracts
+            .insert(self.parse_identifier(&contract_definition.name), symbols);
+        Ok(())
+    }
+
+    /// Resolves the name of an imported symbol, which may be renamed or accessed through a namespace
     ///
-    /// Returns the parsed `Enum` struct
-    fn parse_enum(&mut self, enum_definition: &EnumDefinition) -> Result<Enum, ParserError> {
-        let name = self.parse_identifier(&enum_definition.name);
+    /// `name` the name of the symbol used in the parsed file
+    ///
+    /// Returns the original name of the symbol
+    fn resolve_symbol(&self, name: &str) -> String {
+        let name = match name.split_once("::") {
+            Some((namespace, symbol)) if self.symbols.namespaces.contains(namespace) => symbol,
+            _ => name,
+        };
+        self.symbols
+            .aliases
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| name.to_owned())
+    }
 
-        let comments = self.get_comments(enum_definition.loc.end());
-        let values: Vec<EnumValue> = enum_definition
-            .values
-            .iter()
-            .map(|enum_value| {
-                EnumValue {
-                    name: self.parse_identifier(enum_value),
-                    comments: if let Some(value) = enum_value {
-                        self.get_comments(value.loc.end())
-                    } else {
-                        Vec::default()
-                    },
+    /// Returns true if the expression is a namespace of an imported file, like `A` in `import "A.sol" as A`
+    ///
+    /// `expression` the Solang expression
+    fn is_namespace(&self, expression: &SolangExpression) -> bool {
+        match expression {
+            SolangExpression::Variable(namespace) => {
+                self.symbols.namespaces.contains(&namespace.name)
+            }
+            _ => false,
+        }
+    }
+
+    /// Registers the overload sets of the functions defined in a contract, interface or library
+    /// Functions sharing a name get a distinct name mangled from their parameter types,
+    /// the functions which are not overloaded keep their original name
+    ///
+    /// `contract_definition` the Solang contract definition
+    fn register_overloads(
+        &mut self,
+        contract_definition: &ContractDefinition,
+    ) -> Result<(), ParserError> {
+        let mut overloads: HashMap<String, Vec<FunctionOverload>> = HashMap::new();
+        let mut calldata_params = Vec::new();
+
+        for part in contract_definition.parts.iter() {
+            if let ContractPart::FunctionDefinition(function_definition) = part {
+                if function_definition.ty != FunctionTy::Function {
+                    continue
                 }
-            })
-            .collect();
+                let fn_name = self.parse_identifier(&function_definition.name);
+                let overload = self.function_overload(&fn_name, function_definition)?;
+                if !overload.external {
+                    let calldata = function_definition
+                        .params
+                        .iter()
+                        .map(|(_, param)| {
+                            matches!(
+                                param.as_ref().and_then(|param| param.storage.as_ref()),
+                                Some(StorageLocation::Calldata(_))
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    if calldata.contains(&true) {
+                        calldata_params.push((fn_name.clone(), overload.name.clone(), calldata));
+                    }
+                }
+                overloads.entry(fn_name).or_default().push(overload);
+            }
+        }
 
-        let parsed_enum = Enum {
-            name,
-            values,
-            comments,
-        };
+        for (fn_name, mangled_name, calldata) in calldata_params {
+            let name = if overloads[&fn_name].len() > 1 {
+                mangled_name
+            } else {
+                fn_name
+            };
+            self.calldata_params.insert(name, calldata);
+        }
 
-        Ok(parsed_enum)
+        for (fn_name, overload_set) in overloads {
+            if let [overload] = &overload_set[..] {
+                self.function_arities
+                    .insert(fn_name.clone(), overload.params.len());
+            } else {
+                for overload in overload_set.iter() {
+                    self.function_arities
+                        .insert(overload.name.clone(), overload.params.len());
+                }
+            }
+            if overload_set.len() > 1 {
+                self.overloads_map.insert(fn_name, overload_set);
+            } else {
+                self.overloads_map.remove(&fn_name);
+            }
+        }
+
+        Ok(())
     }
 
-    /// Parses a Solang storage variable definition to Sol2Ink contract field definition
+    /// Creates the overload of a function with its mangled name and parameter types
     ///
-    /// `variable_definition` the Solang variable definition
+    /// `fn_name` the original name of the function
+    /// `function_definition` the Solang function definition
     ///
-    /// Returns the parsed `ContractField` struct
-    fn parse_storage_field(
+    /// Returns the `FunctionOverload`
+    fn function_overload(
         &mut self,
-        variable_definition: &VariableDefinition,
-    ) -> Result<ContractField, ParserError> {
-        let field_type = self.parse_type(&variable_definition.ty)?;
-        let name = self.parse_identifier(&variable_definition.name);
-        let constant = variable_definition
-            .attrs
+        fn_name: &str,
+        function_definition: &FunctionDefinition,
+    ) -> Result<FunctionOverload, ParserError> {
+        let params = function_definition
+            .params
             .iter()
-            .any(|item| matches!(item, VariableAttribute::Constant(_)));
-        let public = variable_definition.attrs.iter().any(|item| {
+            .filter_map(|(_, param)| param.as_ref())
+            .map(|param| self.parse_type(&param.ty))
+            .collect::<Result<Vec<_>, _>>()?;
+        let storage = function_definition
+            .params
+            .iter()
+            .filter_map(|(_, param)| param.as_ref())
+            .map(|param| matches!(param.storage, Some(StorageLocation::Storage(_))))
+            .collect();
+        let returns = function_definition
+            .returns
+            .iter()
+            .filter_map(|(_, param)| param.as_ref())
+            .map(|param| self.parse_type(&param.ty))
+            .collect::<Result<Vec<_>, _>>()?;
+        let external = function_definition.attributes.iter().any(|attribute| {
             matches!(
-                item,
-                VariableAttribute::Visibility(Visibility::External(_))
-                    | VariableAttribute::Visibility(Visibility::Public(_))
+                attribute,
+                FunctionAttribute::Visibility(Visibility::External(_))
+                    | FunctionAttribute::Visibility(Visibility::Public(_))
             )
         });
-        let initial_value = variable_definition.initializer.as_ref().map(|expression| {
-            self.parse_expression(expression, VariableAccessLocation::Constructor)
-        });
-        let comments = self.get_comments(variable_definition.loc.end());
-        let contract_field = ContractField {
-            field_type,
-            name,
-            initial_value,
-            constant,
-            public,
-            comments,
-        };
-
-        Ok(contract_field)
+        Ok(FunctionOverload {
+            name: self.mangle_function_name(fn_name, function_definition),
+            params,
+            returns,
+            external,
+            storage,
+        })
     }
 
-    /// Parses a Solang function definition to Sol2Ink function definition
+    /// Creates a deterministic name of an overloaded function from its parameter types
+    /// `transfer(address,uint256)` becomes `transfer_address_uint256`
     ///
+    /// `fn_name` the original name of the function
     /// `function_definition` the Solang function definition
     ///
-    /// Returns the parsed `Function` struct
-    fn parse_function(
-        &mut self,
+    /// Returns the mangled name
+    fn mangle_function_name(
+        &self,
+        fn_name: &str,
         function_definition: &FunctionDefinition,
-    ) -> Result<Function, ParserError> {
-        let header = self.parse_function_header(function_definition);
-        let mut invalid_modifiers = HashMap::new();
-        for modifier in header.invalid_modifiers.clone() {
-            match modifier {
-                Expression::InvalidModifier(name, _) => {
-                    if let Some(function) = self.modifiers_map.clone().get(&name) {
-                        invalid_modifiers.insert(
-                            (header.name.clone(), name),
-                            Function {
-                                header: self.parse_function_header(function),
-                                body: if let Some(body) = &function.body {
-                                    Some(self.parse_statement(
-                                        body,
-                                        self.parse_variable_access_location(function_definition),
-                                    )?)
-                                } else {
-                                    None
-                                },
-                                ..Default::default()
-                            },
+    ) -> String {
+        let param_types = function_definition
+            .params
+            .iter()
+            .filter_map(|(_, param)| param.as_ref())
+            .map(|param| solidity_type_name(&param.ty))
+            .collect::<Vec<_>>();
+        if param_types.is_empty() {
+            fn_name.to_owned()
+        } else {
+            format!("{fn_name}_{}", param_types.join("_"))
+        }
+    }
+
+    /// Resolves a call of an overloaded function to the overload matching the provided arguments
+    ///
+    /// `fn_name` the original name of the called function
+    /// `args` the parsed arguments of the call
+    ///
+    /// Returns the matching overload or `None` if the function is not overloaded
+    fn resolve_overload(&self, fn_name: &str, args: &[Expression]) -> Option<FunctionOverload> {
+        select_overload(self.overloads_map.get(fn_name)?, args)
+    }
+
+    /// Resolves a call of a function of a deployed contract, like `token.transfer(to, amount)`,
+    /// to the mangled name of the overload if the function is overloaded
+    ///
+    /// `contract` the type of the called contract
+    /// `address` the address of the called contract
+    /// `function` the name of the called function
+    /// `args` the parsed arguments of the call
+    /// `value` the value transferred with the call
+    ///
+    /// Returns `Expression::ContractCall`
+    fn contract_call(
+        &self,
+        contract: String,
+        address: Box<Expression>,
+        function: String,
+        args: Vec<Expression>,
+        value: Option<Box<Expression>>,
+    ) -> Expression {
+        let function = self
+            .symbols
+            .current
+            .contracts
+            .get(&contract)
+            .and_then(|symbols| symbols.overloads.get(&function))
+            .and_then(|overload_set| select_overload(overload_set, &args))
+            .map(|overload| overload.name)
+            .unwrap_or(function);
+        let args = match self.function_signature(&contract, &function) {
+            Some(signature) => self.widen_args(args, &signature.params),
+            None => args,
+        };
+        Expression::ContractCall(contract, address, function, args, value)
+    }
+
+    /// Parses a call of a library function, the arguments are converted to the declared types
+    ///
+    /// `library` the name of the library
+    /// `function` the name of the called function
+    /// `args` the parsed arguments, starting with the value the function is bound to
+    ///
+    /// Returns `Expression::LibraryCall`
+    fn library_call(
+        &mut self,
+        library: String,
+        function: String,
+        args: Vec<Expression>,
+    ) -> Expression {
+        match self.function_signature(&library, &function).cloned() {
+            Some(signature) => {
+                let args = self.widen_args(args, &signature.params);
+                self.pass_storage_args(
+                    Expression::LibraryCall(library, function, args),
+                    &signature.storage,
+                )
+            }
+            None => Expression::LibraryCall(library, function, args),
+        }
+    }
+
+    /// Returns the called function of a contract, interface or library with its declared types
+    ///
+    /// `contract` the contract defining the function
+    /// `function` the name of the function, mangled if it is overloaded
+    fn function_signature(&self, contract: &str, function: &str) -> Option<&FunctionOverload> {
+        let functions = &self.symbols.current.contracts.get(contract)?.functions;
+        functions.get(function).or_else(|| {
+            // functions called through `super` are declared like the function overriding them
+            let overridden = self
+                .super_calls
+                .iter()
+                .find(|name| super_function(name, contract) == function)?;
+            functions.get(overridden)
+        })
+    }
+
+    /// Returns the declared types of a called function of the parsed contract
+    ///
+    /// `function` the parsed called function
+    fn called_function(&self, function: &Expression) -> Option<&FunctionOverload> {
+        match function {
+            Expression::Variable(name, MemberType::Function | MemberType::FunctionPrivate, _) => {
+                self.function_signature(&self.contract_name, name)
+            }
+            _ => None,
+        }
+    }
+
+    /// Passes the arguments of `storage` parameters by mutable reference and clones the values of
+    /// the storage passed to the other parameters, so the called function changes the stored values
+    /// Values of storage fields and mappings can not be borrowed while the contract is borrowed
+    /// by the call, so they are copied to a local before the call and written back after it
+    ///
+    /// `call` the parsed call with the arguments converted to the types of the parameters
+    /// `storage` which of the parameters are `storage` references
+    ///
+    /// Returns the parsed call
+    fn pass_storage_args(&mut self, mut call: Expression, storage: &[bool]) -> Expression {
+        let args = match &mut call {
+            Expression::FunctionCall(_, args, _) | Expression::LibraryCall(_, _, args) => args,
+            _ => return call,
+        };
+        let (mut copies, mut write_backs) = (Vec::new(), Vec::new());
+        let (mut copied, mut written_locals) = (HashSet::new(), HashSet::new());
+        for (i, arg) in args.iter_mut().enumerate() {
+            if !storage.get(i).copied().unwrap_or(false) {
+                if self.is_storage_value(arg)
+                    && self.expression_type(arg).is_some_and(|ty| !ty.is_copy())
+                {
+                    *arg = Expression::Clone(Box::new(arg.clone()));
+                }
+                continue
+            }
+            let local_root = match &*arg {
+                Expression::Variable(name, MemberType::None(_), _) => Some(name.clone()),
+                _ => place_root(arg).filter(|root| self.locals_map.contains_key(root)),
+            };
+            match local_root {
+                // `storage` parameters are mutable references already
+                Some(root)
+                    if self.storage_params.contains(&root)
+                        && matches!(arg, Expression::Variable(..)) => {}
+                Some(root) => {
+                    *arg = Expression::MutableReference(Box::new(arg.clone()));
+                    // `storage` locals are copies, which are written back to the storage
+                    let stored = self.storage_refs.get(&root).cloned();
+                    if let Some(stored) = stored.filter(|_| written_locals.insert(root.clone())) {
+                        let local = Expression::Variable(
+                            root,
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any,
+                        );
+                        write_backs.push(
+                            self.storage_write(Expression::Assign(
+                                Box::new(stored),
+                                Box::new(local),
+                            )),
                         );
                     }
                 }
-                _ => unreachable!("Only invalid modifiers are allowed here"),
+                None => {
+                    let mut name = entry_name(arg);
+                    if !copied.insert(name.clone()) {
+                        name = format!("{name}_{i}");
+                    }
+                    let local = Expression::Variable(
+                        name.clone(),
+                        MemberType::None(Box::new(Type::None)),
+                        VariableAccessLocation::Any,
+                    );
+                    let ty = self.expression_type(arg).unwrap_or(Type::None);
+                    copies.push(Statement::VariableDefinition(
+                        Expression::VariableDeclaration(Box::new(ty), name, None),
+                        Some(arg.clone()),
+                    ));
+                    write_backs.push(self.storage_write(Expression::Assign(
+                        Box::new(arg.clone()),
+                        Box::new(local.clone()),
+                    )));
+                    *arg = Expression::MutableReference(Box::new(local));
+                }
             }
         }
-
-        let body = if let Some(statement) = &function_definition.body {
-            Some(self.parse_statement(
-                statement,
-                self.parse_variable_access_location(function_definition),
-            )?)
+        if copies.is_empty() && write_backs.is_empty() {
+            call
         } else {
-            None
-        };
+            Expression::WriteBack(copies, Box::new(call), write_backs)
+        }
+    }
 
-        Ok(Function {
-            header,
-            body,
-            invalid_modifiers,
-        })
+    /// Returns true if the expression is a value of the storage, or a member or an element of it,
+    /// including the values pointed to by `storage` locals and parameters
+    ///
+    /// `expression` the parsed expression
+    fn is_storage_value(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Variable(_, MemberType::Variable(_), _) => true,
+            Expression::Variable(name, MemberType::None(_), _) => {
+                self.storage_params.contains(name) || self.storage_refs.contains_key(name)
+            }
+            Expression::MemberAccess(inner, _) | Expression::ArraySubscript(inner, _) => {
+                self.is_storage_value(inner)
+            }
+            _ => false,
+        }
     }
 
-    /// Parses a Sol2Ink function header definition from Solang function definition
+    /// Converts the arguments of a call to the declared types of the parameters, see [widen](fn@widen)
     ///
-    /// `function_definition` the Solang function definition
+    /// `args` the parsed arguments
+    /// `params` the types of the parameters
+    fn widen_args(&self, args: Vec<Expression>, params: &[Type]) -> Vec<Expression> {
+        args.into_iter()
+            .enumerate()
+            .map(|(index, arg)| self.widen(arg, params.get(index).cloned()))
+            .collect()
+    }
+
+    /// Replaces the called function with the resolved overload if the function is overloaded
     ///
-    /// Returns the parsed `FunctionHeader` struct
-    fn parse_function_header(
-        &mut self,
-        function_definition: &FunctionDefinition,
-    ) -> FunctionHeader {
-        let name = self.parse_identifier(&function_definition.name);
-        let params = function_definition
-            .params
-            .iter()
-            .map(|item| item.1.clone().unwrap())
-            .filter_map(|param| {
-                let name = self.parse_identifier(&param.name);
-                let param_type = self.parse_type(&param.ty).ok()?;
-                Some(FunctionParam { name, param_type })
-            })
-            .collect();
-        let all_modifiers: Vec<Expression> = function_definition
-            .attributes
-            .iter()
-            .filter(|&attribute| matches!(attribute, FunctionAttribute::BaseOrModifier(..)))
-            .map(|modifier| {
-                if let FunctionAttribute::BaseOrModifier(_, base) = modifier {
-                    let parsed_name = self.parse_identifier_path(&base.name);
-                    let parsed_args = if let Some(args) = &base.args {
-                        self.parse_expression_vec(args, VariableAccessLocation::Any)
-                    } else {
-                        Vec::default()
-                    };
-                    if parsed_args.iter().any(function_call_in_expression) {
-                        Expression::InvalidModifier(parsed_name, parsed_args)
+    /// `function` the parsed called function
+    /// `args` the parsed arguments of the call
+    ///
+    /// Returns the function with the mangled name of the overload
+    fn resolve_overloaded_call(&self, function: Expression, args: &[Expression]) -> Expression {
+        match function {
+            Expression::Variable(
+                name,
+                MemberType::Function | MemberType::FunctionPrivate,
+                location,
+            ) if let Some(overload) = self.resolve_overload(&name, args) => {
+                Expression::Variable(
+                    overload.name,
+                    if overload.external {
+                        MemberType::Function
                     } else {
-                        Expression::Modifier(parsed_name, parsed_args)
+                        MemberType::FunctionPrivate
+                    },
+                    location,
+                )
+            }
+            _ => function,
+        }
+    }
+
+    /// Borrows the arguments passed to `calldata` parameters of internal functions,
+    /// since the parameters are borrowed slices or references
+    ///
+    /// `function` the called function
+    /// `args` the parsed arguments
+    ///
+    /// Returns the arguments of the call
+    fn borrow_calldata_args(
+        &self,
+        function: &Expression,
+        args: Vec<Expression>,
+    ) -> Vec<Expression> {
+        match function {
+            Expression::Variable(name, MemberType::FunctionPrivate, _)
+                if let Some(calldata) = self.calldata_params.get(name) =>
+            {
+                args.into_iter()
+                    .enumerate()
+                    .map(|(i, arg)| {
+                        if calldata.get(i).copied().unwrap_or(false) {
+                            Expression::Reference(Box::new(arg))
+                        } else {
+                            arg
+                        }
+                    })
+                    .collect()
+            }
+            _ => args,
+        }
+    }
+
+    /// Wraps a function used as a value, like `_add` in `apply(x, _add)`, in a function pointer
+    /// which takes the contract as its first argument, the expressions which are not functions
+    /// are returned unchanged
+    ///
+    /// `expression` the parsed expression
+    fn function_value(&self, expression: Expression) -> Expression {
+        match &expression {
+            Expression::Variable(name, MemberType::Function | MemberType::FunctionPrivate, _) => {
+                let arity = self.function_arities.get(name).copied();
+                Expression::FunctionPointer(Box::new(expression), arity)
+            }
+            Expression::Variable(name, MemberType::None(_), _)
+                if !self.locals_map.contains_key(name)
+                    && let Some(arity) = self.free_functions.get(name) =>
+            {
+                Expression::FunctionPointer(Box::new(expression), Some(*arity))
+            }
+            _ => expression,
+        }
+    }
+
+    /// Registers the names of the functions defined in a library,
+    /// so only calls to existing functions are bound to the library
+    ///
+    /// `contract_definition` the Solang library definition
+    fn register_library(&mut self, contract_definition: &ContractDefinition) {
+        let functions = contract_definition
+            .parts
+            .iter()
+            .filter_map(|part| {
+                match part {
+                    ContractPart::FunctionDefinition(function_definition)
+                        if function_definition.ty == FunctionTy::Function =>
+                    {
+                        Some(self.parse_identifier(&function_definition.name))
                     }
-                } else {
-                    unreachable!("The vec was filtered before");
+                    _ => None,
                 }
             })
             .collect();
-        let modifiers = all_modifiers
-            .iter()
-            .filter(|expression| matches!(expression, Expression::Modifier(..)))
-            .cloned()
-            .collect();
-        let invalid_modifiers = all_modifiers
-            .iter()
-            .filter(|expression| matches!(expression, Expression::InvalidModifier(..)))
-            .cloned()
-            .collect();
-        let external = function_definition.attributes.iter().any(|attribute| {
-            matches!(
-                attribute,
-                FunctionAttribute::Visibility(Visibility::External(_))
-                    | FunctionAttribute::Visibility(Visibility::Public(_))
-            )
-        });
-        let view = function_definition.attributes.iter().any(|attribute| {
-            matches!(
-                attribute,
-                FunctionAttribute::Mutability(Mutability::Pure(_))
-                    | FunctionAttribute::Mutability(Mutability::View(_))
-            )
-        });
-        let payable = function_definition.attributes.iter().any(|attribute| {
-            matches!(
-                attribute,
-                FunctionAttribute::Mutability(Mutability::Payable(_))
-            )
-        });
-        let return_params = function_definition
-            .returns
+        self.libraries_map
+            .insert(self.parse_identifier(&contract_definition.name), functions);
+    }
+
+    /// Registers a `using Library for Type` directive
+    /// Directives attaching free functions are skipped, since these are not assembled as libraries
+    ///
+    /// `using` the Solang using directive
+    fn register_using(&mut self, using: &Using) -> Result<(), ParserError> {
+        let ty = using
+            .ty
+            .as_ref()
+            .map(|ty| self.parse_type(ty))
+            .transpose()?;
+        match &using.list {
+            UsingList::Library(library) => {
+                self.usings.push(UsingDirective {
+                    library: self.resolve_symbol(&self.parse_identifier_path(library)),
+                    function: None,
+                    ty,
+                });
+            }
+            UsingList::Functions(functions) => {
+                for function in functions.iter() {
+                    if let [.., library, function] = &function.identifiers[..] {
+                        self.usings.push(UsingDirective {
+                            library: library.name.clone(),
+                            function: Some(function.name.clone()),
+                            ty: ty.clone(),
+                        });
+                    }
+                }
+            }
+            UsingList::Error() => (),
+        }
+        Ok(())
+    }
+
+    /// Binds the operators of a global using directive, like `using {add as +} for Price global`,
+    /// to the value type the directive is attached to
+    ///
+    /// `using` the Solang using directive
+    /// `operators` the operators bound to functions, removed from the source before parsing
+    /// `source_unit_parts` the parts of the file, used to find out if the bound function is unary
+    /// `value_types` the value types defined in the file
+    fn bind_operators(
+        &self,
+        using: &Using,
+        operators: &HashMap<String, String>,
+        source_unit_parts: &[SourceUnitPart],
+        value_types: &mut [ValueType],
+    ) {
+        if let (UsingList::Functions(functions), Some(SolangExpression::Variable(ty))) =
+            (&using.list, &using.ty)
+        {
+            if let Some(value_type) = value_types
+                .iter_mut()
+                .find(|value_type| value_type.name == ty.name)
+            {
+                for function in functions
+                    .iter()
+                    .filter_map(|function| function.identifiers.last())
+                {
+                    if let Some(operator) = operators.get(&function.name) {
+                        let unary = source_unit_parts.iter().any(|part| {
+                            matches!(
+                                part,
+                                SourceUnitPart::FunctionDefinition(function_definition)
+                                    if self.parse_identifier(&function_definition.name) == function.name
+                                        && function_definition.params.len() == 1
+                            )
+                        });
+                        value_type.operators.push(OperatorBinding {
+                            operator: operator.clone(),
+                            function: function.name.clone(),
+                            unary,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the library a function call on `receiver` is bound to by a using directive
+    /// If the functions of the library are known, the library must define the called function,
+    /// otherwise the type of the receiver must be known and match the type the library is attached to
+    ///
+    /// `receiver` the parsed expression the function is called on
+    /// `function` the name of the called function
+    fn bound_library(&self, receiver: &Expression, function: &str) -> Option<String> {
+        let receiver_type = self.expression_type(receiver);
+        self.usings
             .iter()
-            .map(|item| item.1.clone().unwrap())
-            .filter_map(|param| {
-                let name = self.parse_identifier(&param.name);
-                let param_type = self.parse_type(&param.ty).ok()?;
-                Some(FunctionParam { name, param_type })
+            .find(|using| {
+                let function_matches = using
+                    .function
+                    .as_ref()
+                    .is_none_or(|bound_function| bound_function == function);
+                let type_matches = match (&using.ty, &receiver_type) {
+                    (Some(ty), Some(receiver_type)) => types_match(ty, receiver_type),
+                    _ => true,
+                };
+                let library_matches = match self.libraries_map.get(&using.library) {
+                    Some(functions) => functions.contains(function),
+                    None => receiver_type.is_some(),
+                };
+                function_matches && type_matches && library_matches
             })
-            .collect();
+            .map(|using| using.library.clone())
+    }
 
-        FunctionHeader {
-            name,
-            params,
-            external,
-            view,
-            payable,
-            return_params,
-            modifiers,
-            invalid_modifiers,
-            comments: self.get_comments(function_definition.loc.end()),
+    /// Returns true if the name is a library defined in the file or attached by a using directive
+    ///
+    /// `name` the name of the called contract or library
+    fn is_library(&self, name: &str) -> bool {
+        self.libraries_map.contains_key(name)
+            || self.usings.iter().any(|using| using.library == name)
+    }
+
+    /// Returns true if the name is a contract or an interface defined in the parsed file or imported,
+    /// whose values are addresses of deployed contracts
+    ///
+    /// `name` the resolved name of the type
+    fn is_contract_type(&self, name: &str) -> bool {
+        if self.is_library(name) {
+            return false
         }
+        self.contract_types.contains(name)
+            || self
+                .symbols
+                .current
+                .contracts
+                .get(name)
+                .is_some_and(|symbols| symbols.library_functions.is_none())
     }
 
-    /// Parses from where a variable will be accessed
-    /// This affects if we call `Self::var`, `T::var` or `self.var`
+    /// Returns the contract type, the address and the name of the called function
+    /// if the expression is a function of a deployed contract, like `token.transfer`
     ///
-    /// `function_definition` the Solang function definition
+    /// `function` the parsed called function
+    fn contract_member(&self, function: &Expression) -> Option<(String, Box<Expression>, String)> {
+        match function {
+            Expression::MemberAccess(address, member)
+                if let Some(Type::Contract(contract)) = self.expression_type(address) =>
+            {
+                Some((contract, address.clone(), member.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses a low-level call of an address, like `token.call(abi.encodeWithSelector(SELECTOR, to, value))`
+    /// Only calls whose data is encoded with `abi.encodeWithSelector` or `abi.encodeWithSignature`
+    /// are parsed, since ink! calls are built from the selector and the arguments
     ///
-    /// Returns the corresponding `VariableAccessLocation` enum variant
-    fn parse_variable_access_location(
+    /// `receiver` the called address
+    /// `member` the name of the called member, `call`, `staticcall` or `delegatecall`
+    /// `args` the parsed arguments of the call
+    /// `value` the value transferred with the call
+    /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns `Expression::LowLevelCall` or `None` if the call is not a low-level call
+    fn low_level_call(
         &self,
-        function_definition: &FunctionDefinition,
-    ) -> VariableAccessLocation {
-        match function_definition.ty {
-            FunctionTy::Constructor => VariableAccessLocation::Constructor,
-            FunctionTy::Modifier => VariableAccessLocation::Modifier,
-            _ => VariableAccessLocation::Any,
+        receiver: &Expression,
+        member: &str,
+        args: &[Expression],
+        value: Option<Box<Expression>>,
+        location: &VariableAccessLocation,
+    ) -> Option<Expression> {
+        let call_type = match member {
+            "call" => CallType::Call,
+            "staticcall" => CallType::StaticCall,
+            "delegatecall" => CallType::DelegateCall,
+            _ => return None,
+        };
+        if !self.is_account(receiver) {
+            return None
         }
+        let (selector, encoded) = match args {
+            [Expression::AbiEncode(_, Some(selector), encoded)] => (selector, encoded),
+            _ => return None,
+        };
+        Some(Expression::LowLevelCall(
+            call_type,
+            Box::new(receiver.clone()),
+            selector.clone(),
+            encoded.iter().map(|(value, _)| value.clone()).collect(),
+            value.map(|value| Box::new(self.to_balance(*value))),
+            location.clone(),
+        ))
     }
 
-    /// Parses a Solang statement enum variant to Sol2Ink statement enum variant
+    /// Returns the ink! selector of the message called with a Solidity function signature
+    /// ink! computes the selectors of the messages of a trait from the names of the trait and the message,
+    /// so the trait is the most base contract or interface defining the function, the message
+    /// is selected by its name alone if the trait is not known
     ///
-    /// `statement` the original Solang statement enum variant
-    /// `location` the location where the statement is [being called](fn@parse_variable_access_location)
+    /// `signature` the signature of the function, like `transfer(address,uint256)`
     ///
-    /// Returns the parsed `Statement` enum variant
-    fn parse_statement(
-        &mut self,
-        statement: &SolangStatement,
-        location: VariableAccessLocation,
-    ) -> Result<Statement, ParserError> {
-        Ok(match statement {
-            SolangStatement::Block {
-                loc: _,
-                unchecked,
-                statements,
-            } => {
-                let parsed_statements = statements
-                    .iter()
-                    .map(|statement| self.parse_statement(statement, location.clone()))
-                    .map(|result| result.unwrap())
-                    .collect::<Vec<_>>();
-                if *unchecked {
-                    Statement::UncheckedBlock(parsed_statements)
-                } else {
-                    Statement::Block(parsed_statements)
-                }
-            }
-            SolangStatement::Assembly {
-                loc: _,
-                dialect: _,
-                flags: _,
-                block: _,
-            } => Statement::Assembly,
-            SolangStatement::Args(_, _) => {
-                println!("{statement:?}");
-                todo!()
-            }
-            SolangStatement::If(_, expression, if_true, if_false) => {
-                let parsed_expression = self.parse_expression(expression, location.clone());
-                let parsed_if_true = Box::new(self.parse_statement(if_true, location.clone())?);
-                let parsed_if_false = if_false
-                    .as_ref()
-                    .map(|statement| self.parse_statement(statement, location.clone()))
-                    .map(|result| Box::new(result.unwrap()));
-                Statement::If(parsed_expression, parsed_if_true, parsed_if_false)
-            }
-            SolangStatement::While(_, expression, statement) => {
-                let parsed_expression = self.parse_expression(expression, location.clone());
-                let parsed_statement = Box::new(self.parse_statement(statement, location)?);
-                Statement::While(parsed_expression, parsed_statement)
-            }
-            SolangStatement::Expression(_, expression) => {
-                let parsed_expression = self.parse_expression(expression, location);
-                Statement::Expression(parsed_expression)
-            }
-            SolangStatement::VariableDefinition(_, declaration, initial_value_maybe) => {
-                let parsed_name = self.parse_identifier(&declaration.name).to_case(Snake);
-                let parsed_type = Box::new(self.parse_type(&declaration.ty)?);
-                let parsed_declaration = Expression::VariableDeclaration(parsed_type, parsed_name);
-                let parsed_initial_value = initial_value_maybe
-                    .as_ref()
-                    .map(|expression| self.parse_expression(expression, location));
-
-                Statement::VariableDefinition(parsed_declaration, parsed_initial_value)
-            }
-            SolangStatement::For(_, variable_definition, condition, on_pass, body) => {
-                let parsed_variable_definition = variable_definition
-                    .as_ref()
-                    .map(|statement| self.parse_statement(statement, location.clone()))
-                    .map(|result| Box::new(result.unwrap()));
-                let parsed_condition = condition
-                    .as_ref()
-                    .map(|expression| self.parse_expression(expression, location.clone()));
-                let parsed_on_pass = on_pass
-                    .as_ref()
-                    .map(|statement| self.parse_statement(statement, location.clone()))
-                    .map(|result| Box::new(result.unwrap()));
-                let parsed_body = body
-                    .as_ref()
-                    .map(|statement| self.parse_statement(statement, location))
-                    .map(|result| Box::new(result.unwrap()));
+    /// Returns `Expression::Selector` of the message
+    fn message_selector(&self, signature: &str) -> Expression {
+        let function = signature.split('(').next().unwrap_or_default();
+        let message = function.to_case(Snake);
+        let contracts = &self.symbols.current.contracts;
+        let definitions = contracts
+            .iter()
+            .filter(|(_, symbols)| {
+                symbols.library_functions.is_none()
+                    && symbols.members.get(function) == Some(&MemberType::Function)
+            })
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        let roots = definitions
+            .iter()
+            .filter(|name| {
+                contracts[**name]
+                    .linearization
+                    .iter()
+                    .skip(1)
+                    .all(|base| !definitions.contains(&base))
+            })
+            .collect::<Vec<_>>();
+        match roots[..] {
+            [contract] => Expression::Selector(format!("{contract}::{message}")),
+            _ => Expression::Selector(message),
+        }
+    }
 
-                Statement::For(
-                    parsed_variable_definition,
-                    parsed_condition,
-                    parsed_on_pass,
-                    parsed_body,
-                )
-            }
-            SolangStatement::DoWhile(_, body, condition) => {
-                let parsed_condition = self.parse_expression(condition, location.clone());
-                let parsed_body = Box::new(self.parse_statement(body, location)?);
-                Statement::DoWhile(parsed_body, parsed_condition)
+    /// Parses a call of a global function of Solidity, like `keccak256(data)`
+    /// or `ecrecover(digest, v, r, s)`, unless a function of the same name is defined
+    ///
+    /// `function` the parsed called function
+    /// `args` the parsed arguments of the call
+    ///
+    /// Returns the parsed call or `None` if the called function is not a global function
+    fn builtin_call(&mut self, function: &Expression, args: &[Expression]) -> Option<Expression> {
+        let (name, location) = match function {
+            Expression::Variable(name, MemberType::None(_), location) => (name.as_str(), location),
+            _ => return None,
+        };
+        match (name, args) {
+            ("keccak256", [input]) => {
+                Some(Expression::Hash(
+                    HashFunction::Keccak256,
+                    Box::new(input.clone()),
+                    location.clone(),
+                ))
             }
-            SolangStatement::Continue(_) => Statement::Continue,
-            SolangStatement::Break(_) => Statement::Break,
-            SolangStatement::Return(_, expression) => {
-                let parsed_expression = expression
-                    .as_ref()
-                    .map(|expression| self.parse_expression(expression, location));
-                Statement::Return(parsed_expression)
+            ("sha256", [input]) => {
+                Some(Expression::Hash(
+                    HashFunction::Sha256,
+                    Box::new(input.clone()),
+                    location.clone(),
+                ))
             }
-            SolangStatement::Revert(_, identifier_path, args) => {
-                let identifier_path = identifier_path
-                    .as_ref()
-                    .map(|identifier_path| self.parse_identifier_path(identifier_path))
-                    .unwrap_or(String::from("_"));
-                let parsed_args = self.parse_expression_vec(args, location);
-                Statement::Revert(identifier_path, parsed_args)
+            // `gasleft()` is `msg.gas` of older Solidity versions
+            ("gasleft", []) => self.parse_global("msg", "gas", location),
+            ("ripemd160", [_]) => {
+                Some(Expression::Unsupported(String::from(
+                    "ripemd160 is not available in ink!",
+                )))
             }
-            SolangStatement::RevertNamedArgs(_, _, _) => todo!(),
-            SolangStatement::Emit(_, expression) => {
-                let parsed_expression = self.parse_expression(expression, location);
-                Statement::Emit(parsed_expression)
+            // the hashes of blocks are not available, like the hashes of blocks older than 256 blocks
+            ("blockhash", [_]) => {
+                Some(Expression::Substitute(
+                    String::from("blockhash is not available in ink!"),
+                    Box::new(Type::Bytes(32)),
+                    None,
+                ))
             }
-            SolangStatement::Try(_, expression, _, _) => {
-                let parsed_expression = self.parse_expression(expression, location);
-                Statement::Try(parsed_expression)
+            ("ecrecover", [digest, v, r, s]) => {
+                Some(Expression::EcRecover(
+                    Box::new(digest.clone()),
+                    Box::new(v.clone()),
+                    Box::new(r.clone()),
+                    Box::new(s.clone()),
+                    location.clone(),
+                ))
             }
-            SolangStatement::Error(_) => todo!(),
-        })
+            _ => None,
+        }
     }
 
-    /// Parses a Solang expression enum variant to Sol2Ink expression enum variant
+    /// Parses a member of the `msg`, `block` and `tx` globals which has no equivalent in ink!,
+    /// the member is replaced by a substitute marked for review.
+    /// Members with an equivalent in ink!, like `msg.sender`, are translated by the assembler
     ///
-    /// `expression` the original Solang expression enum variant
+    /// `global` the name of the global
+    /// `member` the name of the member
     /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
     ///
-    /// Returns the parsed `Expression` enum variant
-    fn parse_expression(
+    /// Returns `Expression::Substitute` or `None` if the member has an equivalent in ink!
+    fn parse_global(
         &mut self,
-        expression: &SolangExpression,
-        location: VariableAccessLocation,
-    ) -> Expression {
-        macro_rules! maybe_boxed_expression {
-            ($to_declare:ident,$to_parse:expr) => {
-                let $to_declare = $to_parse.as_ref().map(|expression| {
-                    Box::new(self.parse_expression(&expression, location.clone()))
-                });
+        global: &str,
+        member: &str,
+        location: &VariableAccessLocation,
+    ) -> Option<Expression> {
+        let substitute = |note: &str, ty: Type| {
+            Some(Expression::Substitute(
+                String::from(note),
+                Box::new(ty),
+                None,
+            ))
+        };
+        // the numbers are `uint256` in Solidity
+        let uint = Type::Uint(self.convert_int_bits(&256));
+        let parsed_global =
+            match (global, member) {
+                ("msg", "sig") => substitute("msg.sig is not available in ink!", Type::Bytes(4)),
+                ("msg", "data") => {
+                    substitute(
+                        "msg.data is not available in ink!, the input of a message is decoded",
+                        Type::DynamicBytes,
+                    )
+                }
+                ("block", "chainid") => match self.chain_id {
+                    Some(chain_id) => {
+                        let chain_id = Expression::NumberLiteral(chain_id.to_string());
+                        let chain_id = match uint {
+                            Type::Uint(256) => {
+                                Expression::FunctionCall(
+                                    Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                                    vec![chain_id],
+                                    None,
+                                )
+                            }
+                            _ => chain_id,
+                        };
+                        Some(Expression::Substitute(
+                            String::from("block.chainid is the chain id set with --chain-id"),
+                            Box::new(uint),
+                            Some(Box::new(chain_id)),
+                        ))
+                    }
+                    None => substitute(
+                        "block.chainid is not available in ink!, set the chain id with --chain-id",
+                        uint,
+                    ),
+                },
+                ("block", "basefee") | ("tx", "gasprice") => {
+                    substitute(
+                        &format!(
+                            "{global}.{member} is not available in ink!, fees are paid for weight"
+                        ),
+                        uint,
+                    )
+                }
+                ("block", "coinbase") => {
+                    substitute("block.coinbase is not available in ink!", Type::AccountId)
+                }
+                ("block", "difficulty" | "prevrandao" | "gaslimit") => {
+                    substitute(&format!("block.{member} is not available in ink!"), uint)
+                }
+                // the block numbers, timestamps, balances and gas of ink! are narrower than the Solidity integer
+                ("block", "timestamp" | "number") | ("msg", "value" | "gas") => {
+                    Some(Expression::FunctionCall(
+                        Box::new(Expression::Type(Box::new(uint))),
+                        vec![Expression::MemberAccess(
+                            Box::new(Expression::Variable(
+                                String::from(global),
+                                MemberType::None(Box::new(Type::None)),
+                                location.clone(),
+                            )),
+                            String::from(member),
+                        )],
+                        None,
+                    ))
+                }
+                // the caller is not the origin of the transaction, so the access has to be rewritten
+                ("tx", "origin") => {
+                    Some(Expression::Unsupported(String::from(
+                        "tx.origin is not available in ink!",
+                    )))
+                }
+                _ => None,
             };
+        if parsed_global
+            .as_ref()
+            .and_then(|global| self.expression_type(global))
+            == Some(Type::Uint(256))
+        {
+            self.imports.insert(Import::U256);
         }
+        parsed_global
+    }
 
-        macro_rules! boxed_expression {
-            ($to_declare:ident,$to_parse:expr) => {
-                let $to_declare = Box::new(self.parse_expression($to_parse, location.clone()));
-            };
+    /// Returns true if the expression is an address, payable or not
+    ///
+    /// `expression` the parsed expression
+    fn is_account(&self, expression: &Expression) -> bool {
+        matches!(
+            self.expression_type(expression),
+            Some(Type::AccountId | Type::PayableAccountId)
+        )
+    }
+
+    /// Converts an amount of the native currency to `Balance`, which is a 128 bit integer
+    ///
+    /// `amount` the parsed amount
+    fn to_balance(&self, amount: Expression) -> Expression {
+        match self.expression_type(&amount) {
+            // balances widened to the Solidity integer, like `msg.value`, are used as they are
+            Some(_)
+                if let Expression::FunctionCall(function, args, None) = &amount
+                    && matches!(**function, Expression::Type(_))
+                    && args.len() == 1
+                    && self.expression_type(&args[0]) == Some(Type::Uint(128)) =>
+            {
+                args[0].clone()
+            }
+            Some(Type::Uint(256)) => Expression::Balance(Box::new(amount)),
+            _ => amount,
         }
+    }
 
+    /// Returns the type of an expression, if it can be found without a full type check
+    ///
+    /// `expression` the parsed expression
+    fn expression_type(&self, expression: &Expression) -> Option<Type> {
         match expression {
-            SolangExpression::PostIncrement(_, expression) => {
-                boxed_expression!(parsed_expression, expression);
-                Expression::PostIncrement(parsed_expression)
-            }
-            SolangExpression::PostDecrement(_, expression) => {
-                boxed_expression!(parsed_expression, expression);
-                Expression::PostDecrement(parsed_expression)
+            Expression::Variable(_, MemberType::Variable(ty) | MemberType::None(ty), _)
+                if **ty != Type::None =>
+            {
+                Some(*ty.clone())
             }
-            SolangExpression::New(_, expression) => {
-                boxed_expression!(parsed_expression, expression);
-                Expression::New(parsed_expression)
+            Expression::Variable(name, MemberType::None(_), _) => {
+                self.locals_map.get(name).cloned()
             }
-            SolangExpression::ArraySubscript(_, array, index_maybe) => {
-                match *array.clone() {
-                    SolangExpression::ArraySubscript(..) => {
-                        self.array_subscript_to_mapping_subscript(array, index_maybe, location)
-                    }
-                    SolangExpression::MemberAccess(_, expression, _) => {
-                        let parsed_expresion = self.parse_expression(&expression, location.clone());
-                        match parsed_expresion {
-                            Expression::MappingSubscript(..) => {
-                                self.array_subscript_to_mapping_subscript(
-                                    array,
-                                    index_maybe,
-                                    location,
-                                )
-                            }
-                            _ => {
-                                boxed_expression!(parsed_array, array);
-                                maybe_boxed_expression!(parsed_index_maybe, index_maybe);
-                                Expression::ArraySubscript(parsed_array, parsed_index_maybe)
-                            }
-                        }
-                    }
-                    SolangExpression::Variable(identifier) => {
-                        let parsed_identifier = self.parse_identifier(&Some(identifier));
-                        match self.members_map.get(&parsed_identifier) {
-                            Some(ty) => {
-                                match ty {
-                                    MemberType::Variable(variable_type)
-                                    | MemberType::None(variable_type) => {
-                                        match *variable_type.clone() {
-                                            Type::Mapping(_, _) => {
-                                                self.array_subscript_to_mapping_subscript(
-                                                    array,
-                                                    index_maybe,
-                                                    location,
-                                                )
-                                            }
-                                            _ => {
-                                                boxed_expression!(parsed_array, array);
-                                                maybe_boxed_expression!(
-                                                    parsed_index_maybe,
-                                                    index_maybe
-                                                );
-                                                Expression::ArraySubscript(
-                                                    parsed_array,
-                                                    parsed_index_maybe,
-                                                )
-                                            }
-                                        }
-                                    }
-                                    _ => {
-                                        boxed_expression!(parsed_array, array);
-                                        maybe_boxed_expression!(parsed_index_maybe, index_maybe);
-                                        Expression::ArraySubscript(parsed_array, parsed_index_maybe)
-                                    }
-                                }
-                            }
-                            None => {
-                                boxed_expression!(parsed_array, array);
-                                maybe_boxed_expression!(parsed_index_maybe, index_maybe);
-                                Expression::ArraySubscript(parsed_array, parsed_index_maybe)
-                            }
-                        }
-                    }
-                    _ => {
-                        boxed_expression!(parsed_array, array);
-                        maybe_boxed_expression!(parsed_index_maybe, index_maybe);
-                        Expression::ArraySubscript(parsed_array, parsed_index_maybe)
-                    }
-                }
+            Expression::FunctionCall(function, ..) if let Expression::Type(ty) = &**function => {
+                Some(*ty.clone())
             }
-            SolangExpression::ArraySlice(_, exp, left, right) => {
-                boxed_expression!(parsed_exp, exp);
-                maybe_boxed_expression!(parsed_left, left);
-                maybe_boxed_expression!(parsed_right, right);
-
-                Expression::ArraySlice(parsed_exp, parsed_left, parsed_right)
+            Expression::FunctionCall(function, ..)
+                if let Some(signature) = self.called_function(function) =>
+            {
+                single_type(&signature.returns)
             }
-            SolangExpression::Parenthesis(_, expression) => {
-                boxed_expression!(parsed_expression, expression);
-                Expression::Parenthesis(parsed_expression)
+            Expression::LibraryCall(library, function, _)
+                if let Some(signature) = self.function_signature(library, function) =>
+            {
+                single_type(&signature.returns)
             }
-            SolangExpression::MemberAccess(_, expression, identifier) => {
-                boxed_expression!(parsed_expression, expression);
-                let parsed_identifier = self.parse_identifier(&Some(identifier.clone()));
-                Expression::MemberAccess(parsed_expression, parsed_identifier)
+            Expression::ContractCall(contract, _, function, ..) => {
+                single_type(&self.function_signature(contract, function)?.returns)
             }
-            SolangExpression::FunctionCall(_, function, args) => {
-                let parsed_args = self.parse_expression_vec(args, location.clone());
-
-                if let SolangExpression::FunctionCallBlock(_, function, parameters) =
-                    *function.clone()
-                {
-                    if let SolangStatement::Args(_, arguments) = *parameters {
-                        let value_argument = arguments
-                            .iter()
-                            .map(|argument| {
-                                let parsed_argument =
-                                    self.parse_expression(&argument.expr, location.clone());
-                                let parsed_name =
-                                    self.parse_identifier(&Some(argument.name.clone()));
-                                (parsed_name, parsed_argument)
-                            })
-                            .find(|(name, _)| name == "value")
-                            .map(|option| Box::new(option.1));
-                        boxed_expression!(parsed_function, &function);
-                        return Expression::FunctionCall(
-                            parsed_function,
-                            parsed_args,
-                            value_argument,
-                        )
-                    }
-                    unreachable!("Only function is allowed here!");
-                } else {
-                    boxed_expression!(parsed_function, function);
-                    match *parsed_function.clone() {
-                        Expression::Type(ty) if let Type::AccountId = *ty.clone() => {
-                            if parsed_args.len() > 1 {
-                                unreachable!("Multiple parameters were provided to `address` call")
-                            }
-                            let account_id = &parsed_args[0];
-                            match account_id {
-                                Expression::NumberLiteral(number) if number == "0" => {
-                                    self.imports.insert(Import::ZeroAddress);
-                                }
-                                _ => (),
-                            }
-                        }
-                        _ => ()
-                    }
-                    Expression::FunctionCall(parsed_function, parsed_args, None)
+            Expression::UnaryMinus(value) => self.expression_type(value),
+            Expression::MappingSubscript(mapping, _) => {
+                match self.expression_type(mapping) {
+                    Some(Type::Mapping(_, value) | Type::LazyArray(_, value)) => Some(*value),
+                    _ => None,
                 }
             }
-            SolangExpression::FunctionCallBlock(_, _, _) => Expression::None,
-            SolangExpression::NamedFunctionCall(_, expression, arguments) => {
-                boxed_expression!(parsed_expression, expression);
-                if let Expression::Variable(_, MemberType::Function, _) = *parsed_expression.clone()
-                {
-                    let parsed_arguments = arguments
-                        .iter()
-                        .map(|argument| self.parse_expression(&argument.expr, location.clone()))
-                        .collect();
-                    Expression::FunctionCall(parsed_expression, parsed_arguments, None)
-                } else {
-                    let parsed_arguments = arguments
-                        .iter()
-                        .map(|argument| {
-                            let parsed_argument =
-                                self.parse_expression(&argument.expr, location.clone());
-                            let parsed_name = self.parse_identifier(&Some(argument.name.clone()));
-                            (parsed_name, parsed_argument)
-                        })
-                        .collect();
-                    Expression::NamedFunctionCall(parsed_expression, parsed_arguments)
+            Expression::ArraySubscript(array, Some(_)) => {
+                match self.expression_type(array) {
+                    Some(Type::Array(ty, _)) => Some(*ty),
+                    _ => None,
                 }
             }
-            SolangExpression::Not(_, expression) => {
-                boxed_expression!(parsed_expression, expression);
-                Expression::Not(parsed_expression)
-            }
-            SolangExpression::Complement(_, exp) => {
-                boxed_expression!(parsed_expression, exp);
-                Expression::Not(parsed_expression)
+            Expression::MemberAccess(expression, member)
+                if let Some(Type::Variable(name)) = self.expression_type(expression) =>
+            {
+                self.struct_fields.get(&name)?.get(member).cloned()
             }
-            SolangExpression::Delete(_, expression) => {
-                boxed_expression!(parsed_expression, expression);
-                Expression::Delete(parsed_expression)
+            Expression::MemberAccess(expression, member)
+                if let Expression::Variable(name, ..) = &**expression =>
+            {
+                match (name.as_str(), member.as_str()) {
+                    ("msg", "sender") | ("tx", "origin") => Some(Type::AccountId),
+                    ("msg", "value") => Some(Type::Uint(128)),
+                    _ => None,
+                }
             }
-            SolangExpression::PreIncrement(_, expression) => {
-                boxed_expression!(parsed_expression, expression);
-                Expression::PreIncrement(parsed_expression)
+            // functions bound to a type usually return the same type, like `a.add(b).mul(c)`
+            Expression::LibraryCall(_, _, args) => self.expression_type(args.first()?),
+            Expression::Parenthesis(expression) => self.expression_type(expression),
+            Expression::Truncate(ty, _) | Expression::Cast(ty, _) => Some(*ty.clone()),
+            Expression::Balance(_) => Some(Type::Uint(128)),
+            Expression::Instantiate(contract, ..) => Some(Type::Contract(contract.clone())),
+            Expression::AbiEncode(..) | Expression::AbiEncodePacked(_) => Some(Type::DynamicBytes),
+            Expression::Hash(..) => Some(Type::Bytes(32)),
+            Expression::Substitute(_, ty, _) => Some(*ty.clone()),
+            Expression::EcRecover(..) => Some(Type::AccountId),
+            Expression::AbiDecode(_, _, types) if types.len() == 1 => types.first().cloned(),
+            // only the length of lazy arrays is not converted to the Solidity integer
+            Expression::Length(array) => {
+                match self.expression_type(array) {
+                    Some(Type::LazyArray(index, _)) => Some(*index),
+                    _ => None,
+                }
             }
-            SolangExpression::PreDecrement(_, expression) => {
-                boxed_expression!(parsed_expression, expression);
-                Expression::PreDecrement(parsed_expression)
+            Expression::Checked(operation) | Expression::Wrapping(operation) => {
+                self.expression_type(operation)
             }
-            SolangExpression::UnaryPlus(_, exp) => {
-                boxed_expression!(parsed_expression, exp);
-                Expression::UnaryPlus(parsed_expression)
+            // number literals take the type of the other operand
+            Expression::Add(left, right)
+            | Expression::Subtract(left, right)
+            | Expression::Multiply(left, right)
+            | Expression::Divide(left, right)
+            | Expression::Modulo(left, right)
+            | Expression::Power(left, right)
+            | Expression::BitwiseAnd(left, right)
+            | Expression::BitwiseOr(left, right)
+            | Expression::BitwiseXor(left, right)
+                if let Expression::NumberLiteral(_) = **left =>
+            {
+                self.expression_type(right)
             }
-            SolangExpression::UnaryMinus(_, exp) => {
-                boxed_expression!(parsed_expression, exp);
-                Expression::UnaryMinus(parsed_expression)
-            }
-            SolangExpression::Power(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Power(parsed_left, parsed_right)
-            }
-            SolangExpression::Multiply(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Multiply(parsed_left, parsed_right)
-            }
-            SolangExpression::Divide(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Divide(parsed_left, parsed_right)
-            }
-            SolangExpression::Modulo(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Modulo(parsed_left, parsed_right)
-            }
-            SolangExpression::Add(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Add(parsed_left, parsed_right)
-            }
-            SolangExpression::Subtract(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Subtract(parsed_left, parsed_right)
-            }
-            SolangExpression::ShiftLeft(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::ShiftLeft(parsed_left, parsed_right)
-            }
-            SolangExpression::ShiftRight(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::ShiftRight(parsed_left, parsed_right)
-            }
-            SolangExpression::BitwiseAnd(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::BitwiseAnd(parsed_left, parsed_right)
-            }
-            SolangExpression::BitwiseXor(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::BitwiseXor(parsed_left, parsed_right)
-            }
-            SolangExpression::BitwiseOr(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::BitwiseOr(parsed_left, parsed_right)
-            }
-            SolangExpression::Less(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Less(parsed_left, parsed_right)
-            }
-            SolangExpression::More(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::More(parsed_left, parsed_right)
-            }
-            SolangExpression::LessEqual(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::LessEqual(parsed_left, parsed_right)
-            }
-            SolangExpression::MoreEqual(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::MoreEqual(parsed_left, parsed_right)
-            }
-            SolangExpression::Equal(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Equal(parsed_left, parsed_right)
-            }
-            SolangExpression::NotEqual(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::NotEqual(parsed_left, parsed_right)
-            }
-            SolangExpression::And(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::And(parsed_left, parsed_right)
-            }
-            SolangExpression::Or(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Or(parsed_left, parsed_right)
-            }
-            SolangExpression::ConditionalOperator(_, condition, if_true, if_false) => {
-                boxed_expression!(parsed_condition, condition);
-                boxed_expression!(parsed_if_true, if_true);
-                boxed_expression!(parsed_if_false, if_false);
-                Expression::Ternary(parsed_condition, parsed_if_true, parsed_if_false)
-            }
-            SolangExpression::Assign(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::Assign(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignOr(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignOr(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignAnd(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignAnd(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignXor(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignXor(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignShiftLeft(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignShiftLeft(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignShiftRight(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignShiftRight(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignAdd(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignAdd(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignSubtract(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignSubtract(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignMultiply(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignMultiply(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignDivide(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignDivide(parsed_left, parsed_right)
-            }
-            SolangExpression::AssignModulo(_, left, right) => {
-                boxed_expression!(parsed_left, left);
-                boxed_expression!(parsed_right, right);
-                Expression::AssignModulo(parsed_left, parsed_right)
-            }
-            SolangExpression::BoolLiteral(_, value) => Expression::BoolLiteral(*value),
-            SolangExpression::NumberLiteral(_, literal, exponent) => {
-                if !exponent.is_empty() {
-                    let literal = literal.parse::<i128>().unwrap();
-                    let exponent = exponent.parse::<u32>().unwrap();
-                    let out = literal * 10_i128.pow(exponent);
-                    Expression::NumberLiteral(out.to_string())
-                } else {
-                    Expression::NumberLiteral(literal.clone())
-                }
-            }
-            SolangExpression::RationalNumberLiteral(_, integer_exp, float_exp, exp) => {
-                let float_len = float_exp.len();
-                let integer_exp = integer_exp.parse::<f64>().unwrap();
-                let float_exp = float_exp.parse::<f64>().unwrap_or(0_f64);
-                let exp = exp.parse::<i32>().unwrap_or(0);
+            Expression::Add(left, _)
+            | Expression::Subtract(left, _)
+            | Expression::Multiply(left, _)
+            | Expression::Divide(left, _)
+            | Expression::Modulo(left, _)
+            | Expression::Power(left, _)
+            | Expression::BitwiseAnd(left, _)
+            | Expression::BitwiseOr(left, _)
+            | Expression::BitwiseXor(left, _) => self.expression_type(left),
+            Expression::NumberLiteral(_) | Expression::HexLiteral(_) => Some(Type::Uint(128)),
+            Expression::BoolLiteral(_) => Some(Type::Bool),
+            Expression::StringLiteral(_) => Some(Type::String),
+            _ => None,
+        }
+    }
 
-                let result =
-                    (integer_exp + float_exp * 10_f64.powi(-(float_len as i32))) * 10_f64.powi(exp);
+    /// Returns the type of the elements if the expression is a dynamic array, including lazy arrays
+    ///
+    /// `expression` the parsed expression
+    fn dynamic_array_element(&self, expression: &Expression) -> Option<Type> {
+        match self.expression_type(expression)? {
+            Type::Array(element, None) | Type::LazyArray(_, element) => Some(*element),
+            Type::DynamicBytes => Some(Type::Uint(8)),
+            _ => None,
+        }
+    }
 
-                Expression::NumberLiteral(result.to_string())
-            }
-            SolangExpression::HexNumberLiteral(_, hex_number) => {
-                Expression::HexLiteral(hex_number.clone())
+    /// Creates a subscript of an array, converting the index to `usize` since Rust arrays
+    /// and vectors are indexed by `usize`
+    ///
+    /// `array` the parsed array
+    /// `index_maybe` the parsed index
+    ///
+    /// Returns the parsed `Expression::ArraySubscript`
+    fn array_subscript(
+        &self,
+        array: Box<Expression>,
+        index_maybe: Option<Box<Expression>>,
+    ) -> Expression {
+        let index_maybe = match (self.expression_type(&array), index_maybe) {
+            (Some(Type::Array(..) | Type::DynamicBytes), Some(index)) => {
+                Some(Box::new(self.to_usize(index)))
             }
-            SolangExpression::StringLiteral(strings) => {
-                let parsed_strings = strings
-                    .iter()
-                    .map(|string_literal| string_literal.string.clone())
-                    .collect();
-                Expression::StringLiteral(parsed_strings)
+            (_, index_maybe) => index_maybe,
+        };
+        Expression::ArraySubscript(array, index_maybe)
+    }
+
+    /// Converts an integer expression to `usize`, used for indices and sizes of arrays
+    ///
+    /// `expression` the parsed integer expression
+    ///
+    /// Returns the converted expression
+    fn to_usize(&self, expression: Box<Expression>) -> Expression {
+        match (&*expression, self.expression_type(&expression)) {
+            (Expression::NumberLiteral(_), _) => *expression,
+            // literals and lengths converted to the Solidity integer are `usize` already
+            (Expression::FunctionCall(_, args, _), Some(Type::Uint(256)))
+                if let [argument @ (Expression::NumberLiteral(_) | Expression::Length(_))] =
+                    &args[..] =>
+            {
+                argument.clone()
             }
-            SolangExpression::Type(_, solidity_type) => {
-                let parsed_type = Box::new(self.convert_solidity_type(solidity_type));
-                Expression::Type(parsed_type)
+            (Expression::Cast(_, length), _) if let Expression::Length(_) = **length => {
+                *length.clone()
             }
-            SolangExpression::HexLiteral(hex_vec) => {
-                let literal = hex_vec
-                    .iter()
-                    .map(|hex| hex.hex.clone())
-                    .collect::<Vec<_>>()
-                    .join("");
-                Expression::HexLiteral(literal)
+            (_, Some(Type::Uint(256))) => Expression::Truncate(Box::new(Type::Usize), expression),
+            (_, Some(Type::Uint(_) | Type::Int(_))) => {
+                Expression::Cast(Box::new(Type::Usize), expression)
             }
-            SolangExpression::AddressLiteral(_, literal) => Expression::HexLiteral(literal.clone()),
-            SolangExpression::Variable(identifier) => {
-                let parsed_identifier = self.parse_identifier(&Some(identifier.clone()));
-                if parsed_identifier == "_" {
-                    return Expression::ModifierBody
+            _ => *expression,
+        }
+    }
+
+    /// Writes a value changed through a copy back to the storage, since values read from a mapping
+    /// and values pointed to by `storage` locals are copies of the stored values
+    /// `balances[user].amount += x` reads the struct to a local, changes the local and inserts it back
+    ///
+    /// `expression` the parsed expression statement
+    ///
+    /// Returns the parsed `Statement`
+    fn storage_write(&mut self, mut expression: Expression) -> Statement {
+        let target = match assignment_target(&mut expression) {
+            Some(target) => target,
+            None => return Statement::Expression(expression),
+        };
+        if let Some(entry) = mapping_entry(target).cloned() {
+            let name = entry_name(&entry);
+            let local = Expression::Variable(
+                name.clone(),
+                MemberType::None(Box::new(Type::None)),
+                VariableAccessLocation::Any,
+            );
+            replace_expression(target, &entry, &local);
+            let ty = self.expression_type(&entry).unwrap_or(Type::None);
+            return Statement::Block(vec![
+                Statement::VariableDefinition(
+                    Expression::VariableDeclaration(Box::new(ty), name, None),
+                    Some(entry.clone()),
+                ),
+                self.storage_write(expression),
+                self.storage_write(Expression::Assign(Box::new(entry), Box::new(local))),
+            ])
+        }
+        let assigned_local = match &**target {
+            Expression::Variable(name, ..) => Some(name.clone()),
+            _ => None,
+        };
+        match (assigned_local, place_root(target)) {
+            // assigning to a `storage` local makes it point to another value
+            (Some(name), _) if self.storage_refs.contains_key(&name) => {
+                if let Expression::Assign(_, value) = &expression {
+                    self.storage_refs.insert(name, *value.clone());
                 }
-                let none = MemberType::None(Box::new(Type::None));
-                let member_type = self.members_map.get(&parsed_identifier).unwrap_or(&none);
-                Expression::Variable(parsed_identifier, member_type.clone(), location)
-            }
-            SolangExpression::List(_, parameters) => {
-                let list = parameters
-                    .iter()
-                    .map(|tuple| tuple.1.clone())
-                    .map(|parameter_maybe| {
-                        let name = self.parse_identifier(
-                            &parameter_maybe
-                                .map(|parameter| parameter.name)
-                                .unwrap_or(None),
-                        );
-                        Expression::Variable(
-                            name,
-                            MemberType::None(Box::new(Type::None)),
-                            location.clone(),
-                        )
-                    })
-                    .collect();
-                Expression::List(list)
+                Statement::Expression(expression)
             }
-            SolangExpression::ArrayLiteral(_, content) => {
-                let list = self.parse_expression_vec(content, location);
-                Expression::ArrayLiteral(list)
-            }
-            SolangExpression::Unit(_, exp, unit) => {
-                let constant: i128 = match unit {
-                    Unit::Seconds(_) => 1,
-                    Unit::Minutes(_) => 60,
-                    Unit::Hours(_) => 3600,
-                    Unit::Days(_) => 86400,
-                    Unit::Weeks(_) => 604800,
-                    Unit::Wei(_) => 1,
-                    Unit::Gwei(_) => 1_000_000_000,
-                    Unit::Ether(_) => 1_000_000_000_000_000_000,
-                };
-
-                boxed_expression!(parsed_exp, exp);
-
-                Expression::Unit(parsed_exp, constant)
+            (_, Some(root)) if self.storage_refs.contains_key(&root) => {
+                let stored = self.storage_refs[&root].clone();
+                let local = Expression::Variable(
+                    root,
+                    MemberType::None(Box::new(Type::None)),
+                    VariableAccessLocation::Any,
+                );
+                Statement::Block(vec![
+                    Statement::Expression(expression),
+                    self.storage_write(Expression::Assign(Box::new(stored), Box::new(local))),
+                ])
             }
-            SolangExpression::This(_) => Expression::This(location),
+            _ => Statement::Expression(expression),
         }
     }
 
-    /// Parses multiple Solang expression enum variants to Sol2Ink expression enum variants
+    /// Parses a tuple destructuring, `(uint a, , uint c) = pair()` declares the named slots
+    /// and `(a, b) = (b, a)` reads the values to temporaries before assigning them,
+    /// so the targets may be storage fields or mapping entries and may appear on both sides
     ///
-    /// `expressions` the original Solang expression enum variants
-    /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
+    /// `slots` the slots of the destructured tuple, omitted slots are `None`
+    /// `value` the destructured value
+    /// `location` the location where the statement is [being called](fn@parse_variable_access_location)
     ///
-    /// Returns the vec of parsed `Expression` enum variant
-    fn parse_expression_vec(
+    /// Returns the parsed `Statement`
+    fn parse_destructuring(
         &mut self,
-        expressions: &[SolangExpression],
+        slots: &[(Loc, Option<Parameter>)],
+        value: &SolangExpression,
         location: VariableAccessLocation,
-    ) -> Vec<Expression> {
-        expressions
+    ) -> Result<Statement, ParserError> {
+        let parsed_value = self.parse_expression(value, location.clone());
+        let declaration = slots
             .iter()
-            .map(|expression| self.parse_expression(expression, location.clone()))
-            .collect()
+            .any(|(_, slot)| matches!(slot, Some(parameter) if parameter.name.is_some()));
+        if declaration {
+            let mut declarations = Vec::new();
+            for (_, slot) in slots {
+                match slot {
+                    Some(parameter) => {
+                        let name = self.parse_identifier(&parameter.name);
+                        let ty = self.parse_type(&parameter.ty)?;
+                        self.locals_map.insert(name.clone(), ty.clone());
+                        if let Some(bits) = declared_int_bits(&parameter.ty) {
+                            self.int_bits.insert(name.clone(), bits);
+                        }
+                        self.storage_refs.remove(&name);
+                        declarations.push(Expression::VariableDeclaration(
+                            Box::new(ty),
+                            name.to_case(Snake),
+                            data_location(&parameter.storage),
+                        ));
+                    }
+                    None => declarations.push(Expression::None),
+                }
+            }
+            return Ok(Statement::VariableDefinition(
+                Expression::List(declarations),
+                Some(parsed_value),
+            ))
+        }
+
+        let mut temporaries = Vec::new();
+        let mut assignments = Vec::new();
+        for (index, (_, slot)) in slots.iter().enumerate() {
+            match slot {
+                Some(parameter) => {
+                    let name = format!("tuple_{index}");
+                    let temporary = Expression::Variable(
+                        name.clone(),
+                        MemberType::None(Box::new(Type::None)),
+                        VariableAccessLocation::Any,
+                    );
+                    let target = self.parse_expression(&parameter.ty, location.clone());
+                    temporaries.push(Expression::VariableDeclaration(
+                        Box::new(Type::None),
+                        name,
+                        None,
+                    ));
+                    assignments.push(
+                        self.storage_write(Expression::Assign(
+                            Box::new(target),
+                            Box::new(temporary),
+                        )),
+                    );
+                }
+                None => temporaries.push(Expression::None),
+            }
+        }
+        let mut statements = vec![Statement::VariableDefinition(
+            Expression::List(temporaries),
+            Some(parsed_value),
+        )];
+        statements.append(&mut assignments);
+        Ok(Statement::Block(statements))
     }
 
-    /// Converts a Solang `Expression::ArraySubscript` enum variant to Sol2Ink `Expression::MappingSubscript` enum variant
-    /// We do this to differentiate between arrays and mappings
+    /// Parses a Solang struct definition to Sol2Ink struct definition
     ///
-    /// `array` the array we want to convert to mapping
-    /// `index_maybe` the index we were accessing within the ArraySubscript
-    /// `expression` the original Solang expression enum variant
-    /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
+    /// `struct_definition` the Solang struct definition
     ///
-    /// Returns the parsed `Expression` enum variant
-    fn array_subscript_to_mapping_subscript(
+    /// Returns the parsed `Struct` struct
+    fn parse_struct(
         &mut self,
-        array: &SolangExpression,
-        index_maybe: &Option<Box<SolangExpression>>,
-        location: VariableAccessLocation,
-    ) -> Expression {
-        let mut parsed_indices = VecDeque::default();
-        parsed_indices.push_back(
-            self.parse_expression(&index_maybe.as_ref().unwrap().clone(), location.clone()),
+        struct_definition: &StructDefinition,
+    ) -> Result<Struct, ParserError> {
+        let name = self.parse_identifier(&struct_definition.name);
+
+        let comments = self.get_comments(struct_definition.loc.end());
+
+        let fields: Vec<StructField> = struct_definition
+            .fields
+            .iter()
+            .filter_map(|variable_declaration| {
+                let name = self.parse_identifier(&variable_declaration.name);
+                let ty = self.parse_type(&variable_declaration.ty).ok()?;
+                self.members_map
+                    .insert(name.clone(), MemberType::None(Box::new(ty.clone())));
+                let struct_field = StructField {
+                    name,
+                    field_type: ty,
+                    comments: Default::default(),
+                };
+                Some(struct_field)
+            })
+            .collect();
+        self.struct_fields.insert(
+            name.clone(),
+            fields
+                .iter()
+                .map(|field| (field.name.clone(), field.field_type.clone()))
+                .collect(),
         );
-        let mut array_now = array.clone();
-        while let SolangExpression::ArraySubscript(_, array, index_maybe) = array_now {
-            parsed_indices
-                .push_back(self.parse_expression(&index_maybe.unwrap(), location.clone()));
-            array_now = *array.clone();
-        }
-        let mut vec_indices = Vec::default();
-        while !parsed_indices.is_empty() {
-            vec_indices.push(parsed_indices.pop_back().unwrap());
-        }
 
-        let parsed_array = Box::new(self.parse_expression(&array_now, location));
-        Expression::MappingSubscript(parsed_array, vec_indices)
+        let parsed_struct = Struct {
+            name,
+            fields,
+            comments,
+        };
+
+        Ok(parsed_struct)
     }
 
-    /// Parses a Solang `IdentifierPath` struct to String
+    /// Parses a Solang user defined value type, like `type Price is uint128`
     ///
-    /// `identifier_path` the original Solang identifier
+    /// `type_definition` the Solang type definition
     ///
-    /// Returns the parsed `String`
-    fn parse_identifier_path(&self, identifier_path: &IdentifierPath) -> String {
-        identifier_path
-            .identifiers
-            .iter()
-            .map(|identifier| identifier.name.clone())
-            .collect::<Vec<String>>()
-            .join("::")
+    /// Returns the parsed `ValueType` struct
+    fn parse_value_type(
+        &mut self,
+        type_definition: &TypeDefinition,
+    ) -> Result<ValueType, ParserError> {
+        let comments = self.get_comments(type_definition.loc.end());
+
+        Ok(ValueType {
+            name: type_definition.name.name.clone(),
+            ty: self.parse_type(&type_definition.ty)?,
+            operators: Default::default(),
+            comments,
+        })
     }
 
-    /// Converts a Solang `Expression::Type` enum variant to Sol2Ink `Type` enum variant
-    /// We do this to convert some Solidity specific types into ink! specific types
+    /// Parses a Solang event definition to Sol2Ink evet definition
     ///
-    /// `ty` the origina Solang `Expression::Type` enum variant
+    /// `event_definition` the Solang event definition
     ///
-    /// Returns the parsed `Type` enum variant
-    fn parse_type(&mut self, ty: &SolangExpression) -> Result<Type, ParserError> {
-        match &ty {
-            SolangExpression::Type(_, SolangType::Mapping(_, key_type, value_type)) => {
-                let mut parsed_key_types = vec![self.parse_type(key_type)?];
-                let mut value_type_now = value_type.as_ref();
-                while let SolangExpression::Type(
-                    _,
-                    SolangType::Mapping(_, key_type_value, value_type_value),
-                ) = value_type_now
-                {
-                    parsed_key_types.push(self.parse_type(key_type_value)?);
-                    value_type_now = value_type_value;
-                }
-                let parsed_value_type = self.parse_type(value_type_now)?;
-                self.imports.insert(Import::Mapping);
-                Ok(Type::Mapping(parsed_key_types, Box::new(parsed_value_type)))
-            }
-            SolangExpression::Type(_, SolangType::Function { .. }) => {
-                todo!("Function as a parameter")
-            }
-            SolangExpression::Type(_, solidity_type) => {
-                let converted_type = self.convert_solidity_type(solidity_type);
-                match converted_type {
-                    Type::Array(..) => self.imports.insert(Import::Vec),
-                    Type::AccountId => self.imports.insert(Import::AccountId),
-                    Type::String => self.imports.insert(Import::String),
-                    Type::DynamicBytes => self.imports.insert(Import::Vec),
-                    Type::Mapping(_, _) => self.imports.insert(Import::Mapping),
-                    _ => true,
+    /// Returns the parsed `Event` struct
+    fn parse_event(&mut self, event_definition: &EventDefinition) -> Result<Event, ParserError> {
+        let name = self.parse_identifier(&event_definition.name);
+
+        let comments = self.get_comments(event_definition.loc.end());
+        let fields: Vec<EventField> = event_definition
+            .fields
+            .iter()
+            .filter_map(|variable_declaration| {
+                let event_field_name = self.parse_identifier(&variable_declaration.name);
+                let event_field = EventField {
+                    name: if event_field_name == "_" {
+                        String::from("anonymous")
+                    } else {
+                        event_field_name
+                    },
+                    field_type: self.parse_type(&variable_declaration.ty).ok()?,
+                    indexed: variable_declaration.indexed,
+                    comments: self.get_comments(variable_declaration.loc.end()),
                 };
-                Ok(converted_type)
-            }
-            SolangExpression::Variable(identifier) => Ok(Type::Variable(identifier.name.clone())),
-            SolangExpression::ArraySubscript(_, ty, expression_maybe) => {
-                let parsed_type = Box::new(self.parse_type(ty)?);
-                let parsed_expression = expression_maybe
-                    .as_ref()
-                    .map(|option| self.parse_expression(option, VariableAccessLocation::Any));
-                Ok(Type::Array(parsed_type, parsed_expression))
-            }
-            SolangExpression::MemberAccess(_, from, identifier) => {
-                let parsed_expression = self.parse_expression(from, VariableAccessLocation::Any);
-                let parsed_identifier = self.parse_identifier(&Some(identifier.clone()));
-                Ok(Type::MemberAccess(parsed_expression, parsed_identifier))
-            }
-            _ => {
-                println!("{ty:?}");
-                Err(ParserError::IncorrectTypeOfVariable)
-            }
-        }
+                Some(event_field)
+            })
+            .collect();
+
+        let parsed_event = Event {
+            name,
+            fields,
+            comments,
+        };
+
+        Ok(parsed_event)
     }
 
-    /// Converts a Solang `Type` enum variant to Sol2Ink `Type` enum variant
-    /// We do this to convert some Solidity specific types into ink! specific types
+    /// Parses a Solang enum definition to Sol2Ink enum definition
     ///
-    /// `ty` the origina Solang `Type` enum variant
+    /// `enum_definition` the Solang enum definition
     ///
-    /// Returns the parsed `Type` enum variant
-    fn convert_solidity_type(&self, solidity_type: &SolangType) -> Type {
-        match solidity_type {
-            SolangType::Address | SolangType::AddressPayable => Type::AccountId,
-            SolangType::Bool => Type::Bool,
-            SolangType::String => Type::String,
-            SolangType::Int(original_bytes) => Type::Int(self.convert_int_bits(original_bytes)),
-            SolangType::Uint(original_bytes) => Type::Uint(self.convert_int_bits(original_bytes)),
-            SolangType::Bytes(length) => Type::Bytes(*length),
-            SolangType::DynamicBytes => Type::DynamicBytes,
-            _ => Type::None,
-        }
+    /// Returns the parsed `Enum` struct
+    fn parse_enum(&mut self, enum_definition: &EnumDefinition) -> Result<Enum, ParserError> {
+        let name = self.parse_identifier(&enum_definition.name);
+
+        let comments = self.get_comments(enum_definition.loc.end());
+        let values: Vec<EnumValue> = enum_definition
+            .values
+            .iter()
+            .map(|enum_value| {
+                EnumValue {
+                    name: self.parse_identifier(enum_value),
+                    comments: if let Some(value) = enum_value {
+                        self.get_comments(value.loc.end())
+                    } else {
+                        Vec::default()
+                    },
+                }
+            })
+            .collect();
+
+        let parsed_enum = Enum {
+            name,
+            values,
+            comments,
+        };
+
+        Ok(parsed_enum)
     }
 
-    /// Converts a Solidity integer size into rust integer size
-    /// Possible variants in Rust are 8, 16, 32, 64 and 128, we choose the nearest possible to fit
-    /// If the original size is greater than 128 bits, we make it 128 bits
+    /// Registers the fields of a Solang error definition,
+    /// so revert statements can construct the error before it was parsed
     ///
-    /// `original_bits` the origina size of the integer
+    /// `error_definition` the Solang error definition
+    fn register_error(&mut self, error_definition: &ErrorDefinition) -> Result<(), ParserError> {
+        let name = error_name(self.parse_identifier(&error_definition.name));
+        let fields = self.parse_error_fields(error_definition)?;
+        self.errors_map.insert(name, fields);
+        Ok(())
+    }
+
+    /// Parses the fields of a Solang error definition
     ///
-    /// Returns the converted integer size
-    fn convert_int_bits(&self, original_bits: &u16) -> u16 {
-        match *original_bits {
-            i if i <= 8 => 8,
-            i if i <= 16 => 16,
-            i if i <= 32 => 32,
-            i if i <= 64 => 64,
-            _ => 128,
-        }
+    /// `error_definition` the Solang error definition
+    ///
+    /// Returns the vec of parsed `ErrorField` structs
+    fn parse_error_fields(
+        &mut self,
+        error_definition: &ErrorDefinition,
+    ) -> Result<Vec<ErrorField>, ParserError> {
+        error_definition
+            .fields
+            .iter()
+            .map(|error_parameter| {
+                Ok(ErrorField {
+                    field_type: self.parse_type(&error_parameter.ty)?,
+                    name: self.parse_identifier(&error_parameter.name),
+                })
+            })
+            .collect()
     }
 
-    /// Parses a Solang `Identifier` struct to String
+    /// Parses a Solang error definition to Sol2Ink error definition
     ///
-    /// `identifier` the original Solang identifier
+    /// `error_definition` the Solang error definition
     ///
-    /// Returns the parsed `String`
-    fn parse_identifier(&self, identifier: &Option<Identifier>) -> String {
-        match identifier {
-            Some(identifier) => identifier.name.clone(),
-            None => String::from("_"),
-        }
+    /// Returns the parsed `Error` struct
+    fn parse_error(&mut self, error_definition: &ErrorDefinition) -> Result<Error, ParserError> {
+        let name = error_name(self.parse_identifier(&error_definition.name));
+
+        let comments = self.get_comments(error_definition.loc.end());
+        let fields = self.parse_error_fields(error_definition)?;
+
+        let parsed_error = Error {
+            name,
+            fields,
+            comments,
+        };
+
+        Ok(parsed_error)
     }
-}
 
-fn function_call_in_expression(expresion: &Expression) -> bool {
-    match expresion {
-        Expression::Add(expr1, expr2)
-        | Expression::And(expr1, expr2)
-        | Expression::Assign(expr1, expr2)
-        | Expression::AssignAdd(expr1, expr2)
-        | Expression::AssignDivide(expr1, expr2)
-        | Expression::AssignModulo(expr1, expr2)
-        | Expression::AssignMultiply(expr1, expr2)
-        | Expression::AssignSubtract(expr1, expr2)
-        | Expression::Divide(expr1, expr2)
-        | Expression::Equal(expr1, expr2)
-        | Expression::Less(expr1, expr2)
-        | Expression::LessEqual(expr1, expr2)
-        | Expression::Modulo(expr1, expr2)
-        | Expression::More(expr1, expr2)
-        | Expression::MoreEqual(expr1, expr2)
-        | Expression::Multiply(expr1, expr2)
-        | Expression::NotEqual(expr1, expr2)
-        | Expression::Or(expr1, expr2)
-        | Expression::Subtract(expr1, expr2)
-        | Expression::ShiftLeft(expr1, expr2)
-        | Expression::ShiftRight(expr1, expr2)
-        | Expression::BitwiseAnd(expr1, expr2)
-        | Expression::BitwiseXor(expr1, expr2)
-        | Expression::BitwiseOr(expr1, expr2)
-        | Expression::AssignOr(expr1, expr2)
-        | Expression::AssignAnd(expr1, expr2)
-        | Expression::AssignXor(expr1, expr2)
-        | Expression::AssignShiftLeft(expr1, expr2)
-        | Expression::AssignShiftRight(expr1, expr2)
+    /// Parses a Solang storage variable definition to Sol2Ink contract field definition
+    ///
+    /// `variable_definition` the Solang variable definition
+    ///
+    /// Returns the parsed `ContractField` struct
+    fn parse_storage_field(
+        &mut self,
+        variable_definition: &VariableDefinition,
+    ) -> Result<ContractField, ParserError> {
+        // the locals of the previously parsed function are not visible in the initial value
+        self.locals_map.clear();
+        let field_type = self.parse_type(&variable_definition.ty)?;
+        let name = self.parse_identifier(&variable_definition.name);
+        let constant = variable_definition
+            .attrs
+            .iter()
+            .any(|item| matches!(item, VariableAttribute::Constant(_)));
+        let public = variable_definition.attrs.iter().any(|item| {
+            matches!(
+                item,
+                VariableAttribute::Visibility(Visibility::External(_))
+                    | VariableAttribute::Visibility(Visibility::Public(_))
+            )
+        });
+        // overflows in constants are compile time errors, so constants are computed without checks
+        let arithmetic = self.arithmetic;
+        if constant {
+            self.arithmetic = Arithmetic::Plain;
+        }
+        let initial_value = variable_definition.initializer.as_ref().map(|expression| {
+            let parsed_expression =
+                self.parse_expression(expression, VariableAccessLocation::Constructor);
+            self.widen(parsed_expression, Some(field_type.clone()))
+        });
+        self.arithmetic = arithmetic;
+        if let Some(value) = initial_value
+            .as_ref()
+            .filter(|_| constant)
+            .and_then(|initial_value| self.constant_value(initial_value))
+        {
+            self.constant_values.insert(name.clone(), value);
+        }
+        let comments = self.get_comments(variable_definition.loc.end());
+        let contract_field = ContractField {
+            field_type,
+            name,
+            initial_value,
+            constant,
+            public,
+            comments,
+        };
+
+        Ok(contract_field)
+    }
+
+    /// Parses a Solang function definition to Sol2Ink function definition
+    ///
+    /// `function_definition` the Solang function definition
+    ///
+    /// Returns the parsed `Function` struct
+    fn parse_function(
+        &mut self,
+        function_definition: &FunctionDefinition,
+    ) -> Result<Function, ParserError> {
+        let header = self.parse_function_header(function_definition);
+        self.locals_map.clear();
+        self.storage_refs.clear();
+        self.creation_codes.clear();
+        self.int_bits.clear();
+        self.storage_params = header
+            .params
+            .iter()
+            .filter(|param| param.location == Some(DataLocation::Storage))
+            .map(|param| param.name.clone())
+            .collect();
+        for (_, param) in function_definition
+            .params
+            .iter()
+            .chain(function_definition.returns.iter())
+        {
+            if let Some(param) = param {
+                if let Some(bits) = declared_int_bits(&param.ty) {
+                    self.int_bits
+                        .insert(self.parse_identifier(&param.name), bits);
+                }
+            }
+        }
+        self.return_types = header
+            .return_params
+            .iter()
+            .map(|param| param.param_type.clone())
+            .collect();
+        for param in header.params.iter().chain(header.return_params.iter()) {
+            self.locals_map
+                .insert(param.name.clone(), param.param_type.clone());
+        }
+        let mut invalid_modifiers = HashMap::new();
+        for modifier in header.invalid_modifiers.clone() {
+            match modifier {
+                Expression::InvalidModifier(name, _) => {
+                    if let Some(function) = self.modifiers_map.clone().get(&name) {
+                        invalid_modifiers.insert(
+                            (header.name.clone(), name),
+                            Function {
+                                header: self.parse_function_header(function),
+                                body: if let Some(body) = &function.body {
+                                    Some(self.parse_statement(
+                                        body,
+                                        self.parse_variable_access_location(function_definition),
+                                    )?)
+                                } else {
+                                    None
+                                },
+                                ..Default::default()
+                            },
+                        );
+                    }
+                }
+                _ => unreachable!("Only invalid modifiers are allowed here"),
+            }
+        }
+
+        let body = if let Some(statement) = &function_definition.body {
+            Some(self.parse_statement(
+                statement,
+                self.parse_variable_access_location(function_definition),
+            )?)
+        } else {
+            None
+        };
+
+        Ok(Function {
+            header,
+            body,
+            invalid_modifiers,
+        })
+    }
+
+    /// Parses a Sol2Ink function header definition from Solang function definition
+    ///
+    /// `function_definition` the Solang function definition
+    ///
+    /// Returns the parsed `FunctionHeader` struct
+    fn parse_function_header(
+        &mut self,
+        function_definition: &FunctionDefinition,
+    ) -> FunctionHeader {
+        let mut name = self.parse_identifier(&function_definition.name);
+        if function_definition.ty == FunctionTy::Function && self.overloads_map.contains_key(&name)
+        {
+            name = self.mangle_function_name(&name, function_definition);
+        }
+        let params = function_definition
+            .params
+            .iter()
+            .map(|item| item.1.clone().unwrap())
+            .filter_map(|param| {
+                let name = self.parse_identifier(&param.name);
+                let param_type = self.parse_type(&param.ty).ok()?;
+                let location = data_location(&param.storage);
+                Some(FunctionParam {
+                    name,
+                    param_type,
+                    location,
+                })
+            })
+            .collect();
+        let all_modifiers: Vec<Expression> = function_definition
+            .attributes
+            .iter()
+            .filter(|&attribute| matches!(attribute, FunctionAttribute::BaseOrModifier(..)))
+            .map(|modifier| {
+                if let FunctionAttribute::BaseOrModifier(_, base) = modifier {
+                    let parsed_name = self.parse_identifier_path(&base.name);
+                    let parsed_args = if let Some(args) = &base.args {
+                        self.parse_expression_vec(args, VariableAccessLocation::Any)
+                    } else {
+                        Vec::default()
+                    };
+                    if parsed_args.iter().any(function_call_in_expression) {
+                        Expression::InvalidModifier(parsed_name, parsed_args)
+                    } else {
+                        Expression::Modifier(parsed_name, parsed_args)
+                    }
+                } else {
+                    unreachable!("The vec was filtered before");
+                }
+            })
+            .collect();
+        let modifiers = all_modifiers
+            .iter()
+            .filter(|expression| matches!(expression, Expression::Modifier(..)))
+            .cloned()
+            .collect();
+        let invalid_modifiers = all_modifiers
+            .iter()
+            .filter(|expression| matches!(expression, Expression::InvalidModifier(..)))
+            .cloned()
+            .collect();
+        let external = function_definition.attributes.iter().any(|attribute| {
+            matches!(
+                attribute,
+                FunctionAttribute::Visibility(Visibility::External(_))
+                    | FunctionAttribute::Visibility(Visibility::Public(_))
+            )
+        });
+        let view = function_definition.attributes.iter().any(|attribute| {
+            matches!(
+                attribute,
+                FunctionAttribute::Mutability(Mutability::Pure(_))
+                    | FunctionAttribute::Mutability(Mutability::View(_))
+            )
+        });
+        let payable = function_definition.attributes.iter().any(|attribute| {
+            matches!(
+                attribute,
+                FunctionAttribute::Mutability(Mutability::Payable(_))
+            )
+        });
+        let return_params = function_definition
+            .returns
+            .iter()
+            .map(|item| item.1.clone().unwrap())
+            .filter_map(|param| {
+                let name = self.parse_identifier(&param.name);
+                let param_type = self.parse_type(&param.ty).ok()?;
+                let location = data_location(&param.storage);
+                Some(FunctionParam {
+                    name,
+                    param_type,
+                    location,
+                })
+            })
+            .collect();
+
+        FunctionHeader {
+            name,
+            params,
+            external,
+            view,
+            payable,
+            return_params,
+            modifiers,
+            invalid_modifiers,
+            comments: self.get_comments(function_definition.loc.end()),
+        }
+    }
+
+    /// Parses a Solang parameter to Sol2Ink function parameter
+    /// Unnamed parameters are named `_`
+    ///
+    /// `param` the Solang parameter
+    ///
+    /// Returns the parsed `FunctionParam` struct
+    fn parse_function_param(
+        &mut self,
+        param: Option<&Parameter>,
+    ) -> Result<FunctionParam, ParserError> {
+        match param {
+            Some(param) => {
+                Ok(FunctionParam {
+                    name: self.parse_identifier(&param.name),
+                    param_type: self.parse_type(&param.ty)?,
+                    location: data_location(&param.storage),
+                })
+            }
+            None => {
+                Ok(FunctionParam {
+                    name: String::from("_"),
+                    param_type: Type::None,
+                    location: None,
+                })
+            }
+        }
+    }
+
+    /// Parses from where a variable will be accessed
+    /// This affects if we call `Self::var`, `T::var` or `self.var`
+    ///
+    /// `function_definition` the Solang function definition
+    ///
+    /// Returns the corresponding `VariableAccessLocation` enum variant
+    fn parse_variable_access_location(
+        &self,
+        function_definition: &FunctionDefinition,
+    ) -> VariableAccessLocation {
+        match function_definition.ty {
+            FunctionTy::Constructor => VariableAccessLocation::Constructor,
+            FunctionTy::Modifier => VariableAccessLocation::Modifier,
+            _ => VariableAccessLocation::Any,
+        }
+    }
+
+    /// Parses a Solang statement enum variant to Sol2Ink statement enum variant
+    ///
+    /// `statement` the original Solang statement enum variant
+    /// `location` the location where the statement is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns the parsed `Statement` enum variant
+    fn parse_statement(
+        &mut self,
+        statement: &SolangStatement,
+        location: VariableAccessLocation,
+    ) -> Result<Statement, ParserError> {
+        Ok(match statement {
+            SolangStatement::Block {
+                loc: _,
+                unchecked,
+                statements,
+            } => {
+                let arithmetic = self.arithmetic;
+                if *unchecked && arithmetic == Arithmetic::Checked {
+                    self.arithmetic = Arithmetic::Wrapping;
+                }
+                let parsed_statements = statements
+                    .iter()
+                    .map(|statement| self.parse_statement(statement, location.clone()))
+                    .map(|result| result.unwrap())
+                    .collect::<Vec<_>>();
+                self.arithmetic = arithmetic;
+                if *unchecked {
+                    Statement::UncheckedBlock(parsed_statements)
+                } else {
+                    Statement::Block(parsed_statements)
+                }
+            }
+            SolangStatement::Assembly {
+                loc: _,
+                dialect: _,
+                flags: _,
+                block,
+            } => Statement::Assembly(self.parse_yul_block(block, location)?),
+            SolangStatement::Args(_, _) => {
+                println!("{statement:?}");
+                todo!()
+            }
+            SolangStatement::If(_, expression, if_true, if_false) => {
+                let parsed_expression = self.parse_expression(expression, location.clone());
+                let parsed_if_true = Box::new(self.parse_statement(if_true, location.clone())?);
+                let parsed_if_false = if_false
+                    .as_ref()
+                    .map(|statement| self.parse_statement(statement, location.clone()))
+                    .map(|result| Box::new(result.unwrap()));
+                Statement::If(parsed_expression, parsed_if_true, parsed_if_false)
+            }
+            SolangStatement::While(_, expression, statement) => {
+                let parsed_expression = self.parse_expression(expression, location.clone());
+                let parsed_statement = Box::new(self.parse_statement(statement, location)?);
+                Statement::While(parsed_expression, parsed_statement)
+            }
+            SolangStatement::Expression(_, SolangExpression::Assign(_, left, right))
+                if let SolangExpression::List(_, slots) = &**left =>
+            {
+                self.parse_destructuring(slots, right, location)?
+            }
+            SolangStatement::Expression(_, expression) => {
+                let parsed_expression = self.parse_expression(expression, location);
+                self.storage_write(parsed_expression)
+            }
+            SolangStatement::VariableDefinition(_, declaration, initial_value_maybe) => {
+                let parsed_name = self.parse_identifier(&declaration.name).to_case(Snake);
+                let parsed_type = Box::new(self.parse_type(&declaration.ty)?);
+                let parsed_declaration = Expression::VariableDeclaration(
+                    parsed_type.clone(),
+                    parsed_name,
+                    data_location(&declaration.storage),
+                );
+                let parsed_initial_value = initial_value_maybe.as_ref().map(|expression| {
+                    let parsed_expression = self.parse_expression(expression, location);
+                    let parsed_expression = self.function_value(parsed_expression);
+                    self.widen(parsed_expression, Some(*parsed_type.clone()))
+                });
+                self.locals_map
+                    .insert(self.parse_identifier(&declaration.name), *parsed_type);
+                if let Some(bits) = declared_int_bits(&declaration.ty) {
+                    self.int_bits
+                        .insert(self.parse_identifier(&declaration.name), bits);
+                }
+                if let Some(contract) = initial_value_maybe
+                    .as_ref()
+                    .and_then(|expression| self.creation_code(expression))
+                {
+                    self.creation_codes
+                        .insert(self.parse_identifier(&declaration.name), contract);
+                }
+                match (&declaration.storage, &parsed_initial_value) {
+                    (Some(StorageLocation::Storage(_)), Some(initial_value)) => {
+                        self.storage_refs.insert(
+                            self.parse_identifier(&declaration.name),
+                            initial_value.clone(),
+                        );
+                    }
+                    _ => {
+                        self.storage_refs
+                            .remove(&self.parse_identifier(&declaration.name));
+                    }
+                }
+
+                Statement::VariableDefinition(parsed_declaration, parsed_initial_value)
+            }
+            SolangStatement::For(_, variable_definition, condition, on_pass, body) => {
+                let parsed_variable_definition = variable_definition
+                    .as_ref()
+                    .map(|statement| self.parse_statement(statement, location.clone()))
+                    .map(|result| Box::new(result.unwrap()));
+                let parsed_condition = condition
+                    .as_ref()
+                    .map(|expression| self.parse_expression(expression, location.clone()));
+                let parsed_on_pass = on_pass
+                    .as_ref()
+                    .map(|statement| self.parse_statement(statement, location.clone()))
+                    .map(|result| Box::new(result.unwrap()));
+                let parsed_body = body
+                    .as_ref()
+                    .map(|statement| self.parse_statement(statement, location))
+                    .map(|result| Box::new(result.unwrap()));
+
+                Statement::For(
+                    parsed_variable_definition,
+                    parsed_condition,
+                    parsed_on_pass,
+                    parsed_body,
+                )
+            }
+            SolangStatement::DoWhile(_, body, condition) => {
+                let parsed_condition = self.parse_expression(condition, location.clone());
+                let parsed_body = Box::new(self.parse_statement(body, location)?);
+                Statement::DoWhile(parsed_body, parsed_condition)
+            }
+            SolangStatement::Continue(_) => Statement::Continue,
+            SolangStatement::Break(_) => Statement::Break,
+            SolangStatement::Return(_, expression) => {
+                let parsed_expression = expression.as_ref().map(|expression| {
+                    let parsed_expression = self.parse_expression(expression, location);
+                    let parsed_expression = self.function_value(parsed_expression);
+                    match (parsed_expression, &self.return_types[..]) {
+                        (Expression::List(values), types) if values.len() == types.len() => {
+                            Expression::List(
+                                values
+                                    .into_iter()
+                                    .zip(types)
+                                    .map(|(value, ty)| self.widen(value, Some(ty.clone())))
+                                    .collect(),
+                            )
+                        }
+                        (parsed_expression, [ty]) => {
+                            self.widen(parsed_expression, Some(ty.clone()))
+                        }
+                        (parsed_expression, _) => parsed_expression,
+                    }
+                });
+                Statement::Return(parsed_expression)
+            }
+            SolangStatement::Revert(_, identifier_path, args) => {
+                let identifier_path = identifier_path
+                    .as_ref()
+                    .map(|identifier_path| self.parse_error_path(identifier_path))
+                    .unwrap_or(String::from("_"));
+                let parsed_args = self.parse_expression_vec(args, location);
+                let fields = self
+                    .errors_map
+                    .get(&identifier_path)
+                    .cloned()
+                    .unwrap_or_default();
+                let field_types = fields
+                    .iter()
+                    .map(|field| field.field_type.clone())
+                    .collect::<Vec<_>>();
+                let parsed_args = self.widen_args(parsed_args, &field_types);
+                if fields.len() == parsed_args.len()
+                    && !fields.is_empty()
+                    && fields.iter().all(|field| field.name != "_")
+                {
+                    Statement::RevertNamedArgs(
+                        identifier_path,
+                        fields
+                            .into_iter()
+                            .map(|field| field.name)
+                            .zip(parsed_args)
+                            .collect(),
+                    )
+                } else {
+                    Statement::Revert(identifier_path, parsed_args)
+                }
+            }
+            SolangStatement::RevertNamedArgs(_, identifier_path, args) => {
+                let identifier_path = identifier_path
+                    .as_ref()
+                    .map(|identifier_path| self.parse_error_path(identifier_path))
+                    .unwrap_or(String::from("_"));
+                let fields = self
+                    .errors_map
+                    .get(&identifier_path)
+                    .cloned()
+                    .unwrap_or_default();
+                let parsed_args = args
+                    .iter()
+                    .map(|argument| {
+                        let parsed_argument =
+                            self.parse_expression(&argument.expr, location.clone());
+                        let parsed_name = self.parse_identifier(&Some(argument.name.clone()));
+                        let field_type = fields
+                            .iter()
+                            .find(|field| field.name == parsed_name)
+                            .map(|field| field.field_type.clone());
+                        (parsed_name, self.widen(parsed_argument, field_type))
+                    })
+                    .collect();
+                Statement::RevertNamedArgs(identifier_path, parsed_args)
+            }
+            SolangStatement::Emit(_, expression) => {
+                let parsed_expression = self.parse_expression(expression, location);
+                Statement::Emit(parsed_expression)
+            }
+            SolangStatement::Try(_, expression, returns, catch_clauses) => {
+                let parsed_expression = self.parse_expression(expression, location.clone());
+                // calls transferring value are built with the call builder, so the called message
+                // is selected by its ink! selector, like the values of external function types
+                let parsed_expression = match &parsed_expression {
+                    Expression::FunctionCall(function, args, Some(value))
+                        if let Expression::MemberAccess(receiver, member) = &**function =>
+                    {
+                        Expression::FunctionCall(
+                            Box::new(Expression::List(vec![
+                                *receiver.clone(),
+                                self.message_selector(member),
+                            ])),
+                            args.clone(),
+                            Some(value.clone()),
+                        )
+                    }
+                    _ => parsed_expression,
+                };
+                // the returned values and the caught errors are locals of the clauses
+                let (parsed_returns, parsed_body) = match returns {
+                    Some((params, body)) => {
+                        let parsed_params = params
+                            .iter()
+                            .map(|(_, param)| self.parse_function_param(param.as_ref()))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        let parsed_body = self.with_locals(&parsed_params, |parser| {
+                            parser.parse_statement(body, location.clone())
+                        })?;
+                        (parsed_params, parsed_body)
+                    }
+                    None => (Vec::default(), Statement::Block(Vec::default())),
+                };
+                let mut parsed_catch_clauses = Vec::new();
+                for catch_clause in catch_clauses.iter() {
+                    parsed_catch_clauses.push(match catch_clause {
+                        SolangCatchClause::Simple(_, param, body) => {
+                            let parsed_param = param
+                                .as_ref()
+                                .map(|param| self.parse_function_param(Some(param)))
+                                .transpose()?;
+                            let parsed_body = self
+                                .with_locals(parsed_param.as_slice(), |parser| {
+                                    parser.parse_statement(body, location.clone())
+                                })?;
+                            CatchClause::Simple(parsed_param, parsed_body)
+                        }
+                        SolangCatchClause::Named(_, identifier, param, body) => {
+                            let parsed_param = self.parse_function_param(Some(param))?;
+                            let parsed_body = self
+                                .with_locals(std::slice::from_ref(&parsed_param), |parser| {
+                                    parser.parse_statement(body, location.clone())
+                                })?;
+                            CatchClause::Named(
+                                self.parse_identifier(&Some(identifier.clone())),
+                                parsed_param,
+                                parsed_body,
+                            )
+                        }
+                    });
+                }
+                Statement::Try(
+                    parsed_expression,
+                    parsed_returns,
+                    Box::new(parsed_body),
+                    parsed_catch_clauses,
+                )
+            }
+            SolangStatement::Error(loc) => {
+                return Err(ParserError::FileCorrupted(vec![format!(
+                    "Statement at offset {} could not be parsed",
+                    loc.start()
+                )]))
+            }
+        })
+    }
+
+    /// Parses a block in which the params are local variables, like the clauses of `try`,
+    /// the locals which the params shadow are restored afterwards
+    ///
+    /// `params` the params declared by the block
+    /// `parse` the parsing of the block
+    fn with_locals<T>(
+        &mut self,
+        params: &[FunctionParam],
+        parse: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let shadowed = params
+            .iter()
+            .map(|param| {
+                let shadowed = self
+                    .locals_map
+                    .insert(param.name.clone(), param.param_type.clone());
+                (param.name.clone(), shadowed)
+            })
+            .collect::<Vec<_>>();
+        let parsed = parse(self);
+        for (name, shadowed) in shadowed.into_iter().rev() {
+            match shadowed {
+                Some(ty) => self.locals_map.insert(name, ty),
+                None => self.locals_map.remove(&name),
+            };
+        }
+        parsed
+    }
+
+    /// Parses the statements of a Yul block to Sol2Ink statements
+    ///
+    /// `block` the original Yul block
+    /// `location` the location where the block is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns the vec of parsed `Statement` enum variants
+    fn parse_yul_block(
+        &mut self,
+        block: &YulBlock,
+        location: VariableAccessLocation,
+    ) -> Result<Vec<Statement>, ParserError> {
+        block
+            .statements
+            .iter()
+            .map(|statement| self.parse_yul_statement(statement, location.clone()))
+            .collect()
+    }
+
+    /// Parses a Yul statement to Sol2Ink statement
+    /// Statements which can not be expressed in ink! are parsed as `Expression::Untranslatable`
+    ///
+    /// `statement` the original Yul statement
+    /// `location` the location where the statement is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns the parsed `Statement` enum variant
+    fn parse_yul_statement(
+        &mut self,
+        statement: &YulStatement,
+        location: VariableAccessLocation,
+    ) -> Result<Statement, ParserError> {
+        Ok(match statement {
+            YulStatement::Assign(_, variables, value) if variables.len() == 1 => {
+                let parsed_variable = self.parse_yul_expression(&variables[0], location.clone());
+                let parsed_value = self.parse_yul_expression(value, location);
+                let parsed_value = match (
+                    self.expression_type(&parsed_variable),
+                    signed_word(&parsed_value),
+                ) {
+                    // signed results are kept signed if they are assigned to a signed integer
+                    (Some(Type::Int(bits)), Some(signed_value)) => {
+                        match self.yul_word_type() {
+                            Type::Uint(word_bits) if word_bits == bits => signed_value.clone(),
+                            Type::Uint(256) => {
+                                Expression::Truncate(
+                                    Box::new(Type::Int(bits)),
+                                    Box::new(signed_value.clone()),
+                                )
+                            }
+                            _ => {
+                                Expression::Cast(
+                                    Box::new(Type::Int(bits)),
+                                    Box::new(signed_value.clone()),
+                                )
+                            }
+                        }
+                    }
+                    _ => self.yul_word(parsed_value),
+                };
+                Statement::Expression(Expression::Assign(
+                    Box::new(parsed_variable),
+                    Box::new(parsed_value),
+                ))
+            }
+            YulStatement::VariableDeclaration(_, variables, value) if variables.len() == 1 => {
+                let parsed_name = variables[0].id.name.to_case(Snake);
+                let parsed_value = value
+                    .as_ref()
+                    .map(|value| self.parse_yul_expression(value, location))
+                    .map(|value| self.yul_word(value))
+                    .unwrap_or_else(|| {
+                        self.yul_literal(Expression::NumberLiteral(String::from("0")))
+                    });
+                Statement::VariableDefinition(
+                    Expression::VariableDeclaration(
+                        Box::new(self.yul_word_type()),
+                        parsed_name,
+                        None,
+                    ),
+                    Some(parsed_value),
+                )
+            }
+            YulStatement::If(_, condition, block) => {
+                let parsed_condition = self.parse_yul_condition(condition, location.clone());
+                let parsed_block = self.parse_yul_block(block, location)?;
+                Statement::If(
+                    parsed_condition,
+                    Box::new(Statement::Block(parsed_block)),
+                    None,
+                )
+            }
+            YulStatement::For(yul_for) => {
+                let parsed_init = self.parse_yul_block(&yul_for.init_block, location.clone())?;
+                let parsed_condition =
+                    self.parse_yul_condition(&yul_for.condition, location.clone());
+                let parsed_post = self.parse_yul_block(&yul_for.post_block, location.clone())?;
+                let parsed_body = self.parse_yul_block(&yul_for.execution_block, location)?;
+                Statement::For(
+                    Some(Box::new(Statement::Block(parsed_init))),
+                    Some(parsed_condition),
+                    Some(Box::new(Statement::Block(parsed_post))),
+                    Some(Box::new(Statement::Block(parsed_body))),
+                )
+            }
+            YulStatement::Switch(switch) => {
+                let parsed_condition =
+                    self.parse_yul_expression(&switch.condition, location.clone());
+                let parsed_condition = self.yul_word(parsed_condition);
+                let mut parsed_switch = match &switch.default {
+                    Some(
+                        YulSwitchOptions::Case(_, _, block) | YulSwitchOptions::Default(_, block),
+                    ) => {
+                        Some(Box::new(Statement::Block(
+                            self.parse_yul_block(block, location.clone())?,
+                        )))
+                    }
+                    None => None,
+                };
+                for case in switch.cases.iter().rev() {
+                    if let YulSwitchOptions::Case(_, value, block) = case {
+                        let parsed_value = self.parse_yul_expression(value, location.clone());
+                        let parsed_block = self.parse_yul_block(block, location.clone())?;
+                        parsed_switch = Some(Box::new(Statement::If(
+                            Expression::Equal(
+                                Box::new(parsed_condition.clone()),
+                                Box::new(parsed_value),
+                            ),
+                            Box::new(Statement::Block(parsed_block)),
+                            parsed_switch,
+                        )));
+                    }
+                }
+                parsed_switch
+                    .map(|statement| *statement)
+                    .unwrap_or(Statement::Block(Vec::default()))
+            }
+            YulStatement::Break(_) => Statement::Break,
+            YulStatement::Continue(_) => Statement::Continue,
+            YulStatement::Block(block) => Statement::Block(self.parse_yul_block(block, location)?),
+            YulStatement::FunctionCall(function_call) => {
+                match (function_call.id.name.as_str(), &function_call.arguments[..]) {
+                    ("pop", [value]) => {
+                        Statement::Expression(self.parse_yul_expression(value, location))
+                    }
+                    (
+                        "revert",
+                        [YulExpression::NumberLiteral(_, offset, ..), YulExpression::NumberLiteral(_, size, ..)],
+                    ) if offset == "0" && size == "0" => {
+                        Statement::Revert(String::from("_"), Vec::default())
+                    }
+                    _ => {
+                        Statement::Expression(self.parse_yul_function_call(function_call, location))
+                    }
+                }
+            }
+            YulStatement::FunctionDefinition(function_definition) => {
+                Statement::Expression(Expression::Untranslatable(format!(
+                    "function {}",
+                    function_definition.id.name
+                )))
+            }
+            YulStatement::Assign(_, variables, value) => {
+                Statement::Expression(Expression::Untranslatable(format!(
+                    "{} := {}",
+                    variables
+                        .iter()
+                        .map(yul_source)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    yul_source(value)
+                )))
+            }
+            YulStatement::VariableDeclaration(_, variables, value) => {
+                let names = variables
+                    .iter()
+                    .map(|variable| variable.id.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Statement::Expression(Expression::Untranslatable(match value {
+                    Some(value) => format!("let {names} := {}", yul_source(value)),
+                    None => format!("let {names}"),
+                }))
+            }
+            YulStatement::Leave(_) => {
+                Statement::Expression(Expression::Untranslatable(String::from("leave")))
+            }
+            YulStatement::Error(loc) => {
+                return Err(ParserError::FileCorrupted(vec![format!(
+                    "Assembly statement at offset {} could not be parsed",
+                    loc.start()
+                )]))
+            }
+        })
+    }
+
+    /// Parses a Yul expression to Sol2Ink expression
+    ///
+    /// `expression` the original Yul expression
+    /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns the parsed `Expression` enum variant
+    fn parse_yul_expression(
+        &mut self,
+        expression: &YulExpression,
+        location: VariableAccessLocation,
+    ) -> Expression {
+        match expression {
+            YulExpression::BoolLiteral(_, value, _) => Expression::BoolLiteral(*value),
+            YulExpression::NumberLiteral(loc, literal, exponent, _) => {
+                let parsed_literal = self.parse_expression(
+                    &SolangExpression::NumberLiteral(*loc, literal.clone(), exponent.clone()),
+                    location,
+                );
+                self.yul_literal(parsed_literal)
+            }
+            YulExpression::HexNumberLiteral(_, literal, _) => {
+                self.yul_literal(Expression::HexLiteral(literal.clone()))
+            }
+            YulExpression::StringLiteral(literal, _) => {
+                Expression::StringLiteral(vec![unescape_string(&literal.string)])
+            }
+            YulExpression::Variable(identifier) => {
+                self.parse_expression(&SolangExpression::Variable(identifier.clone()), location)
+            }
+            YulExpression::FunctionCall(function_call) => {
+                self.parse_yul_function_call(function_call, location)
+            }
+            YulExpression::HexStringLiteral(..) | YulExpression::SuffixAccess(..) => {
+                Expression::Untranslatable(yul_source(expression))
+            }
+        }
+    }
+
+    /// Parses a call of a Yul builtin to Sol2Ink expression
+    /// Arithmetic, bitwise and comparison builtins are parsed to their Solidity counterparts,
+    /// builtins reading the environment are parsed to the matching Solidity globals or to `Expression::AssemblyCall`
+    /// Opcodes without an ink! equivalent and calls of Yul functions are parsed as `Expression::Untranslatable`
+    ///
+    /// `function_call` the original Yul function call
+    /// `location` the location where the call is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns the parsed `Expression` enum variant
+    fn parse_yul_function_call(
+        &mut self,
+        function_call: &YulFunctionCall,
+        location: VariableAccessLocation,
+    ) -> Expression {
+        let args = function_call
+            .arguments
+            .iter()
+            .map(|argument| self.parse_yul_expression(argument, location.clone()))
+            .collect::<Vec<_>>();
+
+        // `mload(array)` loads the length of a memory array
+        if let ("mload", [array]) = (function_call.id.name.as_str(), &args[..]) {
+            if let Some(Type::Array(_, None) | Type::DynamicBytes | Type::String) =
+                self.expression_type(array)
+            {
+                return self.array_length(array).unwrap()
+            }
+        }
+        let word_bits = self.convert_int_bits(&256);
+        if word_bits == 256
+            && matches!(
+                function_call.id.name.as_str(),
+                "sdiv" | "smod" | "slt" | "sgt" | "sar"
+            )
+        {
+            self.imports.insert(Import::I256);
+        }
+
+        let zero = self.yul_literal(Expression::NumberLiteral(String::from("0")));
+        let operand = |index: usize| Box::new(yul_operand(&self.yul_word(args[index].clone())));
+        let word = |expression: Expression| {
+            Expression::FunctionCall(
+                Box::new(Expression::Type(Box::new(self.yul_word_type()))),
+                vec![expression],
+                None,
+            )
+        };
+        // signed operations reinterpret the words in two's complement
+        let signed = |index: usize| {
+            match word_bits {
+                256 => {
+                    Expression::FunctionCall(
+                        Box::new(Expression::Type(Box::new(Type::Int(256)))),
+                        vec![self.yul_word(args[index].clone())],
+                        None,
+                    )
+                }
+                bits => Expression::Cast(Box::new(Type::Int(bits)), operand(index)),
+            }
+        };
+        let unsigned = |expression: Expression| {
+            match word_bits {
+                256 => word(expression),
+                bits => {
+                    Expression::Cast(
+                        Box::new(Type::Uint(bits)),
+                        Box::new(Expression::Parenthesis(Box::new(expression))),
+                    )
+                }
+            }
+        };
+        let global = |global: &str, member: &str| {
+            Expression::MemberAccess(
+                Box::new(Expression::Variable(
+                    String::from(global),
+                    MemberType::None(Box::new(Type::None)),
+                    location.clone(),
+                )),
+                String::from(member),
+            )
+        };
+
+        // `create2(value, add(code, 32), mload(code), salt)` creates the contract whose creation code is `code`
+        if let ("create" | "create2", [value, ..]) = (function_call.id.name.as_str(), &args[..]) {
+            if let Some(contract) = self.yul_creation_code(&function_call.arguments[1]) {
+                let value = match &function_call.arguments[0] {
+                    YulExpression::NumberLiteral(_, value, ..) if value == "0" => None,
+                    _ => Some(Box::new(self.to_balance(value.clone()))),
+                };
+                let salt = args.get(3).cloned().map(Box::new);
+                return self.instantiate(contract, Vec::default(), value, salt, &location)
+            }
+        }
+
+        match (function_call.id.name.as_str(), args.len()) {
+            // arithmetic of the EVM words wraps around
+            ("add", 2) => Expression::Wrapping(Box::new(Expression::Add(operand(0), operand(1)))),
+            ("sub", 2) => {
+                Expression::Wrapping(Box::new(Expression::Subtract(operand(0), operand(1))))
+            }
+            ("mul", 2) => {
+                Expression::Wrapping(Box::new(Expression::Multiply(operand(0), operand(1))))
+            }
+            ("div", 2) => Expression::Divide(operand(0), operand(1)),
+            ("mod", 2) => Expression::Modulo(operand(0), operand(1)),
+            ("sdiv" | "smod", 2) => {
+                unsigned(Expression::AssemblyCall(
+                    function_call.id.name.clone(),
+                    vec![signed(0), signed(1)],
+                    location,
+                ))
+            }
+            ("exp", 2) => Expression::Wrapping(Box::new(Expression::Power(operand(0), operand(1)))),
+            ("addmod", 3) => {
+                Expression::Modulo(
+                    Box::new(Expression::Parenthesis(Box::new(Expression::Add(
+                        operand(0),
+                        operand(1),
+                    )))),
+                    operand(2),
+                )
+            }
+            ("mulmod", 3) => {
+                Expression::Modulo(
+                    Box::new(Expression::Parenthesis(Box::new(Expression::Multiply(
+                        operand(0),
+                        operand(1),
+                    )))),
+                    operand(2),
+                )
+            }
+            ("and", 2) => Expression::BitwiseAnd(operand(0), operand(1)),
+            ("or", 2) => Expression::BitwiseOr(operand(0), operand(1)),
+            ("xor", 2) => Expression::BitwiseXor(operand(0), operand(1)),
+            ("not", 1) => Expression::Not(operand(0)),
+            // shifts take the shift amount as the first argument
+            ("shl", 2) => Expression::ShiftLeft(operand(1), operand(0)),
+            ("shr", 2) => Expression::ShiftRight(operand(1), operand(0)),
+            ("sar", 2) => unsigned(Expression::ShiftRight(Box::new(signed(1)), operand(0))),
+            ("lt", 2) => Expression::Less(operand(0), operand(1)),
+            ("gt", 2) => Expression::More(operand(0), operand(1)),
+            ("slt", 2) => Expression::Less(Box::new(signed(0)), Box::new(signed(1))),
+            ("sgt", 2) => Expression::More(Box::new(signed(0)), Box::new(signed(1))),
+            ("eq", 2) => Expression::Equal(operand(0), operand(1)),
+            ("iszero", 1) if is_boolean_expression(&args[0]) => {
+                Expression::Not(Box::new(Expression::Parenthesis(Box::new(args[0].clone()))))
+            }
+            ("iszero", 1) => Expression::Equal(operand(0), Box::new(zero)),
+            ("address", 0) => {
+                Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(Type::AccountId))),
+                    vec![Expression::This(location)],
+                    None,
+                )
+            }
+            ("caller", 0) => global("msg", "sender"),
+            ("callvalue", 0) => word(global("msg", "value")),
+            ("gas", 0) => word(global("msg", "gas")),
+            ("timestamp", 0) => word(global("block", "timestamp")),
+            ("number", 0) => word(global("block", "number")),
+            // the substitute of the chain id is a Solidity integer already
+            ("chainid", 0) => self.parse_global("block", "chainid", &location).unwrap(),
+            ("extcodesize" | "selfbalance", _) => {
+                word(Expression::AssemblyCall(
+                    function_call.id.name.clone(),
+                    args,
+                    location,
+                ))
+            }
+            // `mstore(array, length)` shrinks a memory array
+            ("mstore", 2)
+                if let Some(Type::Array(_, None) | Type::DynamicBytes) =
+                    self.expression_type(&args[0]) =>
+            {
+                Expression::AssemblyCall(function_call.id.name.clone(), args, location)
+            }
+            // ink! calls return the decoded result, the returned data is not kept by the contract
+            ("returndatasize" | "returndatacopy", _) => {
+                Expression::Unsupported(format!(
+                    "Returned data is not kept in ink!: {}",
+                    yul_source(&YulExpression::FunctionCall(Box::new(
+                        function_call.clone()
+                    )))
+                ))
+            }
+            // `revert(add(32, data), mload(data))` bubbles up the error data
+            ("revert", 2)
+                if let Expression::Wrapping(offset) = &args[0]
+                    && let Expression::Add(_, data) = &**offset
+                    && loaded_length(&args[1]) == Some(&**data) =>
+            {
+                Expression::AssemblyCall(
+                    function_call.id.name.clone(),
+                    vec![*data.clone()],
+                    location,
+                )
+            }
+            _ => {
+                Expression::Untranslatable(yul_source(&YulExpression::FunctionCall(Box::new(
+                    function_call.clone(),
+                ))))
+            }
+        }
+    }
+
+    /// Returns the length of an array or of dynamic bytes as a Solidity integer
+    /// Returns `None` if the expression is not an array
+    ///
+    /// `array` the parsed expression
+    fn array_length(&mut self, array: &Expression) -> Option<Expression> {
+        match self.expression_type(array)? {
+            // the length of fixed size arrays is known
+            Type::Array(_, Some(Expression::NumberLiteral(size))) => {
+                Some(Expression::NumberLiteral(size))
+            }
+            // lazy arrays store their length in the type of the indices
+            Type::LazyArray(..) => Some(Expression::Length(Box::new(array.clone()))),
+            Type::Array(_, None) | Type::DynamicBytes | Type::String => {
+                let length = Expression::Length(Box::new(array.clone()));
+                Some(match self.convert_int_bits(&256) {
+                    256 => {
+                        self.imports.insert(Import::U256);
+                        Expression::FunctionCall(
+                            Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                            vec![length],
+                            None,
+                        )
+                    }
+                    bits => Expression::Cast(Box::new(Type::Uint(bits)), Box::new(length)),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the contract whose creation code is read by the Yul expression, like `add(bytecode, 32)`
+    ///
+    /// `expression` the original Yul expression
+    fn yul_creation_code(&self, expression: &YulExpression) -> Option<String> {
+        match expression {
+            YulExpression::Variable(identifier) => {
+                self.creation_codes.get(&identifier.name).cloned()
+            }
+            YulExpression::FunctionCall(function_call) => {
+                function_call
+                    .arguments
+                    .iter()
+                    .find_map(|argument| self.yul_creation_code(argument))
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses a Yul expression used as a condition, Yul treats every non-zero value as true
+    ///
+    /// `condition` the original Yul expression
+    /// `location` the location where the condition is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns the parsed `Expression` enum variant evaluating to `bool`
+    fn parse_yul_condition(
+        &mut self,
+        condition: &YulExpression,
+        location: VariableAccessLocation,
+    ) -> Expression {
+        let parsed_condition = self.parse_yul_expression(condition, location);
+        if is_boolean_expression(&parsed_condition) {
+            parsed_condition
+        } else {
+            Expression::NotEqual(
+                Box::new(yul_operand(&self.yul_word(parsed_condition))),
+                Box::new(self.yul_literal(Expression::NumberLiteral(String::from("0")))),
+            )
+        }
+    }
+
+    /// Returns the type of Yul variables, which are 256 bit words
+    fn yul_word_type(&self) -> Type {
+        Type::Uint(self.convert_int_bits(&256))
+    }
+
+    /// Converts booleans and addresses to words, since Yul has no other types
+    ///
+    /// `expression` the parsed Yul expression
+    ///
+    /// Returns the expression evaluating to a word
+    fn yul_word(&self, expression: Expression) -> Expression {
+        let word_type = self.yul_word_type();
+        let ty = self.expression_type(&expression);
+        match ty {
+            _ if is_boolean_expression(&expression) || ty == Some(Type::Bool) => {
+                // `U256` can not be converted from `bool`
+                let value = match word_type {
+                    Type::Uint(256) => {
+                        Expression::Cast(Box::new(Type::Uint(8)), Box::new(expression))
+                    }
+                    _ => expression,
+                };
+                Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(word_type))),
+                    vec![value],
+                    None,
+                )
+            }
+            // addresses are read as big endian words
+            Some(Type::AccountId | Type::PayableAccountId) => {
+                Expression::AssemblyCall(
+                    String::from("address_word"),
+                    vec![expression, Expression::Type(Box::new(word_type))],
+                    VariableAccessLocation::Any,
+                )
+            }
+            _ => expression,
+        }
+    }
+
+    /// Converts a number literal to a word, so it is not inferred as `i32` next to `U256`
+    ///
+    /// `literal` the parsed number literal
+    ///
+    /// Returns the expression evaluating to a word
+    fn yul_literal(&mut self, literal: Expression) -> Expression {
+        match (self.yul_word_type(), literal) {
+            (word_type @ Type::Uint(256), literal) => {
+                self.imports.insert(Import::U256);
+                Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(word_type))),
+                    vec![literal],
+                    None,
+                )
+            }
+            // hex numbers are valid integer literals in Rust
+            (_, Expression::HexLiteral(literal)) => Expression::NumberLiteral(literal),
+            (_, literal) => literal,
+        }
+    }
+
+    /// Parses a Solang expression enum variant to Sol2Ink expression enum variant
+    ///
+    /// `expression` the original Solang expression enum variant
+    /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns the parsed `Expression` enum variant
+    fn parse_expression(
+        &mut self,
+        expression: &SolangExpression,
+        location: VariableAccessLocation,
+    ) -> Expression {
+        macro_rules! maybe_boxed_expression {
+            ($to_declare:ident,$to_parse:expr) => {
+                let $to_declare = $to_parse.as_ref().map(|expression| {
+                    Box::new(self.parse_expression(&expression, location.clone()))
+                });
+            };
+        }
+
+        macro_rules! boxed_expression {
+            ($to_declare:ident,$to_parse:expr) => {
+                let $to_declare = Box::new(self.parse_expression($to_parse, location.clone()));
+            };
+        }
+
+        // number literals and narrower integers used together with a `U256` are converted to `U256`
+        macro_rules! boxed_operands {
+            ($left_declare:ident,$right_declare:ident,$left:expr,$right:expr) => {
+                let $left_declare = self.parse_expression($left, location.clone());
+                let $right_declare = self.parse_expression($right, location.clone());
+                let $left_declare =
+                    Box::new(self.widen($left_declare, self.expression_type(&$right_declare)));
+                let $right_declare =
+                    Box::new(self.widen($right_declare, self.expression_type(&$left_declare)));
+            };
+        }
+
+        // the assigned value is converted to the type of the assigned variable
+        macro_rules! assigned_operands {
+            ($left_declare:ident,$right_declare:ident,$left:expr,$right:expr) => {
+                boxed_expression!($left_declare, $left);
+                let $right_declare = self.parse_expression($right, location.clone());
+                let $right_declare =
+                    Box::new(self.widen($right_declare, self.expression_type(&$left_declare)));
+            };
+        }
+
+        match expression {
+            SolangExpression::PostIncrement(_, expression) => {
+                boxed_expression!(parsed_expression, expression);
+                if self.arithmetic == Arithmetic::Plain {
+                    return Expression::PostIncrement(parsed_expression)
+                }
+                let one = self.widen_literal(
+                    Expression::NumberLiteral(String::from("1")),
+                    self.expression_type(&parsed_expression),
+                );
+                let operation = self.arithmetic_operation(Expression::Add(
+                    parsed_expression.clone(),
+                    Box::new(one),
+                ));
+                Expression::Assign(parsed_expression, Box::new(operation))
+            }
+            SolangExpression::PostDecrement(_, expression) => {
+                boxed_expression!(parsed_expression, expression);
+                if self.arithmetic == Arithmetic::Plain {
+                    return Expression::PostDecrement(parsed_expression)
+                }
+                let one = self.widen_literal(
+                    Expression::NumberLiteral(String::from("1")),
+                    self.expression_type(&parsed_expression),
+                );
+                let operation = self.arithmetic_operation(Expression::Subtract(
+                    parsed_expression.clone(),
+                    Box::new(one),
+                ));
+                Expression::Assign(parsed_expression, Box::new(operation))
+            }
+            SolangExpression::New(_, expression)
+                if let Some(instantiation) = self.parse_instantiation(expression, &location) =>
+            {
+                instantiation
+            }
+            SolangExpression::New(_, expression) => {
+                boxed_expression!(parsed_expression, expression);
+                // the size of a new array is `usize`
+                if let Expression::FunctionCall(array, args, value) = *parsed_expression.clone() {
+                    if let (Expression::ArraySubscript(..), [size]) = (&*array, &args[..]) {
+                        let size = self.to_usize(Box::new(size.clone()));
+                        return Expression::New(Box::new(Expression::FunctionCall(
+                            array,
+                            vec![size],
+                            value,
+                        )))
+                    }
+                }
+                Expression::New(parsed_expression)
+            }
+            SolangExpression::ArraySubscript(_, array, index_maybe) => {
+                boxed_expression!(parsed_array, array);
+                maybe_boxed_expression!(parsed_index_maybe, index_maybe);
+                self.subscript(parsed_array, parsed_index_maybe)
+            }
+            SolangExpression::ArraySlice(_, exp, left, right) => {
+                boxed_expression!(parsed_exp, exp);
+                maybe_boxed_expression!(parsed_left, left);
+                maybe_boxed_expression!(parsed_right, right);
+
+                Expression::ArraySlice(parsed_exp, parsed_left, parsed_right)
+            }
+            SolangExpression::Parenthesis(_, expression) => {
+                boxed_expression!(parsed_expression, expression);
+                Expression::Parenthesis(parsed_expression)
+            }
+            SolangExpression::MemberAccess(_, expression, identifier)
+                if matches!(&**expression, SolangExpression::Variable(variable) if variable.name == "super")
+                    && self.super_implementation(
+                        &self.parse_identifier(&Some(identifier.clone())),
+                    ) =>
+            {
+                let parsed_identifier = self.parse_identifier(&Some(identifier.clone()));
+                if !self.super_calls.contains(&parsed_identifier) {
+                    self.super_calls.push(parsed_identifier.clone());
+                }
+                Expression::Variable(
+                    super_function(&parsed_identifier, &self.contract_name),
+                    MemberType::FunctionPrivate,
+                    location,
+                )
+            }
+            SolangExpression::MemberAccess(_, expression, identifier)
+                if self.is_namespace(expression) =>
+            {
+                // symbols of a file imported as a namespace are accessed directly
+                self.parse_expression(&SolangExpression::Variable(identifier.clone()), location)
+            }
+            // contracts are created from their code hash, the creation code is not available
+            SolangExpression::MemberAccess(..) if let Some(contract) = self.creation_code(expression) => {
+                Expression::Substitute(
+                    format!("type({contract}).creationCode is not available in ink!, {contract} is created from its code hash"),
+                    Box::new(Type::DynamicBytes),
+                    None,
+                )
+            }
+            SolangExpression::MemberAccess(_, expression, identifier) => {
+                boxed_expression!(parsed_expression, expression);
+                let parsed_identifier = self.parse_identifier(&Some(identifier.clone()));
+                if let Expression::Variable(global, MemberType::None(_), _) = &*parsed_expression {
+                    if let Some(parsed_global) =
+                        self.parse_global(global, &parsed_identifier, &location)
+                    {
+                        return parsed_global
+                    }
+                }
+                // only the balance of the contract itself is available in ink!
+                if parsed_identifier == "balance" && self.is_account(&parsed_expression) {
+                    let uint = Type::Uint(self.convert_int_bits(&256));
+                    if uint == Type::Uint(256) {
+                        self.imports.insert(Import::U256);
+                    }
+                    return match *parsed_expression {
+                        Expression::FunctionCall(_, args, _)
+                            if matches!(&args[..], [Expression::This(_)]) =>
+                        {
+                            Expression::FunctionCall(
+                                Box::new(Expression::Type(Box::new(uint))),
+                                vec![Expression::AssemblyCall(
+                                    String::from("selfbalance"),
+                                    Vec::default(),
+                                    location,
+                                )],
+                                None,
+                            )
+                        }
+                        _ => {
+                            Expression::Substitute(
+                                String::from(
+                                    "The balance of other accounts is not available in ink!",
+                                ),
+                                Box::new(uint),
+                                None,
+                            )
+                        }
+                    }
+                }
+                // external functions are stored as a pair of the address and the selector
+                match (
+                    self.expression_type(&parsed_expression),
+                    parsed_identifier.as_str(),
+                ) {
+                    (Some(Type::ExternalFunction(..)), "address") => {
+                        Expression::MemberAccess(parsed_expression, String::from("0"))
+                    }
+                    (Some(Type::ExternalFunction(..)), "selector") => {
+                        Expression::MemberAccess(parsed_expression, String::from("1"))
+                    }
+                    (_, "length") if let Some(length) = self.array_length(&parsed_expression) => {
+                        length
+                    }
+                    _ => Expression::MemberAccess(parsed_expression, parsed_identifier),
+                }
+            }
+            SolangExpression::FunctionCall(_, function, args)
+                if let Some(call) = self.parse_abi_call(function, args, &location) =>
+            {
+                call
+            }
+            SolangExpression::FunctionCall(_, function, args) => {
+                let parsed_args = self.parse_expression_vec(args, location.clone());
+
+                if let SolangExpression::FunctionCallBlock(_, function, parameters) =
+                    *function.clone()
+                {
+                    if let SolangStatement::Args(_, arguments) = *parameters {
+                        let value_argument = arguments
+                            .iter()
+                            .map(|argument| {
+                                let parsed_argument =
+                                    self.parse_expression(&argument.expr, location.clone());
+                                let parsed_name =
+                                    self.parse_identifier(&Some(argument.name.clone()));
+                                (parsed_name, parsed_argument)
+                            })
+                            .find(|(name, _)| name == "value")
+                            .map(|option| Box::new(self.to_balance(option.1)));
+                        boxed_expression!(parsed_function, &function);
+                        let parsed_function =
+                            Box::new(self.resolve_overloaded_call(*parsed_function, &parsed_args));
+                        if let Some((contract, address, member)) =
+                            self.contract_member(&parsed_function)
+                        {
+                            return self.contract_call(
+                                contract,
+                                address,
+                                member,
+                                parsed_args,
+                                value_argument,
+                            )
+                        }
+                        // `call{value: amount}("")` only transfers the value
+                        if let (
+                            Expression::MemberAccess(receiver, member),
+                            Some(value),
+                            [Expression::StringLiteral(data)],
+                        ) = (&*parsed_function, &value_argument, &parsed_args[..])
+                        {
+                            if member == "call"
+                                && data.concat().is_empty()
+                                && self.is_account(receiver)
+                            {
+                                return Expression::List(vec![
+                                    Expression::Send(
+                                        receiver.clone(),
+                                        Box::new(self.to_balance(*value.clone())),
+                                        location,
+                                    ),
+                                    Expression::FunctionCall(
+                                        Box::new(Expression::Type(Box::new(Type::DynamicBytes))),
+                                        parsed_args,
+                                        None,
+                                    ),
+                                ])
+                            }
+                        }
+                        if let Expression::MemberAccess(receiver, member) = &*parsed_function {
+                            if let Some(call) = self.low_level_call(
+                                receiver,
+                                member,
+                                &parsed_args,
+                                value_argument.clone(),
+                                &location,
+                            ) {
+                                return call
+                            }
+                        }
+                        return Expression::FunctionCall(
+                            parsed_function,
+                            parsed_args,
+                            value_argument,
+                        )
+                    }
+                    unreachable!("Only function is allowed here!");
+                } else {
+                    // casts of addresses to contract types, like `IERC20(token)`
+                    if let SolangExpression::Variable(identifier) = &**function {
+                        let name = self.resolve_symbol(&identifier.name);
+                        if self.is_contract_type(&name) {
+                            return Expression::FunctionCall(
+                                Box::new(Expression::Type(Box::new(Type::Contract(name)))),
+                                parsed_args,
+                                None,
+                            )
+                        }
+                    }
+                    boxed_expression!(parsed_function, function);
+                    if let Some(call) = self.builtin_call(&parsed_function, &parsed_args) {
+                        return call
+                    }
+                    let parsed_function =
+                        Box::new(self.resolve_overloaded_call(*parsed_function, &parsed_args));
+                    let parsed_args = self
+                        .borrow_calldata_args(&parsed_function, parsed_args)
+                        .into_iter()
+                        .map(|arg| self.function_value(arg))
+                        .collect::<Vec<_>>();
+                    match self.expression_type(&parsed_function) {
+                        Some(Type::ExternalFunction(params, returns)) => {
+                            let parsed_args = self.widen_args(parsed_args, &params);
+                            return Expression::ExternalFunctionCall(
+                                parsed_function,
+                                parsed_args,
+                                returns,
+                            )
+                        }
+                        Some(Type::Function(params, ..)) => {
+                            let parsed_args = self.widen_args(parsed_args, &params);
+                            return Expression::FunctionPointerCall(
+                                parsed_function,
+                                parsed_args,
+                                location,
+                            )
+                        }
+                        _ => (),
+                    }
+                    if let Expression::MemberAccess(receiver, member) = &*parsed_function {
+                        if let Expression::Variable(name, ..) = &**receiver {
+                            if self.value_types.contains(name) {
+                                match member.as_str() {
+                                    "wrap" => {
+                                        return Expression::FunctionCall(
+                                            Box::new(Expression::Type(Box::new(Type::Variable(
+                                                name.clone(),
+                                            )))),
+                                            parsed_args,
+                                            None,
+                                        )
+                                    }
+                                    "unwrap" => {
+                                        return Expression::MemberAccess(
+                                            Box::new(parsed_args[0].clone()),
+                                            String::from("0"),
+                                        )
+                                    }
+                                    _ => (),
+                                }
+                            }
+                        }
+                        if let Expression::Variable(library, ..) = &**receiver {
+                            if self.is_library(library) {
+                                self.imports.insert(Import::Libs);
+                                return self.library_call(library.clone(), member.clone(), parsed_args)
+                            }
+                        }
+                        if let Some(library) = self.bound_library(receiver, member) {
+                            self.imports.insert(Import::Libs);
+                            return self.library_call(
+                                library,
+                                member.clone(),
+                                [*receiver.clone()].into_iter().chain(parsed_args).collect(),
+                            )
+                        }
+                        if let Some((contract, address, member)) =
+                            self.contract_member(&parsed_function)
+                        {
+                            return self.contract_call(contract, address, member, parsed_args, None)
+                        }
+                        // every address was payable before Solidity 0.5, and `msg.sender` before 0.8
+                        if self.is_account(receiver) {
+                            match (member.as_str(), &parsed_args[..]) {
+                                ("transfer", [amount]) => {
+                                    let amount = self.to_balance(amount.clone());
+                                    return Expression::Transfer(
+                                        receiver.clone(),
+                                        Box::new(amount),
+                                        location,
+                                    )
+                                }
+                                ("send", [amount]) => {
+                                    let amount = self.to_balance(amount.clone());
+                                    return Expression::Send(
+                                        receiver.clone(),
+                                        Box::new(amount),
+                                        location,
+                                    )
+                                }
+                                _ => (),
+                            }
+                        }
+                        if let Some(call) =
+                            self.low_level_call(receiver, member, &parsed_args, None, &location)
+                        {
+                            return call
+                        }
+                        if let Some(element) = self.dynamic_array_element(receiver) {
+                            match (member.as_str(), &parsed_args[..]) {
+                                ("push", [value]) => {
+                                    let value = self.widen(value.clone(), Some(element));
+                                    return Expression::Push(receiver.clone(), Some(Box::new(value)))
+                                }
+                                ("push", []) => return Expression::Push(receiver.clone(), None),
+                                ("pop", []) => return Expression::Pop(receiver.clone()),
+                                _ => (),
+                            }
+                        }
+                    }
+                    // `bytes4(keccak256("transfer(address,uint256)"))` is the selector of a message
+                    if let (
+                        Expression::Type(ty),
+                        [Expression::Hash(HashFunction::Keccak256, input, _)],
+                    ) = (&*parsed_function, &parsed_args[..])
+                    {
+                        if let (Type::Bytes(4), Some(signature)) = (&**ty, string_value(input)) {
+                            return self.message_selector(&signature)
+                        }
+                    }
+                    // `U256` can not be converted to smaller integers with `from`
+                    if let Expression::Type(ty) = &*parsed_function {
+                        if let (Type::Uint(bits) | Type::Int(bits), [argument]) =
+                            (&**ty, &parsed_args[..])
+                        {
+                            if *bits <= 128
+                                && matches!(
+                                    self.expression_type(argument),
+                                    Some(Type::Uint(256) | Type::Int(256))
+                                )
+                            {
+                                return Expression::Truncate(ty.clone(), Box::new(argument.clone()))
+                            }
+                        }
+                    }
+                    match *parsed_function.clone() {
+                        Expression::Type(ty)
+                            if let Type::AccountId | Type::PayableAccountId = *ty.clone() =>
+                        {
+                            if parsed_args.len() > 1 {
+                                unreachable!("Multiple parameters were provided to `address` call")
+                            }
+                            let account_id = &parsed_args[0];
+                            match account_id {
+                                Expression::NumberLiteral(number) if number == "0" => {
+                                    self.imports.insert(Import::ZeroAddress);
+                                }
+                                _ => (),
+                            }
+                        }
+                        _ => (),
+                    }
+                    match self.called_function(&parsed_function).cloned() {
+                        Some(signature) => {
+                            let parsed_args = self.widen_args(parsed_args, &signature.params);
+                            self.pass_storage_args(
+                                Expression::FunctionCall(parsed_function, parsed_args, None),
+                                &signature.storage,
+                            )
+                        }
+                        None => Expression::FunctionCall(parsed_function, parsed_args, None),
+                    }
+                }
+            }
+            SolangExpression::FunctionCallBlock(_, _, _) => Expression::None,
+            SolangExpression::NamedFunctionCall(_, expression, arguments) => {
+                boxed_expression!(parsed_expression, expression);
+                if let Expression::Variable(_, MemberType::Function, _) = *parsed_expression.clone()
+                {
+                    let parsed_arguments = arguments
+                        .iter()
+                        .map(|argument| self.parse_expression(&argument.expr, location.clone()))
+                        .collect::<Vec<_>>();
+                    let parsed_expression = Box::new(
+                        self.resolve_overloaded_call(*parsed_expression, &parsed_arguments),
+                    );
+                    Expression::FunctionCall(parsed_expression, parsed_arguments, None)
+                } else {
+                    let parsed_arguments = arguments
+                        .iter()
+                        .map(|argument| {
+                            let parsed_argument =
+                                self.parse_expression(&argument.expr, location.clone());
+                            let parsed_name = self.parse_identifier(&Some(argument.name.clone()));
+                            (parsed_name, parsed_argument)
+                        })
+                        .collect();
+                    Expression::NamedFunctionCall(parsed_expression, parsed_arguments)
+                }
+            }
+            SolangExpression::Not(_, expression) => {
+                boxed_expression!(parsed_expression, expression);
+                Expression::Not(parsed_expression)
+            }
+            SolangExpression::Complement(_, exp) => {
+                boxed_expression!(parsed_expression, exp);
+                Expression::Not(parsed_expression)
+            }
+            SolangExpression::Delete(_, expression) => {
+                boxed_expression!(parsed_expression, expression);
+                Expression::Delete(parsed_expression)
+            }
+            SolangExpression::PreIncrement(_, expression) => {
+                boxed_expression!(parsed_expression, expression);
+                if self.arithmetic == Arithmetic::Plain {
+                    return Expression::PreIncrement(parsed_expression)
+                }
+                let one = self.widen_literal(
+                    Expression::NumberLiteral(String::from("1")),
+                    self.expression_type(&parsed_expression),
+                );
+                let operation = self.arithmetic_operation(Expression::Add(
+                    parsed_expression.clone(),
+                    Box::new(one),
+                ));
+                Expression::Assign(parsed_expression, Box::new(operation))
+            }
+            SolangExpression::PreDecrement(_, expression) => {
+                boxed_expression!(parsed_expression, expression);
+                if self.arithmetic == Arithmetic::Plain {
+                    return Expression::PreDecrement(parsed_expression)
+                }
+                let one = self.widen_literal(
+                    Expression::NumberLiteral(String::from("1")),
+                    self.expression_type(&parsed_expression),
+                );
+                let operation = self.arithmetic_operation(Expression::Subtract(
+                    parsed_expression.clone(),
+                    Box::new(one),
+                ));
+                Expression::Assign(parsed_expression, Box::new(operation))
+            }
+            SolangExpression::UnaryPlus(_, exp) => {
+                boxed_expression!(parsed_expression, exp);
+                Expression::UnaryPlus(parsed_expression)
+            }
+            SolangExpression::UnaryMinus(_, exp) => {
+                boxed_expression!(parsed_expression, exp);
+                Expression::UnaryMinus(parsed_expression)
+            }
+            // the exponent does not change the type of the power, so only literals are converted
+            SolangExpression::Power(_, left, right) => {
+                let parsed_left = self.parse_expression(left, location.clone());
+                let parsed_right = self.parse_expression(right, location.clone());
+                let parsed_left =
+                    Box::new(self.widen_literal(parsed_left, self.expression_type(&parsed_right)));
+                let parsed_right =
+                    Box::new(self.widen_literal(parsed_right, self.expression_type(&parsed_left)));
+                self.arithmetic_operation(Expression::Power(parsed_left, parsed_right))
+            }
+            SolangExpression::Multiply(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                self.arithmetic_operation(Expression::Multiply(parsed_left, parsed_right))
+            }
+            SolangExpression::Divide(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::Divide(parsed_left, parsed_right)
+            }
+            SolangExpression::Modulo(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::Modulo(parsed_left, parsed_right)
+            }
+            SolangExpression::Add(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                self.arithmetic_operation(Expression::Add(parsed_left, parsed_right))
+            }
+            SolangExpression::Subtract(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                self.arithmetic_operation(Expression::Subtract(parsed_left, parsed_right))
+            }
+            SolangExpression::ShiftLeft(_, left, right) => {
+                boxed_expression!(parsed_left, left);
+                boxed_expression!(parsed_right, right);
+                Expression::ShiftLeft(parsed_left, parsed_right)
+            }
+            SolangExpression::ShiftRight(_, left, right) => {
+                boxed_expression!(parsed_left, left);
+                boxed_expression!(parsed_right, right);
+                Expression::ShiftRight(parsed_left, parsed_right)
+            }
+            SolangExpression::BitwiseAnd(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::BitwiseAnd(parsed_left, parsed_right)
+            }
+            SolangExpression::BitwiseXor(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::BitwiseXor(parsed_left, parsed_right)
+            }
+            SolangExpression::BitwiseOr(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::BitwiseOr(parsed_left, parsed_right)
+            }
+            SolangExpression::Less(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::Less(parsed_left, parsed_right)
+            }
+            SolangExpression::More(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::More(parsed_left, parsed_right)
+            }
+            SolangExpression::LessEqual(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::LessEqual(parsed_left, parsed_right)
+            }
+            SolangExpression::MoreEqual(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::MoreEqual(parsed_left, parsed_right)
+            }
+            SolangExpression::Equal(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::Equal(parsed_left, parsed_right)
+            }
+            SolangExpression::NotEqual(_, left, right) => {
+                boxed_operands!(parsed_left, parsed_right, left, right);
+                Expression::NotEqual(parsed_left, parsed_right)
+            }
+            SolangExpression::And(_, left, right) => {
+                boxed_expression!(parsed_left, left);
+                boxed_expression!(parsed_right, right);
+                Expression::And(parsed_left, parsed_right)
+            }
+            SolangExpression::Or(_, left, right) => {
+                boxed_expression!(parsed_left, left);
+                boxed_expression!(parsed_right, right);
+                Expression::Or(parsed_left, parsed_right)
+            }
+            SolangExpression::ConditionalOperator(_, condition, if_true, if_false) => {
+                boxed_expression!(parsed_condition, condition);
+                boxed_expression!(parsed_if_true, if_true);
+                boxed_expression!(parsed_if_false, if_false);
+                Expression::Ternary(parsed_condition, parsed_if_true, parsed_if_false)
+            }
+            SolangExpression::Assign(_, left, right) => {
+                assigned_operands!(parsed_left, parsed_right, left, right);
+                Expression::Assign(parsed_left, Box::new(self.function_value(*parsed_right)))
+            }
+            SolangExpression::AssignOr(_, left, right) => {
+                assigned_operands!(parsed_left, parsed_right, left, right);
+                Expression::AssignOr(parsed_left, parsed_right)
+            }
+            SolangExpression::AssignAnd(_, left, right) => {
+                assigned_operands!(parsed_left, parsed_right, left, right);
+                Expression::AssignAnd(parsed_left, parsed_right)
+            }
+            SolangExpression::AssignXor(_, left, right) => {
+                assigned_operands!(parsed_left, parsed_right, left, right);
+                Expression::AssignXor(parsed_left, parsed_right)
+            }
+            SolangExpression::AssignShiftLeft(_, left, right) => {
+                boxed_expression!(parsed_left, left);
+                boxed_expression!(parsed_right, right);
+                Expression::AssignShiftLeft(parsed_left, parsed_right)
+            }
+            SolangExpression::AssignShiftRight(_, left, right) => {
+                boxed_expression!(parsed_left, left);
+                boxed_expression!(parsed_right, right);
+                Expression::AssignShiftRight(parsed_left, parsed_right)
+            }
+            SolangExpression::AssignAdd(_, left, right) => {
+                assigned_operands!(parsed_left, parsed_right, left, right);
+                if self.arithmetic == Arithmetic::Plain {
+                    return Expression::AssignAdd(parsed_left, parsed_right)
+                }
+                let operation =
+                    self.arithmetic_operation(Expression::Add(parsed_left.clone(), parsed_right));
+                Expression::Assign(parsed_left, Box::new(operation))
+            }
+            SolangExpression::AssignSubtract(_, left, right) => {
+                assigned_operands!(parsed_left, parsed_right, left, right);
+                if self.arithmetic == Arithmetic::Plain {
+                    return Expression::AssignSubtract(parsed_left, parsed_right)
+                }
+                let operation = self
+                    .arithmetic_operation(Expression::Subtract(parsed_left.clone(), parsed_right));
+                Expression::Assign(parsed_left, Box::new(operation))
+            }
+            SolangExpression::AssignMultiply(_, left, right) => {
+                assigned_operands!(parsed_left, parsed_right, left, right);
+                if self.arithmetic == Arithmetic::Plain {
+                    return Expression::AssignMultiply(parsed_left, parsed_right)
+                }
+                let operation = self
+                    .arithmetic_operation(Expression::Multiply(parsed_left.clone(), parsed_right));
+                Expression::Assign(parsed_left, Box::new(operation))
+            }
+            SolangExpression::AssignDivide(_, left, right) => {
+                assigned_operands!(parsed_left, parsed_right, left, right);
+                Expression::AssignDivide(parsed_left, parsed_right)
+            }
+            SolangExpression::AssignModulo(_, left, right) => {
+                assigned_operands!(parsed_left, parsed_right, left, right);
+                Expression::AssignModulo(parsed_left, parsed_right)
+            }
+            SolangExpression::BoolLiteral(_, value) => Expression::BoolLiteral(*value),
+            SolangExpression::NumberLiteral(_, literal, exponent) => {
+                if !exponent.is_empty() {
+                    // the literal may not fit into 128 bits, so we append the zeros
+                    let exponent = exponent.parse::<usize>().unwrap();
+                    Expression::NumberLiteral(format!("{literal}{}", "0".repeat(exponent)))
+                } else {
+                    Expression::NumberLiteral(literal.clone())
+                }
+            }
+            SolangExpression::RationalNumberLiteral(_, integer_exp, float_exp, exp) => {
+                let float_len = float_exp.len();
+                let integer_exp = integer_exp.parse::<f64>().unwrap();
+                let float_exp = float_exp.parse::<f64>().unwrap_or(0_f64);
+                let exp = exp.parse::<i32>().unwrap_or(0);
+
+                let result =
+                    (integer_exp + float_exp * 10_f64.powi(-(float_len as i32))) * 10_f64.powi(exp);
+
+                Expression::NumberLiteral(result.to_string())
+            }
+            SolangExpression::HexNumberLiteral(_, hex_number) => {
+                Expression::HexLiteral(hex_number.clone())
+            }
+            SolangExpression::StringLiteral(strings) => {
+                let parsed_strings = strings
+                    .iter()
+                    .map(|string_literal| unescape_string(&string_literal.string))
+                    .collect();
+                Expression::StringLiteral(parsed_strings)
+            }
+            SolangExpression::Type(_, solidity_type) => {
+                let parsed_type = Box::new(self.convert_solidity_type(solidity_type));
+                match *parsed_type {
+                    Type::Uint(256) => self.imports.insert(Import::U256),
+                    Type::Int(256) => self.imports.insert(Import::I256),
+                    _ => true,
+                };
+                Expression::Type(parsed_type)
+            }
+            SolangExpression::HexLiteral(hex_vec) => {
+                let literal = hex_vec
+                    .iter()
+                    .map(|hex| hex.hex.clone())
+                    .collect::<Vec<_>>()
+                    .join("");
+                Expression::HexLiteral(literal)
+            }
+            SolangExpression::AddressLiteral(_, literal) => Expression::HexLiteral(literal.clone()),
+            SolangExpression::Variable(identifier) => {
+                let parsed_identifier = self.resolve_symbol(&identifier.name);
+                if parsed_identifier == "_" {
+                    return Expression::ModifierBody
+                }
+                let none = MemberType::None(Box::new(Type::None));
+                // local variables and parameters shadow the members of the contract
+                let member_type = if self.locals_map.contains_key(&parsed_identifier) {
+                    &none
+                } else {
+                    self.members_map.get(&parsed_identifier).unwrap_or(&none)
+                };
+                Expression::Variable(parsed_identifier, member_type.clone(), location)
+            }
+            SolangExpression::List(_, parameters) => {
+                let list = parameters
+                    .iter()
+                    .map(|(_, parameter_maybe)| {
+                        match parameter_maybe {
+                            // tuple of values, the value is stored as the type of the parameter
+                            Some(Parameter { ty, name: None, .. }) => {
+                                self.parse_expression(ty, location.clone())
+                            }
+                            Some(parameter) => {
+                                Expression::Variable(
+                                    self.parse_identifier(&parameter.name),
+                                    MemberType::None(Box::new(Type::None)),
+                                    location.clone(),
+                                )
+                            }
+                            None => Expression::None,
+                        }
+                    })
+                    .collect();
+                Expression::List(list)
+            }
+            SolangExpression::ArrayLiteral(_, content) => {
+                let list = self.parse_expression_vec(content, location);
+                Expression::ArrayLiteral(list)
+            }
+            SolangExpression::Unit(_, exp, unit) => {
+                let constant: i128 = match unit {
+                    Unit::Seconds(_) => 1,
+                    Unit::Minutes(_) => 60,
+                    Unit::Hours(_) => 3600,
+                    Unit::Days(_) => 86400,
+                    Unit::Weeks(_) => 604800,
+                    Unit::Wei(_) => 1,
+                    Unit::Gwei(_) => 1_000_000_000,
+                    Unit::Ether(_) => 1_000_000_000_000_000_000,
+                };
+
+                boxed_expression!(parsed_exp, exp);
+
+                Expression::Unit(parsed_exp, constant)
+            }
+            SolangExpression::This(_) => Expression::This(location),
+        }
+    }
+
+    /// Parses the creation of a contract, like `new Pair()` or `new Pair{salt: salt}()`,
+    /// the code hash of the created contract is stored in the storage of the creating contract
+    ///
+    /// `expression` the Solang expression following `new`
+    /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns `Expression::Instantiate` or `None` if the created value is not a contract
+    fn parse_instantiation(
+        &mut self,
+        expression: &SolangExpression,
+        location: &VariableAccessLocation,
+    ) -> Option<Expression> {
+        let (function, args) = match expression {
+            SolangExpression::FunctionCall(_, function, args) => (function, args),
+            _ => return None,
+        };
+        let (contract, options) = match &**function {
+            SolangExpression::Variable(identifier) => (identifier, None),
+            SolangExpression::FunctionCallBlock(_, function, options)
+                if let SolangExpression::Variable(identifier) = &**function =>
+            {
+                (identifier, Some(options))
+            }
+            _ => return None,
+        };
+        let contract = self.resolve_symbol(&contract.name);
+        if !self.is_contract_type(&contract) {
+            return None
+        }
+
+        let mut value = None;
+        let mut salt = None;
+        if let Some(SolangStatement::Args(_, options)) = options.map(|options| &**options) {
+            for option in options.iter() {
+                let parsed_option = Box::new(self.parse_expression(&option.expr, location.clone()));
+                match option.name.name.as_str() {
+                    "value" => value = Some(Box::new(self.to_balance(*parsed_option))),
+                    "salt" => salt = Some(parsed_option),
+                    _ => (),
+                }
+            }
+        }
+        let parsed_args = self.parse_expression_vec(args, location.clone());
+        Some(self.instantiate(contract, parsed_args, value, salt, location))
+    }
+
+    /// Creates a contract from its code hash, which is stored in the storage of the creating contract
+    ///
+    /// `contract` the name of the created contract
+    /// `args` the parsed arguments of the constructor
+    /// `value` the value transferred to the created contract
+    /// `salt` the salt of the address of the created contract
+    /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns `Expression::Instantiate`
+    fn instantiate(
+        &mut self,
+        contract: String,
+        args: Vec<Expression>,
+        value: Option<Box<Expression>>,
+        salt: Option<Box<Expression>>,
+        location: &VariableAccessLocation,
+    ) -> Expression {
+        if !self.created_contracts.contains(&contract) {
+            self.created_contracts.push(contract.clone());
+        }
+        let code_hash = Expression::Variable(
+            code_hash_field(&contract),
+            MemberType::Variable(Box::new(Type::Bytes(32))),
+            location.clone(),
+        );
+        Expression::Instantiate(contract, Box::new(code_hash), args, value, salt)
+    }
+
+    /// Returns the contract if the expression is its creation code, like `type(Pair).creationCode`
+    ///
+    /// `expression` the Solang expression
+    fn creation_code(&self, expression: &SolangExpression) -> Option<String> {
+        match expression {
+            SolangExpression::MemberAccess(_, function_call, member)
+                if member.name == "creationCode" =>
+            {
+                match &**function_call {
+                    SolangExpression::FunctionCall(_, function, args) => {
+                        match (&**function, &args[..]) {
+                            (
+                                SolangExpression::Variable(function),
+                                [SolangExpression::Variable(contract)],
+                            ) if function.name == "type" => {
+                                let contract = self.resolve_symbol(&contract.name);
+                                self.is_contract_type(&contract).then_some(contract)
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses a call of an `abi` function, like `abi.encodePacked(token0, token1)`
+    /// or `abi.decode(data, (uint, address))`, the types of the values are kept
+    /// since the encodings depend on them
+    ///
+    /// `function` the called Solang expression
+    /// `args` the Solang arguments of the call
+    /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns the parsed call or `None` if the called function is not an `abi` function
+    fn parse_abi_call(
+        &mut self,
+        function: &SolangExpression,
+        args: &[SolangExpression],
+        location: &VariableAccessLocation,
+    ) -> Option<Expression> {
+        let member = match function {
+            SolangExpression::MemberAccess(_, abi, member) if matches!(&**abi, SolangExpression::Variable(identifier) if identifier.name == "abi") => {
+                member.name.as_str()
+            }
+            _ => return None,
+        };
+        if member == "decode" {
+            let (data, types) = match args {
+                [data, types] => (data, types),
+                _ => return None,
+            };
+            let types = match types {
+                SolangExpression::List(_, parameters) => {
+                    parameters
+                        .iter()
+                        .filter_map(|(_, parameter)| parameter.as_ref())
+                        .map(|parameter| self.parse_type(&parameter.ty))
+                        .collect::<Result<Vec<_>, _>>()
+                        .ok()?
+                }
+                SolangExpression::Parenthesis(_, ty) => vec![self.parse_type(ty).ok()?],
+                ty => vec![self.parse_type(ty).ok()?],
+            };
+            let data = self.parse_expression(data, location.clone());
+            return Some(Expression::AbiDecode(
+                self.abi_encoding,
+                Box::new(data),
+                types,
+            ))
+        }
+
+        let parsed_args = self.parse_expression_vec(args, location.clone());
+        self.imports.insert(Import::Vec);
+        // integers are converted to `U256` to be encoded as 32 byte words
+        if self.abi_encoding == AbiEncoding::Ethereum {
+            self.imports.insert(Import::U256);
+        }
+        // the return types of library functions are not known, like `Strings.toString(value)`
+        let typed = |values: &[Expression]| {
+            values
+                .iter()
+                .map(|value| {
+                    let ty = match value {
+                        Expression::LibraryCall(..) => None,
+                        _ => self.expression_type(value),
+                    };
+                    (value.clone(), ty.unwrap_or(Type::None))
+                })
+                .collect::<Vec<_>>()
+        };
+        match (member, &parsed_args[..]) {
+            ("encode", values) => {
+                Some(Expression::AbiEncode(
+                    self.abi_encoding,
+                    None,
+                    typed(values),
+                ))
+            }
+            // the values are packed with the declared width of their integer types
+            ("encodePacked", values) => {
+                Some(Expression::AbiEncodePacked(
+                    typed(values)
+                        .into_iter()
+                        .zip(args)
+                        .map(|((value, ty), argument)| {
+                            let ty = self.packed_type(argument, ty);
+                            (value, ty)
+                        })
+                        .collect(),
+                ))
+            }
+            ("encodeWithSelector", [selector, values @ ..]) => {
+                Some(Expression::AbiEncode(
+                    self.abi_encoding,
+                    Some(Box::new(selector.clone())),
+                    typed(values),
+                ))
+            }
+            ("encodeWithSignature", [Expression::StringLiteral(signature), values @ ..]) => {
+                Some(Expression::AbiEncode(
+                    self.abi_encoding,
+                    Some(Box::new(self.message_selector(&signature.concat()))),
+                    typed(values),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses multiple Solang expression enum variants to Sol2Ink expression enum variants
+    ///
+    /// `expressions` the original Solang expression enum variants
+    /// `location` the location where the expression is [being called](fn@parse_variable_access_location)
+    ///
+    /// Returns the vec of parsed `Expression` enum variant
+    fn parse_expression_vec(
+        &mut self,
+        expressions: &[SolangExpression],
+        location: VariableAccessLocation,
+    ) -> Vec<Expression> {
+        expressions
+            .iter()
+            .map(|expression| self.parse_expression(expression, location.clone()))
+            .collect()
+    }
+
+    /// Creates a subscript of a mapping, a lazy array or an array
+    /// Nested mappings are stored as a single mapping keyed by a tuple, so their subscripts are merged
+    /// into one `Expression::MappingSubscript`, while arrays are indexed per dimension
+    ///
+    /// `array` the parsed subscripted expression
+    /// `index_maybe` the parsed index
+    ///
+    /// Returns the parsed `Expression` enum variant
+    fn subscript(
+        &self,
+        array: Box<Expression>,
+        index_maybe: Option<Box<Expression>>,
+    ) -> Expression {
+        let index = match index_maybe {
+            Some(index) => *index,
+            None => return self.array_subscript(array, None),
+        };
+        if let Expression::MappingSubscript(mapping, indices) = &*array {
+            let nested_key = match self.expression_type(mapping) {
+                Some(Type::Mapping(keys, _)) => keys.get(indices.len()).cloned().map(Some),
+                Some(_) => None,
+                // subscripts of unknown mappings are expected to be nested
+                None => Some(None),
+            };
+            if let Some(key) = nested_key {
+                let mut indices = indices.clone();
+                indices.push(self.widen(index, key));
+                return Expression::MappingSubscript(mapping.clone(), indices)
+            }
+        }
+        // literal keys are converted to the type of the keys
+        match self.expression_type(&array) {
+            Some(Type::Mapping(keys, _)) => {
+                let index = self.widen(index, keys.first().cloned());
+                Expression::MappingSubscript(array, vec![index])
+            }
+            Some(Type::LazyArray(key, _)) => {
+                let index = self.widen(index, Some(*key));
+                Expression::MappingSubscript(array, vec![index])
+            }
+            _ => self.array_subscript(array, Some(Box::new(index))),
+        }
+    }
+
+    /// Parses a Solang `IdentifierPath` struct to String
+    ///
+    /// `identifier_path` the original Solang identifier
+    ///
+    /// Returns the parsed `String`
+    fn parse_identifier_path(&self, identifier_path: &IdentifierPath) -> String {
+        identifier_path
+            .identifiers
+            .iter()
+            .map(|identifier| identifier.name.clone())
+            .collect::<Vec<String>>()
+            .join("::")
+    }
+
+    /// Parses the Solang `IdentifierPath` of a reverted error to the name of the error
+    /// Errors qualified by a contract name (`Errors.Unauthorized`) become variants
+    /// of the generated `Error` enum, so we only keep the last identifier
+    ///
+    /// `identifier_path` the original Solang identifier
+    ///
+    /// Returns the parsed `String`
+    fn parse_error_path(&self, identifier_path: &IdentifierPath) -> String {
+        identifier_path
+            .identifiers
+            .last()
+            .map(|identifier| error_name(identifier.name.clone()))
+            .unwrap_or(String::from("_"))
+    }
+
+    /// Converts a Solang `Expression::Type` enum variant to Sol2Ink `Type` enum variant
+    /// We do this to convert some Solidity specific types into ink! specific types
+    ///
+    /// `ty` the origina Solang `Expression::Type` enum variant
+    ///
+    /// Returns the parsed `Type` enum variant
+    fn parse_type(&mut self, ty: &SolangExpression) -> Result<Type, ParserError> {
+        match &ty {
+            SolangExpression::Type(_, SolangType::Mapping(_, key_type, value_type)) => {
+                let mut parsed_key_types = vec![self.parse_type(key_type)?];
+                let mut value_type_now = value_type.as_ref();
+                while let SolangExpression::Type(
+                    _,
+                    SolangType::Mapping(_, key_type_value, value_type_value),
+                ) = value_type_now
+                {
+                    parsed_key_types.push(self.parse_type(key_type_value)?);
+                    value_type_now = value_type_value;
+                }
+                let parsed_value_type = self.parse_type(value_type_now)?;
+                self.imports.insert(Import::Mapping);
+                Ok(Type::Mapping(parsed_key_types, Box::new(parsed_value_type)))
+            }
+            SolangExpression::Type(
+                _,
+                SolangType::Function {
+                    params,
+                    attributes,
+                    returns,
+                },
+            ) => {
+                let parsed_params = params
+                    .iter()
+                    .map(|(_, param)| self.parse_function_param(param.as_ref()))
+                    .map(|param| param.map(|param| param.param_type))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let parsed_returns = returns
+                    .iter()
+                    .flat_map(|(returns, _)| returns.iter())
+                    .map(|(_, param)| self.parse_function_param(param.as_ref()))
+                    .map(|param| param.map(|param| param.param_type))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let external = attributes.iter().any(|attribute| {
+                    matches!(
+                        attribute,
+                        FunctionAttribute::Visibility(Visibility::External(_))
+                    )
+                });
+                let view = attributes.iter().any(|attribute| {
+                    matches!(
+                        attribute,
+                        FunctionAttribute::Mutability(Mutability::Pure(_) | Mutability::View(_))
+                    )
+                });
+                if external {
+                    self.imports.insert(Import::AccountId);
+                    Ok(Type::ExternalFunction(parsed_params, parsed_returns))
+                } else {
+                    Ok(Type::Function(parsed_params, parsed_returns, view))
+                }
+            }
+            SolangExpression::Type(_, solidity_type) => {
+                let converted_type = self.convert_solidity_type(solidity_type);
+                match converted_type {
+                    Type::Array(..) => self.imports.insert(Import::Vec),
+                    Type::AccountId | Type::PayableAccountId => {
+                        self.imports.insert(Import::AccountId)
+                    }
+                    Type::String => self.imports.insert(Import::String),
+                    Type::DynamicBytes => self.imports.insert(Import::Vec),
+                    Type::Mapping(_, _) => self.imports.insert(Import::Mapping),
+                    Type::Uint(256) => self.imports.insert(Import::U256),
+                    Type::Int(256) => self.imports.insert(Import::I256),
+                    _ => true,
+                };
+                Ok(converted_type)
+            }
+            SolangExpression::Variable(identifier) => {
+                let name = self.resolve_symbol(&identifier.name);
+                if self.is_contract_type(&name) {
+                    self.imports.insert(Import::AccountId);
+                    Ok(Type::Contract(name))
+                } else {
+                    Ok(Type::Variable(name))
+                }
+            }
+            SolangExpression::ArraySubscript(_, ty, expression_maybe) => {
+                let parsed_type = Box::new(self.parse_type(ty)?);
+                // sizes of fixed size arrays are evaluated, since Rust needs a `usize` constant
+                let parsed_expression = expression_maybe.as_ref().map(|option| {
+                    let parsed_expression =
+                        self.parse_expression(option, VariableAccessLocation::Any);
+                    match self.constant_value(&parsed_expression) {
+                        Some(size) => Expression::NumberLiteral(size.to_string()),
+                        None => parsed_expression,
+                    }
+                });
+                Ok(Type::Array(parsed_type, parsed_expression))
+            }
+            SolangExpression::MemberAccess(_, from, identifier) if self.is_namespace(from) => {
+                Ok(Type::Variable(self.resolve_symbol(&identifier.name)))
+            }
+            SolangExpression::MemberAccess(_, from, identifier) => {
+                let parsed_expression = self.parse_expression(from, VariableAccessLocation::Any);
+                let parsed_identifier = self.parse_identifier(&Some(identifier.clone()));
+                Ok(Type::MemberAccess(parsed_expression, parsed_identifier))
+            }
+            _ => {
+                println!("{ty:?}");
+                Err(ParserError::IncorrectTypeOfVariable)
+            }
+        }
+    }
+
+    /// Converts a Solang `Type` enum variant to Sol2Ink `Type` enum variant
+    /// We do this to convert some Solidity specific types into ink! specific types
+    ///
+    /// `ty` the origina Solang `Type` enum variant
+    ///
+    /// Returns the parsed `Type` enum variant
+    fn convert_solidity_type(&self, solidity_type: &SolangType) -> Type {
+        match solidity_type {
+            SolangType::Address => Type::AccountId,
+            // `payable(account)` converts an address to a payable address
+            SolangType::AddressPayable | SolangType::Payable => Type::PayableAccountId,
+            SolangType::Bool => Type::Bool,
+            SolangType::String => Type::String,
+            // ink! has no signed 256 bit integer, `I256` is generated in the libs
+            SolangType::Int(original_bytes) => Type::Int(self.convert_int_bits(original_bytes)),
+            SolangType::Uint(original_bytes) => Type::Uint(self.convert_int_bits(original_bytes)),
+            SolangType::Bytes(length) => Type::Bytes(*length),
+            SolangType::DynamicBytes => Type::DynamicBytes,
+            _ => Type::None,
+        }
+    }
+
+    /// Converts a Solidity integer size into rust integer size
+    /// Possible variants in Rust are 8, 16, 32, 64, 128 and 256 (`U256`), we choose the nearest possible to fit
+    /// If the integers are narrowed, sizes greater than 128 bits are converted to 128 bits
+    ///
+    /// `original_bits` the origina size of the integer
+    ///
+    /// Returns the converted integer size
+    fn convert_int_bits(&self, original_bits: &u16) -> u16 {
+        match *original_bits {
+            i if i <= 8 => 8,
+            i if i <= 16 => 16,
+            i if i <= 32 => 32,
+            i if i <= 64 => 64,
+            i if i <= 128 || self.narrow_integers => 128,
+            _ => 256,
+        }
+    }
+
+    /// Returns the type of an integer with its declared width, like `Type::Uint(24)` for `uint24`,
+    /// if the width is known and the integer is not narrowed
+    ///
+    /// `argument` the Solang expression of the value
+    /// `ty` the type of the parsed value
+    fn packed_type(&self, argument: &SolangExpression, ty: Type) -> Type {
+        let bits = match argument {
+            SolangExpression::FunctionCall(_, function, _) => declared_int_bits(function),
+            SolangExpression::Variable(identifier) => {
+                self.int_bits
+                    .get(&identifier.name)
+                    .or(self.field_int_bits.get(&identifier.name))
+                    .copied()
+            }
+            _ => None,
+        };
+        match (ty, bits) {
+            (Type::Uint(rounded), Some(bits)) if self.convert_int_bits(&bits) == rounded => {
+                Type::Uint(bits)
+            }
+            (Type::Int(rounded), Some(bits)) if self.convert_int_bits(&bits) == rounded => {
+                Type::Int(bits)
+            }
+            (ty, _) => ty,
+        }
+    }
+
+    /// Converts the type of a storage field selected as a lazy array to a mapping from indices
+    /// to elements, the length of the array is stored in a separate field
+    ///
+    /// `contract` the name of the contract
+    /// `field` the name of the storage field
+    /// `ty` the parsed type of the storage field
+    ///
+    /// Returns the type the field is stored as
+    fn storage_array_type(&mut self, contract: &str, field: &str, ty: Type) -> Type {
+        let lazy = self.lazy_arrays.contains(field)
+            || self.lazy_arrays.contains(&format!("{contract}.{field}"));
+        match ty {
+            Type::Array(element, None) if lazy => {
+                self.imports.insert(Import::Mapping);
+                let index = Type::Uint(self.convert_int_bits(&256));
+                if index == Type::Uint(256) {
+                    self.imports.insert(Import::U256);
+                }
+                Type::LazyArray(Box::new(index), element)
+            }
+            _ => ty,
+        }
+    }
+
+    /// Converts a value to `U256` or `I256` if it is used as a 256 bit integer, since Rust
+    /// converts neither integer literals nor narrower integers, like a `uint112` stored in `u128`
+    ///
+    /// `expression` the parsed expression
+    /// `ty` the type the expression is used as
+    ///
+    /// Returns the converted expression
+    fn widen(&self, expression: Expression, ty: Option<Type>) -> Expression {
+        let narrower = match (self.expression_type(&expression), &ty) {
+            (Some(Type::Uint(bits)), Some(Type::Uint(256) | Type::Int(256)))
+            | (Some(Type::Int(bits)), Some(Type::Int(256))) => bits < 256,
+            _ => false,
+        };
+        match ty {
+            Some(ty) if narrower && !is_number_literal(&expression) => {
+                Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(ty))),
+                    vec![expression],
+                    None,
+                )
+            }
+            ty => self.widen_literal(expression, ty),
+        }
+    }
+
+    /// Converts a number literal to `U256` if it is used as a 256 bit integer,
+    /// since Rust does not convert integer literals to `U256` implicitly
+    ///
+    /// `expression` the parsed expression, which may be a number literal
+    /// `ty` the type the expression is used as
+    ///
+    /// Returns the converted expression
+    fn widen_literal(&self, expression: Expression, ty: Option<Type>) -> Expression {
+        match (&expression, ty) {
+            (
+                Expression::NumberLiteral(_) | Expression::HexLiteral(_),
+                Some(ty @ (Type::Uint(256) | Type::Int(256))),
+            ) => {
+                Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(ty))),
+                    vec![expression],
+                    None,
+                )
+            }
+            (Expression::UnaryMinus(value), Some(ty @ Type::Int(256))) => {
+                Expression::UnaryMinus(Box::new(self.widen_literal(*value.clone(), Some(ty))))
+            }
+            // operations of literals only, like `10 ** 3`, are evaluated to a single literal
+            (_, Some(ty @ (Type::Uint(256) | Type::Int(256))))
+                if is_literal_operation(&expression) =>
+            {
+                match self.constant_value(&expression) {
+                    Some(value) => {
+                        self.widen_literal(Expression::NumberLiteral(value.to_string()), Some(ty))
+                    }
+                    None => expression,
+                }
+            }
+            _ => expression,
+        }
+    }
+
+    /// Evaluates a constant expression, like the size of a fixed size array
+    ///
+    /// `expression` the parsed expression
+    ///
+    /// Returns the value of the expression or `None` if it can not be evaluated
+    fn constant_value(&self, expression: &Expression) -> Option<u128> {
+        let operands = |left: &Expression, right: &Expression| {
+            Some((self.constant_value(left)?, self.constant_value(right)?))
+        };
+        match expression {
+            // literals typed with a suffix, like `10_u128`, are evaluated without the suffix
+            Expression::NumberLiteral(number) => {
+                number
+                    .split("_u")
+                    .next()?
+                    .split("_i")
+                    .next()?
+                    .replace('_', "")
+                    .parse()
+                    .ok()
+            }
+            Expression::HexLiteral(hex) if hex.starts_with("0x") => {
+                u128::from_str_radix(&hex[2..].replace('_', ""), 16).ok()
+            }
+            Expression::Variable(name, MemberType::Constant, _) => {
+                self.constant_values.get(name).copied()
+            }
+            Expression::Parenthesis(expression)
+            | Expression::Checked(expression)
+            | Expression::Wrapping(expression) => self.constant_value(expression),
+            Expression::FunctionCall(function, args, _)
+                if let (Expression::Type(_), [argument]) = (&**function, &args[..]) =>
+            {
+                self.constant_value(argument)
+            }
+            Expression::Add(left, right) => {
+                operands(left, right).and_then(|(left, right)| left.checked_add(right))
+            }
+            Expression::Subtract(left, right) => {
+                operands(left, right).and_then(|(left, right)| left.checked_sub(right))
+            }
+            Expression::Multiply(left, right) => {
+                operands(left, right).and_then(|(left, right)| left.checked_mul(right))
+            }
+            Expression::Divide(left, right) => {
+                operands(left, right).and_then(|(left, right)| left.checked_div(right))
+            }
+            Expression::Modulo(left, right) => {
+                operands(left, right).and_then(|(left, right)| left.checked_rem(right))
+            }
+            Expression::Power(left, right) => {
+                operands(left, right)
+                    .and_then(|(left, right)| left.checked_pow(right.try_into().ok()?))
+            }
+            Expression::ShiftLeft(left, right) => {
+                operands(left, right)
+                    .and_then(|(left, right)| left.checked_shl(right.try_into().ok()?))
+            }
+            _ => None,
+        }
+    }
+
+    /// Wraps an arithmetic operation to be checked or wrapping, depending on the Solidity version
+    /// and on the operation being in an `unchecked` block
+    /// Number literals used as the receiver of the operation get the type of the other operand,
+    /// so Rust can call the method on them
+    /// Operations on user-defined value types are kept as they are, the operator implementations
+    /// of the newtype call the functions bound to the operators
+    ///
+    /// `operation` the parsed arithmetic operation
+    ///
+    /// Returns the wrapped operation
+    fn arithmetic_operation(&self, operation: Expression) -> Expression {
+        if let Expression::Add(left, right)
+        | Expression::Subtract(left, right)
+        | Expression::Multiply(left, right) = &operation
+        {
+            let value_type = |operand: &Expression| {
+                matches!(
+                    self.expression_type(operand),
+                    Some(Type::Variable(name)) if self.value_types.contains(&name)
+                )
+            };
+            if value_type(left) || value_type(right) {
+                return operation
+            }
+        }
+        let operation = match operation {
+            Expression::Add(left, right) => {
+                Expression::Add(Box::new(self.typed_literal(*left, &right)), right)
+            }
+            Expression::Subtract(left, right) => {
+                Expression::Subtract(Box::new(self.typed_literal(*left, &right)), right)
+            }
+            Expression::Multiply(left, right) => {
+                Expression::Multiply(Box::new(self.typed_literal(*left, &right)), right)
+            }
+            Expression::Power(left, right) => {
+                Expression::Power(Box::new(self.typed_literal(*left, &right)), right)
+            }
+            _ => operation,
+        };
+        match self.arithmetic {
+            Arithmetic::Plain => operation,
+            Arithmetic::Checked => Expression::Checked(Box::new(operation)),
+            Arithmetic::Wrapping => Expression::Wrapping(Box::new(operation)),
+        }
+    }
+
+    /// Adds the type suffix to a number literal, like `1_u8`, if the other operand has a known integer type
+    ///
+    /// `expression` the parsed expression, which may be a number literal
+    /// `other` the other operand of the operation
+    ///
+    /// Returns the typed expression
+    fn typed_literal(&self, expression: Expression, other: &Expression) -> Expression {
+        match (&expression, self.expression_type(other)) {
+            (Expression::NumberLiteral(number), Some(Type::Uint(bits))) if bits <= 128 => {
+                Expression::NumberLiteral(format!("{number}_u{bits}"))
+            }
+            (Expression::NumberLiteral(number), Some(Type::Int(bits))) if bits <= 128 => {
+                Expression::NumberLiteral(format!("{number}_i{bits}"))
+            }
+            _ => expression,
+        }
+    }
+
+    /// Parses a Solang `Identifier` struct to String
+    ///
+    /// `identifier` the original Solang identifier
+    ///
+    /// Returns the parsed `String`
+    fn parse_identifier(&self, identifier: &Option<Identifier>) -> String {
+        match identifier {
+            Some(identifier) => identifier.name.clone(),
+            None => String::from("_"),
+        }
+    }
+}
+
+/// Returns the paths of the files imported by a solidity file
+///
+/// `path` the path to the solidity file
+/// `content` the content of the solidity file
+/// `resolver` the resolver of the imported paths
+pub fn file_imports(
+    path: &str,
+    content: &str,
+    resolver: &ImportResolver,
+) -> Result<Vec<PathBuf>, ParserError> {
+    let (content, _) = extract_operator_bindings(content);
+    let (source_unit, _) = parse(&content, 0).map_err(|errors| {
+        ParserError::FileCorrupted(errors.iter().map(|error| error.message.clone()).collect())
+    })?;
+
+    Ok(source_unit
+        .0
+        .iter()
+        .filter_map(|source_unit_part| {
+            match source_unit_part {
+                SourceUnitPart::ImportDirective(
+                    SolangImport::Plain(file, _)
+                    | SolangImport::GlobalSymbol(file, ..)
+                    | SolangImport::Rename(file, ..),
+                ) => Some(resolver.resolve(path, &file.string)),
+                _ => None,
+            }
+        })
+        .collect())
+}
+
+/// Returns the name of the internal function a `super` call resolves to,
+/// implemented by the base following the calling contract in the linearization
+///
+/// `function` the name of the function called through `super`
+/// `contract` the name of the contract calling the function
+fn super_function(function: &str, contract: &str) -> String {
+    format!("{function}_super_{contract}")
+}
+
+/// Returns the name of the storage field holding the code hash of a contract created by the parsed contract
+///
+/// `contract` the name of the created contract
+fn code_hash_field(contract: &str) -> String {
+    format!("{}_code_hash", contract.to_case(Snake))
+}
+
+/// Returns the declared width of an integer type, like 24 of `uint24`
+///
+/// `ty` the Solang type
+fn declared_int_bits(ty: &SolangExpression) -> Option<u16> {
+    match ty {
+        SolangExpression::Type(_, SolangType::Uint(bits) | SolangType::Int(bits)) => Some(*bits),
+        _ => None,
+    }
+}
+
+/// Returns the name of the `Error` variant of a Solidity error
+/// The `Custom` variant holds the messages of `revert` and `require`, so the error named `Custom` is renamed
+///
+/// `error` the name of the Solidity error
+fn error_name(error: String) -> String {
+    match error.as_str() {
+        "Custom" => String::from("CustomError"),
+        _ => error,
+    }
+}
+
+/// Converts a Solang data location to Sol2Ink data location
+///
+/// `storage` the Solang data location of a parameter or a variable
+fn data_location(storage: &Option<StorageLocation>) -> Option<DataLocation> {
+    match storage {
+        Some(StorageLocation::Memory(_)) => Some(DataLocation::Memory),
+        Some(StorageLocation::Storage(_)) => Some(DataLocation::Storage),
+        Some(StorageLocation::Calldata(_)) => Some(DataLocation::Calldata),
+        None => None,
+    }
+}
+
+/// Returns the value changed by an expression, like the left side of an assignment
+///
+/// `expression` the parsed expression
+fn assignment_target(expression: &mut Expression) -> Option<&mut Box<Expression>> {
+    match expression {
+        Expression::Assign(target, _)
+        | Expression::AssignAdd(target, _)
+        | Expression::AssignSubtract(target, _)
+        | Expression::AssignMultiply(target, _)
+        | Expression::AssignDivide(target, _)
+        | Expression::AssignModulo(target, _)
+        | Expression::AssignOr(target, _)
+        | Expression::AssignAnd(target, _)
+        | Expression::AssignXor(target, _)
+        | Expression::AssignShiftLeft(target, _)
+        | Expression::AssignShiftRight(target, _)
+        | Expression::PostIncrement(target)
+        | Expression::PostDecrement(target)
+        | Expression::PreIncrement(target)
+        | Expression::PreDecrement(target)
+        | Expression::Delete(target)
+        | Expression::Push(target, _)
+        | Expression::Pop(target) => Some(target),
+        _ => None,
+    }
+}
+
+/// Returns the value read from a mapping which is changed through a member or an element,
+/// like `balances[user]` in `balances[user].amount`, the one closest to the storage is returned
+///
+/// `expression` the changed value
+fn mapping_entry(expression: &Expression) -> Option<&Expression> {
+    match expression {
+        Expression::MemberAccess(inner, _)
+        | Expression::ArraySubscript(inner, _)
+        | Expression::MappingSubscript(inner, _) => {
+            mapping_entry(inner).or(match **inner {
+                Expression::MappingSubscript(..) => Some(&**inner),
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the name of the local holding a copy of a stored value, like `positions_entry` for `positions[user]`
+///
+/// `expression` the stored value
+fn entry_name(expression: &Expression) -> String {
+    match expression {
+        Expression::Variable(name, ..) | Expression::MemberAccess(_, name) => {
+            format!("{name}_entry").to_case(Snake)
+        }
+        Expression::MappingSubscript(mapping, _) => entry_name(mapping),
+        _ => String::from("entry"),
+    }
+}
+
+/// Returns the name of the variable a member or an element is accessed on, like `p` in `p.values[1]`
+///
+/// `expression` the changed value
+fn place_root(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::MemberAccess(inner, _)
+        | Expression::ArraySubscript(inner, _)
+        | Expression::MappingSubscript(inner, _) => {
+            match &**inner {
+                Expression::Variable(name, ..) => Some(name.clone()),
+                _ => place_root(inner),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Replaces the accessed value in a chain of member and element accesses
+///
+/// `expression` the chain of accesses
+/// `from` the replaced value
+/// `to` the new value
+fn replace_expression(expression: &mut Expression, from: &Expression, to: &Expression) {
+    if expression == from {
+        *expression = to.clone();
+        return
+    }
+    if let Expression::MemberAccess(inner, _)
+    | Expression::ArraySubscript(inner, _)
+    | Expression::MappingSubscript(inner, _) = expression
+    {
+        replace_expression(inner, from, to)
+    }
+}
+
+/// Returns true if the arithmetic of a file with the version pragma is checked, which Solidity does since 0.8
+/// The lowest version mentioned in the pragma is used, so the arithmetic of `>=0.7.0 <0.9.0` is not checked
+///
+/// `version` the version of the `pragma solidity` directive
+fn checked_arithmetic(version: &str) -> bool {
+    version
+        .split(|char: char| !char.is_ascii_digit() && char != '.')
+        .filter_map(|version| {
+            let mut numbers = version.split('.').map(|number| number.parse::<u32>());
+            match (numbers.next(), numbers.next()) {
+                (Some(Ok(major)), Some(Ok(minor))) => Some((major, minor)),
+                _ => None,
+            }
+        })
+        .min()
+        .is_none_or(|version| version >= (0, 8))
+}
+
+/// Solang can not parse user defined operators, like `using {add as +} for Price global`,
+/// so we record the operators and replace them with whitespace before parsing the file
+/// Replacing keeps the locations of the comments intact
+///
+/// `content` the content of a solidity file
+///
+/// Returns the content without the operators and the map of the bound functions to their operators
+fn extract_operator_bindings(content: &str) -> (String, HashMap<String, String>) {
+    let mut bytes = content.as_bytes().to_vec();
+    let mut operators = HashMap::new();
+    let mut search_from = 0;
+
+    while let Some(found) = content[search_from..].find("using") {
+        let start = search_from + found;
+        search_from = start + "using".len();
+        let preceded_by_identifier = content[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|char| char.is_alphanumeric() || char == '_');
+        let rest = content[search_from..].trim_start();
+        if preceded_by_identifier || !rest.starts_with('{') {
+            continue
+        }
+        let open = content.len() - rest.len();
+        if let Some(close) = content[open..].find('}') {
+            let mut item_start = open + 1;
+            for item in content[open + 1..open + close].split(',') {
+                if let [function, "as", operator] = item.split_whitespace().collect::<Vec<_>>()[..]
+                {
+                    let function_name = function.rsplit('.').next().unwrap_or(function);
+                    operators.insert(function_name.to_owned(), operator.to_owned());
+                    let function_end = item_start + item.find(function).unwrap() + function.len();
+                    bytes[function_end..item_start + item.len()]
+                        .iter_mut()
+                        .filter(|byte| !byte.is_ascii_whitespace())
+                        .for_each(|byte| *byte = b' ');
+                }
+                item_start += item.len() + 1;
+            }
+            search_from = open + close;
+        }
+    }
+
+    (String::from_utf8(bytes).unwrap(), operators)
+}
+
+/// Returns the Yul source of an expression, used to mark untranslatable assembly
+///
+/// `expression` the original Yul expression
+fn yul_source(expression: &YulExpression) -> String {
+    match expression {
+        YulExpression::BoolLiteral(_, value, _) => value.to_string(),
+        YulExpression::NumberLiteral(_, literal, exponent, _) if exponent.is_empty() => {
+            literal.clone()
+        }
+        YulExpression::NumberLiteral(_, literal, exponent, _) => format!("{literal}e{exponent}"),
+        YulExpression::HexNumberLiteral(_, literal, _) => literal.clone(),
+        YulExpression::HexStringLiteral(literal, _) => format!("hex\"{}\"", literal.hex),
+        YulExpression::StringLiteral(literal, _) => format!("\"{}\"", literal.string),
+        YulExpression::Variable(identifier) => identifier.name.clone(),
+        YulExpression::FunctionCall(function_call) => {
+            format!(
+                "{}({})",
+                function_call.id.name,
+                function_call
+                    .arguments
+                    .iter()
+                    .map(yul_source)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+        YulExpression::SuffixAccess(_, expression, suffix) => {
+            format!("{}.{}", yul_source(expression), suffix.name)
+        }
+    }
+}
+
+/// Wraps operators in parenthesis, since nested Yul calls carry no precedence
+///
+/// `expression` the parsed argument of a Yul builtin
+fn yul_operand(expression: &Expression) -> Expression {
+    match expression {
+        Expression::Add(..)
+        | Expression::Subtract(..)
+        | Expression::Multiply(..)
+        | Expression::Divide(..)
+        | Expression::Modulo(..)
+        | Expression::Power(..)
+        | Expression::BitwiseAnd(..)
+        | Expression::BitwiseOr(..)
+        | Expression::BitwiseXor(..)
+        | Expression::ShiftLeft(..)
+        | Expression::ShiftRight(..)
+        | Expression::Less(..)
+        | Expression::More(..)
+        | Expression::Equal(..)
+        | Expression::NotEqual(..) => Expression::Parenthesis(Box::new(expression.clone())),
+        _ => expression.clone(),
+    }
+}
+
+/// Returns the value of a Solidity string literal, whose escape sequences are kept by the lexer,
+/// so `"a\nb"` becomes `a`, a line feed and `b`
+/// Bytes written with `\xNN` which do not form valid UTF-8 are replaced by `U+FFFD`
+///
+/// `string` the text of the literal between the quotes
+fn unescape_string(string: &str) -> String {
+    let hex_value = |chars: &mut std::str::Chars, digits: usize| {
+        u32::from_str_radix(&chars.take(digits).collect::<String>(), 16).unwrap_or_default()
+    };
+    let mut bytes = Vec::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(char) = chars.next() {
+        let char = match char {
+            '\\' => {
+                match chars.next() {
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('x') => {
+                        bytes.push(hex_value(&mut chars, 2) as u8);
+                        continue
+                    }
+                    Some('u') => {
+                        char::from_u32(hex_value(&mut chars, 4))
+                            .unwrap_or(char::REPLACEMENT_CHARACTER)
+                    }
+                    // escaped line breaks continue the string on the next line
+                    Some('\r') => {
+                        if chars.clone().next() == Some('\n') {
+                            chars.next();
+                        }
+                        continue
+                    }
+                    Some('\n') | None => continue,
+                    Some(escaped) => escaped,
+                }
+            }
+            _ => char,
+        };
+        bytes.extend_from_slice(char.encode_utf8(&mut [0; 4]).as_bytes());
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Returns the signed result of `sdiv`, `smod` or `sar` before it is converted to a word
+///
+/// `expression` the parsed Yul expression
+fn signed_word(expression: &Expression) -> Option<&Expression> {
+    let signed = match expression {
+        Expression::FunctionCall(function, args, _)
+            if let (Expression::Type(ty), [signed]) = (&**function, &args[..])
+                && let Type::Uint(_) = **ty =>
+        {
+            signed
+        }
+        Expression::Cast(ty, signed) if let Type::Uint(_) = **ty => {
+            match &**signed {
+                Expression::Parenthesis(signed) => signed,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    match signed {
+        Expression::AssemblyCall(function, ..) if function == "sdiv" || function == "smod" => {
+            Some(signed)
+        }
+        Expression::ShiftRight(value, _)
+            if let Expression::FunctionCall(function, ..) = &**value
+                && let Expression::Type(ty) = &**function
+                && let Type::Int(_) = **ty =>
+        {
+            Some(signed)
+        }
+        Expression::ShiftRight(value, _)
+            if let Expression::Cast(ty, _) = &**value
+                && let Type::Int(_) = **ty =>
+        {
+            Some(signed)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the array whose length is loaded by the expression, like `data` of `mload(data)`
+///
+/// `expression` the parsed Yul expression
+fn loaded_length(expression: &Expression) -> Option<&Expression> {
+    match expression {
+        Expression::FunctionCall(_, args, _) if let [Expression::Length(array)] = &args[..] => {
+            Some(array)
+        }
+        Expression::Cast(_, length) if let Expression::Length(array) = &**length => Some(array),
+        _ => None,
+    }
+}
+
+/// Returns the value of a string literal, also if it is converted to bytes, like `bytes("transfer()")`
+///
+/// `expression` the parsed expression
+fn string_value(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::StringLiteral(parts) => Some(parts.concat()),
+        Expression::FunctionCall(function, args, _)
+            if let (Expression::Type(ty), [argument]) = (&**function, &args[..]) =>
+        {
+            match **ty {
+                Type::DynamicBytes => string_value(argument),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the type of a function returning a single value
+///
+/// `returns` the types of the returned values
+fn single_type(returns: &[Type]) -> Option<Type> {
+    match returns {
+        [ty] => Some(ty.clone()),
+        _ => None,
+    }
+}
+
+/// Returns true if the expression is a number literal or an operation of number literals only
+///
+/// `expression` the parsed expression
+fn is_number_literal(expression: &Expression) -> bool {
+    match expression {
+        Expression::NumberLiteral(_) | Expression::HexLiteral(_) => true,
+        Expression::UnaryMinus(value) => is_number_literal(value),
+        _ => is_literal_operation(expression),
+    }
+}
+
+/// Returns true if the expression is an arithmetic operation of number literals only, like `10 ** 3`
+///
+/// `expression` the parsed expression
+fn is_literal_operation(expression: &Expression) -> bool {
+    let is_literal = |operand: &Expression| {
+        match operand {
+            Expression::NumberLiteral(_) | Expression::HexLiteral(_) => true,
+            Expression::FunctionCall(function, args, _)
+                if let (Expression::Type(_), [argument]) = (&**function, &args[..]) =>
+            {
+                matches!(
+                    argument,
+                    Expression::NumberLiteral(_) | Expression::HexLiteral(_)
+                )
+            }
+            _ => is_literal_operation(operand),
+        }
+    };
+    match expression {
+        Expression::Add(left, right)
+        | Expression::Subtract(left, right)
+        | Expression::Multiply(left, right)
+        | Expression::Divide(left, right)
+        | Expression::Modulo(left, right)
+        | Expression::Power(left, right)
+        | Expression::ShiftLeft(left, right) => is_literal(left) && is_literal(right),
+        Expression::Parenthesis(operation)
+        | Expression::Checked(operation)
+        | Expression::Wrapping(operation) => is_literal_operation(operation),
+        _ => false,
+    }
+}
+
+/// Returns true if the expression evaluates to `bool` in Rust
+///
+/// `expression` the parsed expression
+fn is_boolean_expression(expression: &Expression) -> bool {
+    match expression {
+        Expression::BoolLiteral(_)
+        | Expression::Less(..)
+        | Expression::LessEqual(..)
+        | Expression::More(..)
+        | Expression::MoreEqual(..)
+        | Expression::Equal(..)
+        | Expression::NotEqual(..)
+        | Expression::And(..)
+        | Expression::Or(..) => true,
+        Expression::Not(expression) | Expression::Parenthesis(expression) => {
+            is_boolean_expression(expression)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the name of a Solidity type as written in the source code, used to mangle overloads
+///
+/// `ty` the original Solang type expression
+fn solidity_type_name(ty: &SolangExpression) -> String {
+    match ty {
+        SolangExpression::Type(_, solidity_type) => {
+            match solidity_type {
+                SolangType::Address | SolangType::AddressPayable | SolangType::Payable => {
+                    String::from("address")
+                }
+                SolangType::Bool => String::from("bool"),
+                SolangType::String => String::from("string"),
+                SolangType::Int(bits) => format!("int{bits}"),
+                SolangType::Uint(bits) => format!("uint{bits}"),
+                SolangType::Bytes(length) => format!("bytes{length}"),
+                SolangType::DynamicBytes => String::from("bytes"),
+                SolangType::Rational => String::from("rational"),
+                SolangType::Mapping(..) => String::from("mapping"),
+                SolangType::Function { .. } => String::from("function"),
+            }
+        }
+        SolangExpression::Variable(identifier) => identifier.name.clone(),
+        SolangExpression::MemberAccess(_, _, identifier) => identifier.name.clone(),
+        SolangExpression::ArraySubscript(_, ty, _) => format!("{}_array", solidity_type_name(ty)),
+        _ => String::from("unknown"),
+    }
+}
+
+/// Selects the overload matching the provided arguments
+/// We look for the first overload with the same number of parameters whose parameter types
+/// match the known types of the arguments
+///
+/// `overload_set` the overloads of the called function
+/// `args` the parsed arguments of the call
+fn select_overload(
+    overload_set: &[FunctionOverload],
+    args: &[Expression],
+) -> Option<FunctionOverload> {
+    let same_length = overload_set
+        .iter()
+        .filter(|overload| overload.params.len() == args.len())
+        .collect::<Vec<_>>();
+    same_length
+        .iter()
+        .find(|overload| {
+            overload
+                .params
+                .iter()
+                .zip(args)
+                .all(|(param, arg)| argument_matches_type(arg, param))
+        })
+        .or(same_length.first())
+        .map(|overload| (*overload).clone())
+}
+
+/// Returns false if the type of the argument is known and it does not match the parameter type
+/// Arguments of unknown type match every parameter type
+///
+/// `arg` the parsed argument of a function call
+/// `param` the type of the function parameter
+fn argument_matches_type(arg: &Expression, param: &Type) -> bool {
+    match arg {
+        Expression::NumberLiteral(_) | Expression::Unit(..) => {
+            matches!(param, Type::Int(_) | Type::Uint(_))
+        }
+        Expression::BoolLiteral(_)
+        | Expression::Not(_)
+        | Expression::And(..)
+        | Expression::Or(..)
+        | Expression::Equal(..)
+        | Expression::NotEqual(..)
+        | Expression::Less(..)
+        | Expression::LessEqual(..)
+        | Expression::More(..)
+        | Expression::MoreEqual(..) => *param == Type::Bool,
+        Expression::StringLiteral(_) => {
+            matches!(param, Type::String | Type::DynamicBytes | Type::Bytes(_))
+        }
+        Expression::Variable(_, MemberType::Variable(ty) | MemberType::None(ty), _)
+            if **ty != Type::None =>
+        {
+            types_match(ty, param)
+        }
+        Expression::FunctionCall(function, ..) if let Expression::Type(ty) = &**function => {
+            types_match(ty, param)
+        }
+        Expression::MemberAccess(expression, member)
+            if let Expression::Variable(name, ..) = &**expression =>
+        {
+            !(name == "msg" && member == "sender") || *param == Type::AccountId
+        }
+        _ => true,
+    }
+}
+
+/// Returns true if two types are the same, ignoring the size of integers
+fn types_match(left: &Type, right: &Type) -> bool {
+    match (left, right) {
+        (Type::Int(_), Type::Int(_)) | (Type::Uint(_), Type::Uint(_)) => true,
+        // contracts are passed as addresses
+        (
+            Type::Contract(_) | Type::AccountId | Type::PayableAccountId,
+            Type::Contract(_) | Type::AccountId | Type::PayableAccountId,
+        ) => true,
+        _ => left == right,
+    }
+}
+
+fn function_call_in_expression(expresion: &Expression) -> bool {
+    match expresion {
+        Expression::Add(expr1, expr2)
+        | Expression::And(expr1, expr2)
+        | Expression::Assign(expr1, expr2)
+        | Expression::AssignAdd(expr1, expr2)
+        | Expression::AssignDivide(expr1, expr2)
+        | Expression::AssignModulo(expr1, expr2)
+        | Expression::AssignMultiply(expr1, expr2)
+        | Expression::AssignSubtract(expr1, expr2)
+        | Expression::Divide(expr1, expr2)
+        | Expression::Equal(expr1, expr2)
+        | Expression::Less(expr1, expr2)
+        | Expression::LessEqual(expr1, expr2)
+        | Expression::Modulo(expr1, expr2)
+        | Expression::More(expr1, expr2)
+        | Expression::MoreEqual(expr1, expr2)
+        | Expression::Multiply(expr1, expr2)
+        | Expression::NotEqual(expr1, expr2)
+        | Expression::Or(expr1, expr2)
+        | Expression::Subtract(expr1, expr2)
+        | Expression::ShiftLeft(expr1, expr2)
+        | Expression::ShiftRight(expr1, expr2)
+        | Expression::BitwiseAnd(expr1, expr2)
+        | Expression::BitwiseXor(expr1, expr2)
+        | Expression::BitwiseOr(expr1, expr2)
+        | Expression::AssignOr(expr1, expr2)
+        | Expression::AssignAnd(expr1, expr2)
+        | Expression::AssignXor(expr1, expr2)
+        | Expression::AssignShiftLeft(expr1, expr2)
+        | Expression::AssignShiftRight(expr1, expr2)
         | Expression::Power(expr1, expr2) => {
             function_call_in_expression(expr1) || function_call_in_expression(expr2)
         }
-        Expression::List(list) => {
-            list.iter()
-                .map(function_call_in_expression)
-                .any(|output| output)
+        Expression::List(list) => {
+            list.iter()
+                .map(function_call_in_expression)
+                .any(|output| output)
+        }
+        Expression::MappingSubscript(expr, list) => {
+            list.iter()
+                .map(function_call_in_expression)
+                .any(|output| output)
+                || function_call_in_expression(expr)
+        }
+        Expression::ArraySubscript(expr1, expr2) => {
+            function_call_in_expression(expr1)
+                || expr2
+                    .as_ref()
+                    .map_or(false, |expression| function_call_in_expression(expression))
+        }
+        Expression::MemberAccess(expr, _) => function_call_in_expression(expr),
+        Expression::New(expr)
+        | Expression::Not(expr)
+        | Expression::Parenthesis(expr)
+        | Expression::PostDecrement(expr)
+        | Expression::PostIncrement(expr)
+        | Expression::PreDecrement(expr)
+        | Expression::PreIncrement(expr)
+        | Expression::Wrapping(expr) => function_call_in_expression(expr),
+        Expression::Ternary(expr1, expr2, expr3) => {
+            function_call_in_expression(expr1)
+                || function_call_in_expression(expr2)
+                || function_call_in_expression(expr3)
+        }
+        Expression::NamedFunctionCall(..)
+        | Expression::FunctionCall(..)
+        | Expression::Checked(..)
+        | Expression::Balance(..)
+        | Expression::Truncate(..) => true,
+        Expression::Modifier(_, list) => {
+            list.iter()
+                .map(function_call_in_expression)
+                .any(|output| output)
+        }
+        _ => false,
+    }
+}
+
+#[macro_export]
+macro_rules! initialize_parser {
+    ($parser: ident) => {
+        let mut fields_map = HashMap::new();
+        let mut modifier_map = HashMap::new();
+        let mut errors_map = HashMap::new();
+        let mut overloads_map = HashMap::new();
+        let mut locals_map = HashMap::new();
+        let mut libraries_map = HashMap::new();
+        let mut value_types = HashSet::new();
+        let mut usings = Vec::new();
+        let mut symbols = SymbolTable::default();
+        let mut imports = HashSet::new();
+        let mut comments = RBTree::new();
+
+        let mut $parser = Parser::new(
+            &mut fields_map,
+            &mut modifier_map,
+            &mut errors_map,
+            &mut overloads_map,
+            &mut locals_map,
+            &mut libraries_map,
+            &mut value_types,
+            &mut usings,
+            &mut symbols,
+            &mut imports,
+            &mut comments,
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import_resolver::Remapping;
+    use std::str::FromStr;
+
+    macro_rules! function_header {
+        (
+            $name: expr,
+            $params: expr,
+            $external: expr,
+            $view: expr,
+            $payable: expr,
+            $return_params: expr,
+            $comments: expr,
+            $modifiers: expr,
+            $invalid_modifiers: expr
+        ) => {
+            FunctionHeader {
+                name: $name.to_string(),
+                params: $params,
+                external: $external,
+                view: $view,
+                payable: $payable,
+                return_params: $return_params,
+                comments: $comments,
+                modifiers: $modifiers,
+                invalid_modifiers: $invalid_modifiers,
+            }
+        };
+    }
+
+    macro_rules! contract_field {
+        ($field_type: expr, $name: expr, $initial_value: expr, $constant: expr, $public: expr) => {
+            ContractField {
+                field_type: $field_type,
+                name: $name.to_string(),
+                comments: Vec::default(),
+                initial_value: $initial_value,
+                constant: $constant,
+                public: $public,
+            }
+        };
+    }
+
+    macro_rules! local {
+        ($name: expr) => {
+            Expression::Variable(
+                String::from($name),
+                MemberType::None(Box::new(Type::None)),
+                VariableAccessLocation::Any,
+            )
+        };
+    }
+
+    #[test]
+    fn one_contract_definition() {
+        initialize_parser!(parser);
+        let output = parser.parse_file("Contract.sol", "contract Contract {}");
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        assert_eq!(output_ok.len(), 1);
+    }
+
+    #[test]
+    fn multiple_contract_definitions() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            "contract Contract {} interface Interface {} library Library {}",
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        assert_eq!(output_ok.len(), 3);
+    }
+
+    #[test]
+    fn no_contract_definitions() {
+        initialize_parser!(parser);
+        let output = parser.parse_file("Contract.sol", "/// This is an empty Solidity file");
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        assert_eq!(output_ok.len(), 0);
+    }
+
+    #[test]
+    fn corrupted_file() {
+        initialize_parser!(parser);
+        let output = parser.parse_file("Contract.sol", "contract { uint abc = 123; }");
+        assert_eq!(
+            output.err().unwrap(),
+            ParserError::FileCorrupted(vec![String::from(
+                r#"unrecognised token '{', expected "case", "default", "error", "leave", "revert", "switch", identifier"#
+            )])
+        );
+    }
+
+    #[test]
+    fn contract_with_function() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract A { 
+                function fun_1() external {}
+                function fun_2() external view {}
+                function fun_3() external payable {}
+                function fun_4() internal {}
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+
+        let contract_a = &output_ok[0];
+
+        if let ParserOutput::Contract(_, contract) = contract_a {
+            assert!(contract.base.is_empty());
+            assert_eq!(contract.functions.len(), 4);
+            assert_eq!(
+                contract.functions[0].header,
+                function_header!(
+                    "fun_1",
+                    Vec::default(),
+                    true,
+                    false,
+                    false,
+                    Vec::default(),
+                    Vec::default(),
+                    Vec::default(),
+                    Vec::default()
+                )
+            );
+            assert_eq!(
+                contract.functions[1].header,
+                function_header!(
+                    "fun_2",
+                    Vec::default(),
+                    true,
+                    true,
+                    false,
+                    Vec::default(),
+                    Vec::default(),
+                    Vec::default(),
+                    Vec::default()
+                )
+            );
+            assert_eq!(
+                contract.functions[2].header,
+                function_header!(
+                    "fun_3",
+                    Vec::default(),
+                    true,
+                    false,
+                    true,
+                    Vec::default(),
+                    Vec::default(),
+                    Vec::default(),
+                    Vec::default()
+                )
+            );
+            assert_eq!(
+                contract.functions[3].header,
+                function_header!(
+                    "fun_4",
+                    Vec::default(),
+                    false,
+                    false,
+                    false,
+                    Vec::default(),
+                    Vec::default(),
+                    Vec::default(),
+                    Vec::default()
+                )
+            );
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn contract_with_base() {
+        initialize_parser!(parser);
+        let output = parser.parse_file("Contract.sol", r#"contract A is B, C, D { }"#);
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+
+        let contract_a = &output_ok[0];
+
+        if let ParserOutput::Contract(_, contract) = contract_a {
+            assert_eq!(contract.base.len(), 3);
+            assert_eq!(contract.base[0], "B");
+            assert_eq!(contract.base[1], "C");
+            assert_eq!(contract.base[2], "D");
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn arithmetic_operations_work() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+                pragma solidity ^0.7.0;
+
+                contract A { 
+                    function do_math(uint b, uint c) internal returns (uint) { 
+                        uint result = b + c; 
+                        result = b - c; 
+                        result += b * c; 
+                        result -= b / c; 
+                        result *= b % c; 
+                        result /= result; 
+                        return result;
+                    } 
+                }
+                "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+
+        let contract_a = &output_ok[0];
+
+        if let ParserOutput::Contract(_, contract) = contract_a {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::VariableDefinition(
+                        Expression::VariableDeclaration(
+                            Box::new(Type::Uint(256)),
+                            String::from("result"),
+                            None
+                        ),
+                        Some(Expression::Add(
+                            Box::new(Expression::Variable(
+                                String::from("b"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                            Box::new(Expression::Variable(
+                                String::from("c"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                        ))
+                    )
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(Expression::Variable(
+                            String::from("result"),
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any
+                        )),
+                        Box::new(Expression::Subtract(
+                            Box::new(Expression::Variable(
+                                String::from("b"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                            Box::new(Expression::Variable(
+                                String::from("c"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                        ))
+                    ))
+                );
+                assert_eq!(
+                    body[2],
+                    Statement::Expression(Expression::AssignAdd(
+                        Box::new(Expression::Variable(
+                            String::from("result"),
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any
+                        )),
+                        Box::new(Expression::Multiply(
+                            Box::new(Expression::Variable(
+                                String::from("b"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                            Box::new(Expression::Variable(
+                                String::from("c"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                        ))
+                    ))
+                );
+                assert_eq!(
+                    body[3],
+                    Statement::Expression(Expression::AssignSubtract(
+                        Box::new(Expression::Variable(
+                            String::from("result"),
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any
+                        )),
+                        Box::new(Expression::Divide(
+                            Box::new(Expression::Variable(
+                                String::from("b"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                            Box::new(Expression::Variable(
+                                String::from("c"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                        ))
+                    ))
+                );
+                assert_eq!(
+                    body[4],
+                    Statement::Expression(Expression::AssignMultiply(
+                        Box::new(Expression::Variable(
+                            String::from("result"),
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any
+                        )),
+                        Box::new(Expression::Modulo(
+                            Box::new(Expression::Variable(
+                                String::from("b"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                            Box::new(Expression::Variable(
+                                String::from("c"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                        ))
+                    ))
+                );
+                assert_eq!(
+                    body[5],
+                    Statement::Expression(Expression::AssignDivide(
+                        Box::new(Expression::Variable(
+                            String::from("result"),
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any
+                        )),
+                        Box::new(Expression::Variable(
+                            String::from("result"),
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any
+                        )),
+                    ))
+                );
+                assert_eq!(
+                    body[6],
+                    Statement::Return(Some(Expression::Variable(
+                        String::from("result"),
+                        MemberType::None(Box::new(Type::None)),
+                        VariableAccessLocation::Any
+                    )))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn contract_with_attributes() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                string public name;
+                uint public age;
+                address payable owner;
+                uint constant FEE = 1 ether;
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        assert_eq!(output_ok.len(), 1);
+
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert_eq!(
+                contract.fields[0],
+                contract_field!(Type::String, "name", None, false, true)
+            );
+            assert_eq!(
+                contract.fields[1],
+                contract_field!(Type::Uint(256), "age", None, false, true)
+            );
+            assert_eq!(
+                contract.fields[2],
+                contract_field!(Type::PayableAccountId, "owner", None, false, false)
+            );
+            assert_eq!(
+                contract.fields[3],
+                contract_field!(
+                    Type::Uint(256),
+                    "FEE",
+                    Some(Expression::Unit(
+                        Box::new(Expression::NumberLiteral(String::from("1"))),
+                        1000000000000000000
+                    )),
+                    true,
+                    false
+                )
+            );
+        } else {
+            unreachable!("Only contract allowed here!")
+        }
+    }
+
+    #[test]
+    fn contract_with_errors() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                error Unauthorized();
+                error InsufficientBalance(uint256 available, uint256 required);
+
+                function withdraw(uint amount) external {
+                    revert InsufficientBalance(0, amount);
+                    revert InsufficientBalance({required: amount, available: 0});
+                    revert Unauthorized();
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert_eq!(contract.errors.len(), 2);
+            assert_eq!(contract.errors[0].name, "Unauthorized");
+            assert!(contract.errors[0].fields.is_empty());
+            assert_eq!(contract.errors[1].name, "InsufficientBalance");
+            assert_eq!(contract.errors[1].fields[0].name, "available");
+            assert_eq!(contract.errors[1].fields[1].field_type, Type::Uint(256));
+
+            let amount = Expression::Variable(
+                String::from("amount"),
+                MemberType::None(Box::new(Type::None)),
+                VariableAccessLocation::Any,
+            );
+            // the arguments are converted to the types of the fields
+            let zero = Expression::FunctionCall(
+                Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                vec![Expression::NumberLiteral(String::from("0"))],
+                None,
+            );
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::RevertNamedArgs(
+                        String::from("InsufficientBalance"),
+                        vec![
+                            (String::from("available"), zero.clone()),
+                            (String::from("required"), amount.clone())
+                        ]
+                    )
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::RevertNamedArgs(
+                        String::from("InsufficientBalance"),
+                        vec![
+                            (String::from("required"), amount),
+                            (String::from("available"), zero)
+                        ]
+                    )
+                );
+                assert_eq!(
+                    body[2],
+                    Statement::Revert(String::from("Unauthorized"), Vec::default())
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn contract_with_overloaded_functions() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                bool flag;
+
+                function transfer(address to) external {}
+                function transfer(address to, uint256 amount) external {}
+                function set(uint256 value) internal {}
+                function set(bool value) internal {}
+                function single() external {
+                    transfer(msg.sender, 1);
+                    set(flag);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            let names = contract
+                .functions
+                .iter()
+                .map(|function| function.header.name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                names,
+                vec![
+                    "transfer_address",
+                    "transfer_address_uint256",
+                    "set_uint256",
+                    "set_bool",
+                    "single"
+                ]
+            );
+
+            if let Some(Statement::Block(body)) = &contract.functions[4].body {
+                match &body[0] {
+                    Statement::Expression(Expression::FunctionCall(function, ..)) => {
+                        assert_eq!(
+                            **function,
+                            Expression::Variable(
+                                String::from("transfer_address_uint256"),
+                                MemberType::Function,
+                                VariableAccessLocation::Any
+                            )
+                        )
+                    }
+                    _ => unreachable!("Function call expected here"),
+                }
+                match &body[1] {
+                    Statement::Expression(Expression::FunctionCall(function, ..)) => {
+                        assert_eq!(
+                            **function,
+                            Expression::Variable(
+                                String::from("set_bool"),
+                                MemberType::FunctionPrivate,
+                                VariableAccessLocation::Any
+                            )
+                        )
+                    }
+                    _ => unreachable!("Function call expected here"),
+                }
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn external_overloaded_calls() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            interface INft {
+                function safeTransferFrom(address from, address to, uint256 id) external;
+                function safeTransferFrom(address from, address to, uint256 id, bytes calldata data) external;
+            }
+
+            contract Contract {
+                function send(INft nft, address to) external {
+                    nft.safeTransferFrom(address(this), to, 1);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[1] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                match &body[0] {
+                    Statement::Expression(Expression::ContractCall(contract, _, function, ..)) => {
+                        assert_eq!(contract, "INft");
+                        assert_eq!(function, "safeTransferFrom_address_address_uint256");
+                    }
+                    _ => unreachable!("Contract call expected here"),
+                }
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn try_catch_clauses() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                uint256 reason;
+
+                function call(Callee callee) external {
+                    try callee.value() returns (uint v) {
+                    } catch Error(string memory reason) {
+                    } catch (bytes memory data) {
+                        data.length;
+                    }
+                    reason;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                if let Statement::Try(_, returns, _, catch_clauses) = &body[0] {
+                    assert_eq!(
+                        returns,
+                        &vec![FunctionParam {
+                            name: String::from("v"),
+                            param_type: Type::Uint(256),
+                            location: None
+                        }]
+                    );
+                    assert_eq!(
+                        catch_clauses,
+                        &vec![
+                            CatchClause::Named(
+                                String::from("Error"),
+                                FunctionParam {
+                                    name: String::from("reason"),
+                                    param_type: Type::String,
+                                    location: Some(DataLocation::Memory)
+                                },
+                                Statement::Block(Vec::default())
+                            ),
+                            CatchClause::Simple(
+                                Some(FunctionParam {
+                                    name: String::from("data"),
+                                    param_type: Type::DynamicBytes,
+                                    location: Some(DataLocation::Memory)
+                                }),
+                                Statement::Block(vec![Statement::Expression(
+                                    Expression::FunctionCall(
+                                        Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                                        vec![Expression::Length(Box::new(local!("data")))],
+                                        None
+                                    )
+                                )])
+                            )
+                        ]
+                    );
+                } else {
+                    unreachable!("Try statement expected here")
+                }
+                // the caught errors are not visible after the clauses
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::Variable(
+                        String::from("reason"),
+                        MemberType::Variable(Box::new(Type::Uint(256))),
+                        VariableAccessLocation::Any
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn assembly_block() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                function id(uint a) external returns (uint result) {
+                    assembly {
+                        let x := add(a, 0x20)
+                        result := chainid()
+                        sstore(0, x)
+                    }
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Assembly(vec![
+                        Statement::VariableDefinition(
+                            Expression::VariableDeclaration(
+                                Box::new(Type::Uint(256)),
+                                String::from("x"),
+                                None
+                            ),
+                            Some(Expression::Wrapping(Box::new(Expression::Add(
+                                Box::new(local!("a")),
+                                Box::new(Expression::FunctionCall(
+                                    Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                                    vec![Expression::HexLiteral(String::from("0x20"))],
+                                    None
+                                ))
+                            ))))
+                        ),
+                        Statement::Expression(Expression::Assign(
+                            Box::new(local!("result")),
+                            Box::new(Expression::Substitute(
+                                String::from(
+                                    "block.chainid is not available in ink!, set the chain id with --chain-id"
+                                ),
+                                Box::new(Type::Uint(256)),
+                                None
+                            ))
+                        )),
+                        Statement::Expression(Expression::Untranslatable(String::from(
+                            "sstore(0, x)"
+                        )))
+                    ])
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn assembly_signed_operations() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                function signed(uint a, uint b, bytes memory data) external returns (uint result, int quotient) {
+                    assembly {
+                        result := sdiv(a, b)
+                        result := slt(a, b)
+                        result := mload(data)
+                        quotient := smod(a, 2)
+                    }
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert!(contract.imports.contains(&Import::I256));
+            let word = |expression: Expression| {
+                Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                    vec![expression],
+                    None,
+                )
+            };
+            let signed = |expression: Expression| {
+                Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(Type::Int(256)))),
+                    vec![expression],
+                    None,
+                )
+            };
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Assembly(vec![
+                        Statement::Expression(Expression::Assign(
+                            Box::new(local!("result")),
+                            Box::new(word(Expression::AssemblyCall(
+                                String::from("sdiv"),
+                                vec![signed(local!("a")), signed(local!("b"))],
+                                VariableAccessLocation::Any
+                            )))
+                        )),
+                        Statement::Expression(Expression::Assign(
+                            Box::new(local!("result")),
+                            Box::new(word(Expression::Cast(
+                                Box::new(Type::Uint(8)),
+                                Box::new(Expression::Less(
+                                    Box::new(signed(local!("a"))),
+                                    Box::new(signed(local!("b")))
+                                ))
+                            )))
+                        )),
+                        Statement::Expression(Expression::Assign(
+                            Box::new(local!("result")),
+                            Box::new(word(Expression::Length(Box::new(local!("data")))))
+                        )),
+                        Statement::Expression(Expression::Assign(
+                            Box::new(local!("quotient")),
+                            Box::new(Expression::AssemblyCall(
+                                String::from("smod"),
+                                vec![
+                                    signed(local!("a")),
+                                    signed(word(Expression::NumberLiteral(String::from("2"))))
+                                ],
+                                VariableAccessLocation::Any
+                            ))
+                        )),
+                    ])
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn contract_with_using_directive() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            library SafeMath {
+                function add(uint a, uint b) internal pure returns (uint) {
+                    return a + b;
+                }
+            }
+
+            contract Contract {
+                using SafeMath for uint;
+
+                function sum(uint a, uint b, address c) external returns (uint) {
+                    c.call("");
+                    return a.add(b);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+
+        if let ParserOutput::Contract(_, contract) = &output_ok[1] {
+            assert!(contract.imports.contains(&Import::Libs));
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::FunctionCall(
+                        Box::new(Expression::MemberAccess(
+                            Box::new(local!("c")),
+                            String::from("call")
+                        )),
+                        vec![Expression::StringLiteral(vec![String::new()])],
+                        None
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Return(Some(Expression::LibraryCall(
+                        String::from("SafeMath"),
+                        String::from("add"),
+                        vec![local!("a"), local!("b")]
+                    )))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn contract_with_function_types() {
+        initialize_parser!(parser);
+        let output = parser.parse_file("Contract.sol", 
+            r#"
+            contract Contract {
+                function(uint) external returns (bool) hook;
+
+                function apply(uint x, function(uint, uint) internal pure returns (uint) f) external {
+                    hook.selector;
+                    f(x, x);
+                }
+
+                function run(uint x) external {
+                    apply(x, _add);
+                }
+
+                function _add(uint a, uint b) internal pure returns (uint) {
+                    return a + b;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert_eq!(
+                contract.fields[0].field_type,
+                Type::ExternalFunction(vec![Type::Uint(256)], vec![Type::Bool])
+            );
+            assert_eq!(
+                contract.functions[0].header.params[1].param_type,
+                Type::Function(
+                    vec![Type::Uint(256), Type::Uint(256)],
+                    vec![Type::Uint(256)],
+                    true
+                )
+            );
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::MemberAccess(
+                        Box::new(Expression::Variable(
+                            String::from("hook"),
+                            MemberType::Variable(Box::new(Type::ExternalFunction(
+                                vec![Type::Uint(256)],
+                                vec![Type::Bool]
+                            ))),
+                            VariableAccessLocation::Any
+                        )),
+                        String::from("1")
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::FunctionPointerCall(
+                        Box::new(local!("f")),
+                        vec![local!("x"), local!("x")],
+                        VariableAccessLocation::Any
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+            if let Some(Statement::Block(body)) = &contract.functions[1].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::FunctionCall(
+                        Box::new(Expression::Variable(
+                            String::from("apply"),
+                            MemberType::Function,
+                            VariableAccessLocation::Any
+                        )),
+                        vec![
+                            local!("x"),
+                            Expression::FunctionPointer(
+                                Box::new(Expression::Variable(
+                                    String::from("_add"),
+                                    MemberType::FunctionPrivate,
+                                    VariableAccessLocation::Any
+                                )),
+                                Some(2)
+                            )
+                        ],
+                        None
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn function_type_values() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            function double(uint256 a) pure returns (uint256) {
+                return a * 2;
+            }
+
+            contract Contract {
+                function(uint256) external returns (bool) hook;
+
+                function apply(uint256 x, function(uint256) internal pure returns (uint256) f) internal pure returns (uint256) {
+                    return f(x);
+                }
+
+                function run(uint256 x) external {
+                    hook(5);
+                    apply(x, double);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[1].body {
+                // arguments of external function types are converted to the declared types
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::ExternalFunctionCall(
+                        Box::new(Expression::Variable(
+                            String::from("hook"),
+                            MemberType::Variable(Box::new(Type::ExternalFunction(
+                                vec![Type::Uint(256)],
+                                vec![Type::Bool]
+                            ))),
+                            VariableAccessLocation::Any
+                        )),
+                        vec![Expression::FunctionCall(
+                            Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                            vec![Expression::NumberLiteral(String::from("5"))],
+                            None
+                        )],
+                        vec![Type::Bool]
+                    ))
+                );
+                // free functions are wrapped like the functions of the contract
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::FunctionCall(
+                        Box::new(Expression::Variable(
+                            String::from("apply"),
+                            MemberType::FunctionPrivate,
+                            VariableAccessLocation::Any
+                        )),
+                        vec![
+                            local!("x"),
+                            Expression::FunctionPointer(Box::new(local!("double")), Some(1))
+                        ],
+                        None
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+
+        // internal function pointers can not be stored
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                function(uint256) internal returns (uint256) handler;
+            }
+            "#,
+        );
+        assert_eq!(
+            output.err(),
+            Some(ParserError::FunctionTypedField(String::from(
+                "Contract.handler"
+            )))
+        );
+    }
+
+    #[test]
+    fn user_defined_value_types() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            type Price is uint128;
+
+            using {add as +, lt as <} for Price global;
+
+            contract Contract {
+                type Amount is uint256;
+
+                function unwrapped(Amount amount) external returns (uint256) {
+                    return Amount.unwrap(Amount.wrap(5));
+                }
+
+                function sum(Price a, Price b) external returns (Price) {
+                    return a + b;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        assert_eq!(output_ok.len(), 2);
+
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert_eq!(contract.value_types[0].name, "Amount");
+            assert_eq!(contract.value_types[0].ty, Type::Uint(256));
+            assert!(contract
+                .imports
+                .contains(&Import::Globals(String::from("contract_globals"))));
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Return(Some(Expression::MemberAccess(
+                        Box::new(Expression::FunctionCall(
+                            Box::new(Expression::Type(Box::new(Type::Variable(String::from(
+                                "Amount"
+                            ))))),
+                            vec![Expression::NumberLiteral(String::from("5"))],
+                            None
+                        )),
+                        String::from("0")
+                    )))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+            // the operator implementations of the newtype are used instead of the checked operations
+            if let Some(Statement::Block(body)) = &contract.functions[1].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Return(Some(Expression::Add(
+                        Box::new(local!("a")),
+                        Box::new(local!("b"))
+                    )))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+
+        if let ParserOutput::Globals(_, globals) = &output_ok[1] {
+            let price = &globals.value_types[0];
+            assert_eq!(price.name, "Price");
+            assert_eq!(
+                price
+                    .operators
+                    .iter()
+                    .map(|binding| (binding.operator.as_str(), binding.function.as_str()))
+                    .collect::<Vec<_>>(),
+                vec![("+", "add"), ("<", "lt")]
+            );
+        } else {
+            unreachable!("Globals expected here")
+        }
+    }
+
+    #[test]
+    fn file_level_definitions() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Math.sol",
+            r#"
+            uint256 constant WAD = 1e18;
+
+            struct Position {
+                uint256 size;
+            }
+
+            function mulWad(uint256 x, uint256 y) pure returns (uint256) {
+                return x * y / WAD;
+            }
+
+            contract Contract {
+                function scaled(uint256 amount) external returns (uint256) {
+                    return mulWad(amount, WAD);
+                }
+            }
+
+            error Overflow();
+            error Custom(uint256 code);
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        assert_eq!(output_ok.len(), 2);
+
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert!(contract
+                .imports
+                .contains(&Import::Globals(String::from("math_globals"))));
+            assert_eq!(contract.errors[0].name, "Overflow");
+            assert_eq!(contract.errors[1].name, "CustomError");
+            assert_eq!(contract.globals_errors.len(), 2);
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Return(Some(Expression::FunctionCall(
+                        Box::new(Expression::Variable(
+                            String::from("mulWad"),
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any
+                        )),
+                        vec![
+                            Expression::Variable(
+                                String::from("amount"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            ),
+                            Expression::Variable(
+                                String::from("WAD"),
+                                MemberType::Constant,
+                                VariableAccessLocation::Any
+                            )
+                        ],
+                        None
+                    )))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+
+        if let ParserOutput::Globals(name, globals) = &output_ok[1] {
+            assert_eq!(name, "math_globals");
+            assert_eq!(globals.fields[0].name, "WAD");
+            assert!(globals.fields[0].constant);
+            assert_eq!(globals.structs[0].name, "Position");
+            assert_eq!(globals.functions[0].header.name, "mulWad");
+            assert_eq!(globals.errors[0].name, "Overflow");
+        } else {
+            unreachable!("Globals expected here")
+        }
+    }
+
+    #[test]
+    fn imported_symbols() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "lib/Base.sol",
+            r#"
+            uint256 constant MAX = 100;
+
+            contract Base {
+                uint256 total;
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+        parser.clear();
+
+        let output = parser.parse_file(
+            "Token.sol",
+            r#"
+            import {Base as Parent, MAX} from "./lib/Base.sol";
+
+            contract Token is Parent {
+                function mint(uint256 amount) external {
+                    total = MAX;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert_eq!(contract.base, vec![String::from("Base")]);
+            assert!(contract
+                .imports
+                .contains(&Import::ImportedGlobals(String::from("base_globals"))));
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(Expression::Variable(
+                            String::from("total"),
+                            MemberType::Variable(Box::new(Type::Uint(256))),
+                            VariableAccessLocation::Any
+                        )),
+                        Box::new(Expression::Variable(
+                            String::from("MAX"),
+                            MemberType::Constant,
+                            VariableAccessLocation::Any
+                        ))
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn remapped_imports() {
+        initialize_parser!(parser);
+        parser.set_import_resolver(ImportResolver::new(
+            "",
+            &[Remapping::from_str("@oz/=lib/oz/").unwrap()],
+            &[],
+        ));
+        let output = parser.parse_file(
+            "lib/oz/Base.sol",
+            r#"
+            contract Base {
+                uint256 total;
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+        parser.clear();
+
+        let output = parser.parse_file(
+            "Token.sol",
+            r#"
+            import "@oz/Base.sol";
+
+            contract Token is Base {
+                function mint(uint256 amount) external {
+                    total = amount;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(Expression::Variable(
+                            String::from("total"),
+                            MemberType::Variable(Box::new(Type::Uint(256))),
+                            VariableAccessLocation::Any
+                        )),
+                        Box::new(Expression::Variable(
+                            String::from("amount"),
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any
+                        ))
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
         }
-        Expression::MappingSubscript(expr, list) => {
-            list.iter()
-                .map(function_call_in_expression)
-                .any(|output| output)
-                || function_call_in_expression(expr)
+    }
+
+    #[test]
+    fn wide_integers() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                uint256 total;
+
+                function add(uint256 amount) external {
+                    total = amount + 1;
+                    uint64 small = uint64(total);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert_eq!(contract.fields[0].field_type, Type::Uint(256));
+            assert!(contract.imports.contains(&Import::U256));
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                let total = Expression::Variable(
+                    String::from("total"),
+                    MemberType::Variable(Box::new(Type::Uint(256))),
+                    VariableAccessLocation::Any,
+                );
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(total.clone()),
+                        Box::new(Expression::Checked(Box::new(Expression::Add(
+                            Box::new(Expression::Variable(
+                                String::from("amount"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )),
+                            Box::new(Expression::FunctionCall(
+                                Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                                vec![Expression::NumberLiteral(String::from("1"))],
+                                None
+                            ))
+                        ))))
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::VariableDefinition(
+                        Expression::VariableDeclaration(
+                            Box::new(Type::Uint(64)),
+                            String::from("small"),
+                            None
+                        ),
+                        Some(Expression::Truncate(
+                            Box::new(Type::Uint(64)),
+                            Box::new(total)
+                        ))
+                    )
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+
+        parser.clear();
+        parser.set_narrow_integers(true);
+        let output = parser.parse_file("Contract.sol", "contract Contract { uint256 total; }");
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert_eq!(contract.fields[0].field_type, Type::Uint(128));
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn wide_integer_literals() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                uint256 constant MINIMUM = 10 ** 3;
+                int256 delta;
+
+                function update() external returns (bool, uint256) {
+                    delta = -1;
+                    return (false, block.timestamp);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            let wide = |ty: Type, value: Expression| {
+                Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(ty))),
+                    vec![value],
+                    None,
+                )
+            };
+            assert_eq!(
+                contract.fields[0].initial_value,
+                Some(wide(
+                    Type::Uint(256),
+                    Expression::NumberLiteral(String::from("1000"))
+                ))
+            );
+            assert_eq!(contract.fields[1].field_type, Type::Int(256));
+            assert!(contract.imports.contains(&Import::I256));
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(Expression::Variable(
+                            String::from("delta"),
+                            MemberType::Variable(Box::new(Type::Int(256))),
+                            VariableAccessLocation::Any
+                        )),
+                        Box::new(Expression::UnaryMinus(Box::new(wide(
+                            Type::Int(256),
+                            Expression::NumberLiteral(String::from("1"))
+                        ))))
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Return(Some(Expression::List(vec![
+                        Expression::BoolLiteral(false),
+                        wide(
+                            Type::Uint(256),
+                            Expression::MemberAccess(
+                                Box::new(local!("block")),
+                                String::from("timestamp")
+                            )
+                        )
+                    ])))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn widened_integers() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            library Math {
+                function sub(uint256 a, uint256 b) internal pure returns (uint256) {
+                    return a - b;
+                }
+            }
+
+            contract Contract {
+                error Bad(uint256 value);
+                uint112 reserve;
+                uint256 total;
+
+                function small() internal pure returns (uint32) {
+                    return 3;
+                }
+
+                function update() external {
+                    total = reserve;
+                    total = small() + 1;
+                    total = Math.sub(total, 1);
+                    revert Bad(2);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[1] {
+            let wide = |value: Expression| {
+                Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                    vec![value],
+                    None,
+                )
+            };
+            let number = |value: &str| Expression::NumberLiteral(String::from(value));
+            let total = Expression::Variable(
+                String::from("total"),
+                MemberType::Variable(Box::new(Type::Uint(256))),
+                VariableAccessLocation::Any,
+            );
+            let small = Expression::FunctionCall(
+                Box::new(Expression::Variable(
+                    String::from("small"),
+                    MemberType::FunctionPrivate,
+                    VariableAccessLocation::Any,
+                )),
+                Vec::default(),
+                None,
+            );
+            if let Some(Statement::Block(body)) = &contract.functions[1].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(total.clone()),
+                        Box::new(wide(Expression::Variable(
+                            String::from("reserve"),
+                            MemberType::Variable(Box::new(Type::Uint(128))),
+                            VariableAccessLocation::Any
+                        )))
+                    ))
+                );
+                // the sum is computed with the type returned by the function
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(total.clone()),
+                        Box::new(wide(Expression::Checked(Box::new(Expression::Add(
+                            Box::new(small),
+                            Box::new(number("1"))
+                        )))))
+                    ))
+                );
+                assert_eq!(
+                    body[2],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(total.clone()),
+                        Box::new(Expression::LibraryCall(
+                            String::from("Math"),
+                            String::from("sub"),
+                            vec![total, wide(number("1"))]
+                        ))
+                    ))
+                );
+                assert_eq!(
+                    body[3],
+                    Statement::RevertNamedArgs(
+                        String::from("Bad"),
+                        vec![(String::from("value"), wide(number("2")))]
+                    )
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            pragma solidity ^0.8.0;
+
+            contract Contract {
+                function count(uint8 a) internal returns (uint8) {
+                    a += 1;
+                    unchecked {
+                        a = a * 2;
+                    }
+                    return a;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                let a = || {
+                    Box::new(Expression::Variable(
+                        String::from("a"),
+                        MemberType::None(Box::new(Type::None)),
+                        VariableAccessLocation::Any,
+                    ))
+                };
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Assign(
+                        a(),
+                        Box::new(Expression::Checked(Box::new(Expression::Add(
+                            a(),
+                            Box::new(Expression::NumberLiteral(String::from("1")))
+                        ))))
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::UncheckedBlock(vec![Statement::Expression(Expression::Assign(
+                        a(),
+                        Box::new(Expression::Wrapping(Box::new(Expression::Multiply(
+                            a(),
+                            Box::new(Expression::NumberLiteral(String::from("2")))
+                        ))))
+                    ))])
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn fixed_size_arrays() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                uint8 constant SIZE = 4;
+                uint8[SIZE * 2] values;
+
+                function size() external view returns (uint256) {
+                    return values.length;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert_eq!(
+                contract.fields[1].field_type,
+                Type::Array(
+                    Box::new(Type::Uint(8)),
+                    Some(Expression::NumberLiteral(String::from("8")))
+                )
+            );
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Return(Some(Expression::FunctionCall(
+                        Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                        vec![Expression::NumberLiteral(String::from("8"))],
+                        None
+                    )))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
         }
-        Expression::ArraySubscript(expr1, expr2) => {
-            function_call_in_expression(expr1)
-                || expr2
-                    .as_ref()
-                    .map_or(false, |expression| function_call_in_expression(expression))
+    }
+
+    #[test]
+    fn nested_subscripts() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                uint8[3][2] grid;
+                mapping(address => mapping(address => uint8)) allowances;
+
+                function update(address owner, address spender) external {
+                    grid[1][2] = 5;
+                    allowances[owner][spender] = 4;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                let size = |size: &str| Some(Expression::NumberLiteral(String::from(size)));
+                let grid = Box::new(Expression::Variable(
+                    String::from("grid"),
+                    MemberType::Variable(Box::new(Type::Array(
+                        Box::new(Type::Array(Box::new(Type::Uint(8)), size("3"))),
+                        size("2"),
+                    ))),
+                    VariableAccessLocation::Any,
+                ));
+                // arrays are indexed per dimension
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(Expression::ArraySubscript(
+                            Box::new(Expression::ArraySubscript(grid, size("1").map(Box::new))),
+                            size("2").map(Box::new)
+                        )),
+                        Box::new(Expression::NumberLiteral(String::from("5")))
+                    ))
+                );
+                // nested mappings are a single mapping keyed by a tuple
+                let allowances = Box::new(Expression::Variable(
+                    String::from("allowances"),
+                    MemberType::Variable(Box::new(Type::Mapping(
+                        vec![Type::AccountId, Type::AccountId],
+                        Box::new(Type::Uint(8)),
+                    ))),
+                    VariableAccessLocation::Any,
+                ));
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::Assign(
+                        Box::new(Expression::MappingSubscript(
+                            allowances,
+                            vec![local!("owner"), local!("spender")]
+                        )),
+                        Box::new(Expression::NumberLiteral(String::from("4")))
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
         }
-        Expression::MemberAccess(expr, _) => function_call_in_expression(expr),
-        Expression::New(expr)
-        | Expression::Not(expr)
-        | Expression::Parenthesis(expr)
-        | Expression::PostDecrement(expr)
-        | Expression::PostIncrement(expr)
-        | Expression::PreDecrement(expr)
-        | Expression::PreIncrement(expr) => function_call_in_expression(expr),
-        Expression::Ternary(expr1, expr2, expr3) => {
-            function_call_in_expression(expr1)
-                || function_call_in_expression(expr2)
-                || function_call_in_expression(expr3)
+    }
+
+    #[test]
+    fn storage_arrays() {
+        initialize_parser!(parser);
+        parser.set_lazy_arrays(&[String::from("Contract.orders")]);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                uint8[] values;
+                uint8[] orders;
+
+                function update() external returns (uint256) {
+                    values.push(1);
+                    orders.pop();
+                    return values.length;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            let values_type = Type::Array(Box::new(Type::Uint(8)), None);
+            let orders_type = Type::LazyArray(Box::new(Type::Uint(256)), Box::new(Type::Uint(8)));
+            assert_eq!(contract.fields[0].field_type, values_type);
+            assert_eq!(contract.fields[1].field_type, orders_type);
+            assert_eq!(contract.fields[2].name, "orders_length");
+            assert_eq!(contract.fields[2].field_type, Type::Uint(256));
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                let values = Box::new(Expression::Variable(
+                    String::from("values"),
+                    MemberType::Variable(Box::new(values_type)),
+                    VariableAccessLocation::Any,
+                ));
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Push(
+                        values.clone(),
+                        Some(Box::new(Expression::NumberLiteral(String::from("1"))))
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::Pop(Box::new(Expression::Variable(
+                        String::from("orders"),
+                        MemberType::Variable(Box::new(orders_type)),
+                        VariableAccessLocation::Any,
+                    ))))
+                );
+                assert_eq!(
+                    body[2],
+                    Statement::Return(Some(Expression::FunctionCall(
+                        Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                        vec![Expression::Length(values)],
+                        None
+                    )))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
         }
-        Expression::NamedFunctionCall(..) | Expression::FunctionCall(..) => true,
-        Expression::Modifier(_, list) => {
-            list.iter()
-                .map(function_call_in_expression)
-                .any(|output| output)
+    }
+
+    #[test]
+    fn storage_arguments() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                struct Position {
+                    uint256 amount;
+                }
+
+                mapping(address => Position) positions;
+                uint256[] values;
+
+                function _update(Position storage position) internal {
+                    position.amount = 1;
+                }
+
+                function _sum(uint256[] memory array) internal pure returns (uint256) {
+                    return array.length;
+                }
+
+                function update(address user) external returns (uint256) {
+                    _update(positions[user]);
+                    return _sum(values);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[2].body {
+                let entry = Expression::MappingSubscript(
+                    Box::new(Expression::Variable(
+                        String::from("positions"),
+                        MemberType::Variable(Box::new(Type::Mapping(
+                            vec![Type::AccountId],
+                            Box::new(Type::Variable(String::from("Position"))),
+                        ))),
+                        VariableAccessLocation::Any,
+                    )),
+                    vec![local!("user")],
+                );
+                // the stored value is copied to a local which is borrowed and written back
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::WriteBack(
+                        vec![Statement::VariableDefinition(
+                            Expression::VariableDeclaration(
+                                Box::new(Type::Variable(String::from("Position"))),
+                                String::from("positions_entry"),
+                                None
+                            ),
+                            Some(entry.clone())
+                        )],
+                        Box::new(Expression::FunctionCall(
+                            Box::new(Expression::Variable(
+                                String::from("_update"),
+                                MemberType::FunctionPrivate,
+                                VariableAccessLocation::Any,
+                            )),
+                            vec![Expression::MutableReference(Box::new(local!(
+                                "positions_entry"
+                            )))],
+                            None
+                        )),
+                        vec![Statement::Expression(Expression::Assign(
+                            Box::new(entry),
+                            Box::new(local!("positions_entry"))
+                        ))]
+                    ))
+                );
+                // memory parameters get a copy of the stored value
+                assert_eq!(
+                    body[1],
+                    Statement::Return(Some(Expression::FunctionCall(
+                        Box::new(Expression::Variable(
+                            String::from("_sum"),
+                            MemberType::FunctionPrivate,
+                            VariableAccessLocation::Any,
+                        )),
+                        vec![Expression::Clone(Box::new(Expression::Variable(
+                            String::from("values"),
+                            MemberType::Variable(Box::new(Type::Array(
+                                Box::new(Type::Uint(256)),
+                                None
+                            ))),
+                            VariableAccessLocation::Any,
+                        )))],
+                        None
+                    )))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
         }
-        _ => false,
     }
-}
 
-#[macro_export]
-macro_rules! initialize_parser {
-    ($parser: ident) => {
-        let mut fields_map = HashMap::new();
-        let mut modifier_map = HashMap::new();
-        let mut imports = HashSet::new();
-        let mut comments = RBTree::new();
+    #[test]
+    fn storage_writes() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                struct Position {
+                    uint256 amount;
+                }
 
-        let mut $parser = Parser::new(
-            &mut fields_map,
-            &mut modifier_map,
-            &mut imports,
-            &mut comments,
+                mapping(address => Position) positions;
+
+                function update(address user) external {
+                    positions[user].amount = 1;
+                    Position storage position = positions[user];
+                    position.amount = 2;
+                }
+            }
+            "#,
         );
-    };
-}
+        assert!(output.is_ok());
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                let entry = Box::new(Expression::MappingSubscript(
+                    Box::new(Expression::Variable(
+                        String::from("positions"),
+                        MemberType::Variable(Box::new(Type::Mapping(
+                            vec![Type::AccountId],
+                            Box::new(Type::Variable(String::from("Position"))),
+                        ))),
+                        VariableAccessLocation::Any,
+                    )),
+                    vec![local!("user")],
+                ));
+                let amount = |value: &str| {
+                    Box::new(Expression::FunctionCall(
+                        Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                        vec![Expression::NumberLiteral(String::from(value))],
+                        None,
+                    ))
+                };
+                assert_eq!(
+                    body[0],
+                    Statement::Block(vec![
+                        Statement::VariableDefinition(
+                            Expression::VariableDeclaration(
+                                Box::new(Type::Variable(String::from("Position"))),
+                                String::from("positions_entry"),
+                                None
+                            ),
+                            Some(*entry.clone())
+                        ),
+                        Statement::Expression(Expression::Assign(
+                            Box::new(Expression::MemberAccess(
+                                Box::new(local!("positions_entry")),
+                                String::from("amount")
+                            )),
+                            amount("1")
+                        )),
+                        Statement::Expression(Expression::Assign(
+                            entry.clone(),
+                            Box::new(local!("positions_entry"))
+                        )),
+                    ])
+                );
+                assert_eq!(
+                    body[2],
+                    Statement::Block(vec![
+                        Statement::Expression(Expression::Assign(
+                            Box::new(Expression::MemberAccess(
+                                Box::new(local!("position")),
+                                String::from("amount")
+                            )),
+                            amount("2")
+                        )),
+                        Statement::Expression(Expression::Assign(
+                            entry,
+                            Box::new(local!("position"))
+                        )),
+                    ])
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
 
-    macro_rules! function_header {
-        (
-            $name: expr,
-            $params: expr,
-            $external: expr,
-            $view: expr,
-            $payable: expr,
-            $return_params: expr,
-            $comments: expr,
-            $modifiers: expr,
-            $invalid_modifiers: expr
-        ) => {
-            FunctionHeader {
-                name: $name.to_string(),
-                params: $params,
-                external: $external,
-                view: $view,
-                payable: $payable,
-                return_params: $return_params,
-                comments: $comments,
-                modifiers: $modifiers,
-                invalid_modifiers: $invalid_modifiers,
+    #[test]
+    fn data_locations() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                function total(uint256[] calldata values) external view returns (uint256) {
+                    uint256[] memory copy = values;
+                    return sum(values);
+                }
+
+                function sum(uint256[] calldata values) internal view returns (uint256) {
+                    return values.length;
+                }
             }
-        };
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            assert_eq!(
+                contract.functions[1].header.params,
+                vec![FunctionParam {
+                    name: String::from("values"),
+                    param_type: Type::Array(Box::new(Type::Uint(256)), None),
+                    location: Some(DataLocation::Calldata),
+                }]
+            );
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                match &body[0] {
+                    Statement::VariableDefinition(declaration, _) => {
+                        assert_eq!(
+                            declaration,
+                            &Expression::VariableDeclaration(
+                                Box::new(Type::Array(Box::new(Type::Uint(256)), None)),
+                                String::from("copy"),
+                                Some(DataLocation::Memory)
+                            )
+                        )
+                    }
+                    _ => unreachable!("Variable definition expected here"),
+                }
+                match &body[1] {
+                    Statement::Return(Some(Expression::FunctionCall(_, args, _))) => {
+                        assert_eq!(
+                            args,
+                            &vec![Expression::Reference(Box::new(Expression::Variable(
+                                String::from("values"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Any
+                            )))]
+                        )
+                    }
+                    _ => unreachable!("Return of a function call expected here"),
+                }
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
     }
 
-    macro_rules! contract_field {
-        ($field_type: expr, $name: expr, $initial_value: expr, $constant: expr, $public: expr) => {
-            ContractField {
-                field_type: $field_type,
-                name: $name.to_string(),
-                comments: Vec::default(),
-                initial_value: $initial_value,
-                constant: $constant,
-                public: $public,
+    #[test]
+    fn contract_calls() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            interface IERC20 {
+                function transfer(address to, uint256 amount) external returns (bool);
+            }
+
+            contract Contract {
+                IERC20 token;
+
+                function pay(address token_address, address to) external {
+                    IERC20(token_address).transfer(to, 1);
+                    token.transfer{value: 2}(to, 3);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[1] {
+            assert_eq!(
+                contract.fields[0].field_type,
+                Type::Contract(String::from("IERC20"))
+            );
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                let number = |value: &str| Expression::NumberLiteral(String::from(value));
+                let wide = |value: &str| {
+                    Expression::FunctionCall(
+                        Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                        vec![number(value)],
+                        None,
+                    )
+                };
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::ContractCall(
+                        String::from("IERC20"),
+                        Box::new(Expression::FunctionCall(
+                            Box::new(Expression::Type(Box::new(Type::Contract(String::from(
+                                "IERC20"
+                            ))))),
+                            vec![local!("token_address")],
+                            None
+                        )),
+                        String::from("transfer"),
+                        vec![local!("to"), wide("1")],
+                        None
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::ContractCall(
+                        String::from("IERC20"),
+                        Box::new(Expression::Variable(
+                            String::from("token"),
+                            MemberType::Variable(Box::new(Type::Contract(String::from("IERC20")))),
+                            VariableAccessLocation::Any
+                        )),
+                        String::from("transfer"),
+                        vec![local!("to"), wide("3")],
+                        Some(Box::new(number("2")))
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
             }
-        };
+        } else {
+            unreachable!("Contract expected here")
+        }
     }
 
     #[test]
-    fn one_contract_definition() {
+    fn contract_instantiation() {
         initialize_parser!(parser);
-        let output = parser.parse_file("contract Contract {}");
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Pair {}
+
+            contract Factory {
+                function create(bytes32 salt) external {
+                    new Pair{salt: salt}();
+                }
+            }
+            "#,
+        );
         assert!(output.is_ok());
 
         let output_ok = output.unwrap();
-        assert_eq!(output_ok.len(), 1);
+        if let ParserOutput::Contract(_, contract) = &output_ok[1] {
+            assert_eq!(contract.fields[0].name, "pair_code_hash");
+            assert_eq!(contract.fields[0].field_type, Type::Bytes(32));
+            assert_eq!(
+                contract.constructor.header.params,
+                vec![FunctionParam {
+                    name: String::from("pair_code_hash"),
+                    param_type: Type::Bytes(32),
+                    location: None,
+                }]
+            );
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Instantiate(
+                        String::from("Pair"),
+                        Box::new(Expression::Variable(
+                            String::from("pair_code_hash"),
+                            MemberType::Variable(Box::new(Type::Bytes(32))),
+                            VariableAccessLocation::Any
+                        )),
+                        Vec::default(),
+                        None,
+                        Some(Box::new(Expression::Variable(
+                            String::from("salt"),
+                            MemberType::None(Box::new(Type::None)),
+                            VariableAccessLocation::Any
+                        )))
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
     }
 
     #[test]
-    fn multiple_contract_definitions() {
+    fn assembly_contract_creation() {
         initialize_parser!(parser);
-        let output =
-            parser.parse_file("contract Contract {} interface Interface {} library Library {}");
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Pair {}
+
+            contract Factory {
+                function create(bytes32 salt) external returns (address pair) {
+                    bytes memory bytecode = type(Pair).creationCode;
+                    assembly {
+                        pair := create2(0, add(bytecode, 32), mload(bytecode), salt)
+                    }
+                }
+            }
+            "#,
+        );
         assert!(output.is_ok());
 
         let output_ok = output.unwrap();
-        assert_eq!(output_ok.len(), 3);
+        if let ParserOutput::Contract(_, contract) = &output_ok[1] {
+            assert_eq!(contract.fields[0].name, "pair_code_hash");
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert!(matches!(
+                    &body[0],
+                    Statement::VariableDefinition(_, Some(Expression::Substitute(..)))
+                ));
+                assert_eq!(
+                    body[1],
+                    Statement::Assembly(vec![Statement::Expression(Expression::Assign(
+                        Box::new(local!("pair")),
+                        Box::new(Expression::Instantiate(
+                            String::from("Pair"),
+                            Box::new(Expression::Variable(
+                                String::from("pair_code_hash"),
+                                MemberType::Variable(Box::new(Type::Bytes(32))),
+                                VariableAccessLocation::Any
+                            )),
+                            Vec::default(),
+                            None,
+                            Some(Box::new(local!("salt")))
+                        ))
+                    ))])
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
     }
 
     #[test]
-    fn no_contract_definitions() {
+    fn native_transfers() {
         initialize_parser!(parser);
-        let output = parser.parse_file("/// This is an empty Solidity file");
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                function pay(address to, uint8 amount) external {
+                    payable(to).transfer(amount);
+                    payable(to).send(amount);
+                    to.call{value: amount}("");
+                    msg.sender.transfer(amount);
+                }
+            }
+            "#,
+        );
         assert!(output.is_ok());
 
         let output_ok = output.unwrap();
-        assert_eq!(output_ok.len(), 0);
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                let payable = Box::new(Expression::FunctionCall(
+                    Box::new(Expression::Type(Box::new(Type::PayableAccountId))),
+                    vec![local!("to")],
+                    None,
+                ));
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Transfer(
+                        payable.clone(),
+                        Box::new(local!("amount")),
+                        VariableAccessLocation::Any
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::Send(
+                        payable,
+                        Box::new(local!("amount")),
+                        VariableAccessLocation::Any
+                    ))
+                );
+                assert_eq!(
+                    body[2],
+                    Statement::Expression(Expression::List(vec![
+                        Expression::Send(
+                            Box::new(local!("to")),
+                            Box::new(local!("amount")),
+                            VariableAccessLocation::Any
+                        ),
+                        Expression::FunctionCall(
+                            Box::new(Expression::Type(Box::new(Type::DynamicBytes))),
+                            vec![Expression::StringLiteral(vec![String::new()])],
+                            None
+                        )
+                    ]))
+                );
+                assert!(matches!(
+                    body[3],
+                    Statement::Expression(Expression::Transfer(..))
+                ));
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
     }
 
     #[test]
-    fn corrupted_file() {
+    fn low_level_calls() {
         initialize_parser!(parser);
-        let output = parser.parse_file("contract { uint abc = 123; }");
-        assert_eq!(
-            output.err().unwrap(),
-            ParserError::FileCorrupted(vec![String::from(
-                r#"unrecognised token '{', expected "case", "default", "error", "leave", "revert", "switch", identifier"#
-            )])
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            interface IPair {
+                function getReserves() external;
+            }
+
+            contract Contract {
+                bytes4 constant SELECTOR = bytes4(keccak256(bytes("transfer(address,uint256)")));
+
+                function forward(address token, address router, bytes4 selector) external {
+                    token.call(abi.encodeWithSelector(selector, router));
+                    router.delegatecall(abi.encodeWithSignature("getReserves()"));
+                }
+            }
+            "#,
         );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[1] {
+            assert_eq!(
+                contract.fields[0].initial_value,
+                Some(Expression::Selector(String::from("transfer")))
+            );
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::LowLevelCall(
+                        CallType::Call,
+                        Box::new(local!("token")),
+                        Box::new(local!("selector")),
+                        vec![local!("router")],
+                        None,
+                        VariableAccessLocation::Any
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::LowLevelCall(
+                        CallType::DelegateCall,
+                        Box::new(local!("router")),
+                        Box::new(Expression::Selector(String::from("IPair::get_reserves"))),
+                        Vec::default(),
+                        None,
+                        VariableAccessLocation::Any
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
     }
 
     #[test]
-    fn contract_with_function() {
+    fn abi_encoding() {
         initialize_parser!(parser);
+        parser.set_abi_encoding(AbiEncoding::Ethereum);
         let output = parser.parse_file(
+            "Contract.sol",
             r#"
-            contract A { 
-                function fun_1() external {}
-                function fun_2() external view {}
-                function fun_3() external payable {}
-                function fun_4() internal {}
+            contract Contract {
+                function codec(address token, uint8 amount, bytes memory data, uint24 fee) external {
+                    abi.encodePacked(token, amount, fee);
+                    abi.encode(amount);
+                    abi.decode(data, (uint256, address));
+                }
             }
             "#,
         );
         assert!(output.is_ok());
 
         let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::AbiEncodePacked(vec![
+                        (local!("token"), Type::AccountId),
+                        (local!("amount"), Type::Uint(8)),
+                        (local!("fee"), Type::Uint(24)),
+                    ]))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::AbiEncode(
+                        AbiEncoding::Ethereum,
+                        None,
+                        vec![(local!("amount"), Type::Uint(8))]
+                    ))
+                );
+                assert_eq!(
+                    body[2],
+                    Statement::Expression(Expression::AbiDecode(
+                        AbiEncoding::Ethereum,
+                        Box::new(local!("data")),
+                        vec![Type::Uint(256), Type::AccountId]
+                    ))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
 
-        let contract_a = &output_ok[0];
-
-        if let ParserOutput::Contract(_, contract) = contract_a {
-            assert!(contract.base.is_empty());
-            assert_eq!(contract.functions.len(), 4);
-            assert_eq!(
-                contract.functions[0].header,
-                function_header!(
-                    "fun_1",
-                    Vec::default(),
-                    true,
-                    false,
-                    false,
-                    Vec::default(),
-                    Vec::default(),
-                    Vec::default(),
-                    Vec::default()
-                )
-            );
-            assert_eq!(
-                contract.functions[1].header,
-                function_header!(
-                    "fun_2",
-                    Vec::default(),
-                    true,
-                    true,
-                    false,
-                    Vec::default(),
-                    Vec::default(),
-                    Vec::default(),
-                    Vec::default()
-                )
-            );
-            assert_eq!(
-                contract.functions[2].header,
-                function_header!(
-                    "fun_3",
-                    Vec::default(),
-                    true,
-                    false,
-                    true,
-                    Vec::default(),
-                    Vec::default(),
-                    Vec::default(),
-                    Vec::default()
-                )
-            );
-            assert_eq!(
-                contract.functions[3].header,
-                function_header!(
-                    "fun_4",
-                    Vec::default(),
-                    false,
-                    false,
-                    false,
-                    Vec::default(),
-                    Vec::default(),
-                    Vec::default(),
-                    Vec::default()
-                )
-            );
+    #[test]
+    fn crypto_builtins() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                function verify(bytes memory data, uint8 v, bytes32 r, bytes32 s) external {
+                    sha256(data);
+                    ecrecover(keccak256(data), v, r, s);
+                    ripemd160(data);
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
+                assert_eq!(
+                    body[0],
+                    Statement::Expression(Expression::Hash(
+                        HashFunction::Sha256,
+                        Box::new(local!("data")),
+                        VariableAccessLocation::Any
+                    ))
+                );
+                assert_eq!(
+                    body[1],
+                    Statement::Expression(Expression::EcRecover(
+                        Box::new(Expression::Hash(
+                            HashFunction::Keccak256,
+                            Box::new(local!("data")),
+                            VariableAccessLocation::Any
+                        )),
+                        Box::new(local!("v")),
+                        Box::new(local!("r")),
+                        Box::new(local!("s")),
+                        VariableAccessLocation::Any
+                    ))
+                );
+                assert_eq!(
+                    body[2],
+                    Statement::Expression(Expression::Unsupported(String::from(
+                        "ripemd160 is not available in ink!"
+                    )))
+                );
+            } else {
+                unreachable!("Body should not be empty")
+            }
         } else {
             unreachable!("Contract expected here")
         }
     }
 
     #[test]
-    fn contract_with_base() {
+    fn escaped_strings() {
         initialize_parser!(parser);
-        let output = parser.parse_file(r#"contract A is B, C, D { }"#);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                bytes32 constant HASH = keccak256("a\nb");
+                string constant GREETING = "say \"hi\"\x21 caf\u00e9\t";
+            }
+            "#,
+        );
         assert!(output.is_ok());
 
         let output_ok = output.unwrap();
-
-        let contract_a = &output_ok[0];
-
-        if let ParserOutput::Contract(_, contract) = contract_a {
-            assert_eq!(contract.base.len(), 3);
-            assert_eq!(contract.base[0], "B");
-            assert_eq!(contract.base[1], "C");
-            assert_eq!(contract.base[2], "D");
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            // the hash of the unescaped string is computed while transpiling
+            let hash = contract.fields[0].initial_value.as_ref().unwrap();
+            assert!(quote::quote!(#hash)
+                .to_string()
+                .starts_with("[0x72 , 0xb4 , 0x7b , 0x63"));
+            assert_eq!(
+                contract.fields[1].initial_value,
+                Some(Expression::StringLiteral(vec![String::from(
+                    "say \"hi\"! café\t"
+                )]))
+            );
         } else {
             unreachable!("Contract expected here")
         }
     }
 
     #[test]
-    fn arithmetic_operations_work() {
+    fn global_substitutes() {
         initialize_parser!(parser);
+        parser.set_chain_id(Some(1));
         let output = parser.parse_file(
+            "Contract.sol",
             r#"
-                contract A { 
-                    function do_math(uint b, uint c) internal returns (uint) { 
-                        uint result = b + c; 
-                        result = b - c; 
-                        result += b * c; 
-                        result -= b / c; 
-                        result *= b % c; 
-                        result /= result; 
-                        return result;
-                    } 
+            contract Contract {
+                function globals() external {
+                    tx.origin;
+                    block.chainid;
+                    address(this).balance;
+                    msg.value;
+                    gasleft();
                 }
-                "#,
+            }
+            "#,
         );
         assert!(output.is_ok());
 
         let output_ok = output.unwrap();
-
-        let contract_a = &output_ok[0];
-
-        if let ParserOutput::Contract(_, contract) = contract_a {
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
             if let Some(Statement::Block(body)) = &contract.functions[0].body {
                 assert_eq!(
                     body[0],
-                    Statement::VariableDefinition(
-                        Expression::VariableDeclaration(
-                            Box::new(Type::Uint(128)),
-                            String::from("result")
-                        ),
-                        Some(Expression::Add(
-                            Box::new(Expression::Variable(
-                                String::from("b"),
-                                MemberType::None(Box::new(Type::None)),
-                                VariableAccessLocation::Any
-                            )),
-                            Box::new(Expression::Variable(
-                                String::from("c"),
-                                MemberType::None(Box::new(Type::None)),
-                                VariableAccessLocation::Any
-                            )),
-                        ))
-                    )
+                    Statement::Expression(Expression::Unsupported(String::from(
+                        "tx.origin is not available in ink!"
+                    )))
                 );
                 assert_eq!(
                     body[1],
-                    Statement::Expression(Expression::Assign(
-                        Box::new(Expression::Variable(
-                            String::from("result"),
-                            MemberType::None(Box::new(Type::None)),
-                            VariableAccessLocation::Any
-                        )),
-                        Box::new(Expression::Subtract(
-                            Box::new(Expression::Variable(
-                                String::from("b"),
-                                MemberType::None(Box::new(Type::None)),
-                                VariableAccessLocation::Any
-                            )),
-                            Box::new(Expression::Variable(
-                                String::from("c"),
-                                MemberType::None(Box::new(Type::None)),
-                                VariableAccessLocation::Any
-                            )),
-                        ))
+                    Statement::Expression(Expression::Substitute(
+                        String::from("block.chainid is the chain id set with --chain-id"),
+                        Box::new(Type::Uint(256)),
+                        Some(Box::new(Expression::FunctionCall(
+                            Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                            vec![Expression::NumberLiteral(String::from("1"))],
+                            None
+                        )))
                     ))
                 );
                 assert_eq!(
                     body[2],
-                    Statement::Expression(Expression::AssignAdd(
-                        Box::new(Expression::Variable(
-                            String::from("result"),
-                            MemberType::None(Box::new(Type::None)),
+                    Statement::Expression(Expression::FunctionCall(
+                        Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                        vec![Expression::AssemblyCall(
+                            String::from("selfbalance"),
+                            Vec::default(),
                             VariableAccessLocation::Any
-                        )),
-                        Box::new(Expression::Multiply(
-                            Box::new(Expression::Variable(
-                                String::from("b"),
-                                MemberType::None(Box::new(Type::None)),
-                                VariableAccessLocation::Any
-                            )),
-                            Box::new(Expression::Variable(
-                                String::from("c"),
-                                MemberType::None(Box::new(Type::None)),
-                                VariableAccessLocation::Any
-                            )),
-                        ))
+                        )],
+                        None
                     ))
                 );
                 assert_eq!(
                     body[3],
-                    Statement::Expression(Expression::AssignSubtract(
-                        Box::new(Expression::Variable(
-                            String::from("result"),
-                            MemberType::None(Box::new(Type::None)),
-                            VariableAccessLocation::Any
-                        )),
-                        Box::new(Expression::Divide(
+                    Statement::Expression(Expression::FunctionCall(
+                        Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                        vec![Expression::MemberAccess(
                             Box::new(Expression::Variable(
-                                String::from("b"),
-                                MemberType::None(Box::new(Type::None)),
-                                VariableAccessLocation::Any
-                            )),
-                            Box::new(Expression::Variable(
-                                String::from("c"),
+                                String::from("msg"),
                                 MemberType::None(Box::new(Type::None)),
                                 VariableAccessLocation::Any
                             )),
-                        ))
+                            String::from("value")
+                        )],
+                        None
                     ))
                 );
                 assert_eq!(
                     body[4],
-                    Statement::Expression(Expression::AssignMultiply(
-                        Box::new(Expression::Variable(
-                            String::from("result"),
-                            MemberType::None(Box::new(Type::None)),
-                            VariableAccessLocation::Any
-                        )),
-                        Box::new(Expression::Modulo(
-                            Box::new(Expression::Variable(
-                                String::from("b"),
-                                MemberType::None(Box::new(Type::None)),
-                                VariableAccessLocation::Any
-                            )),
+                    Statement::Expression(Expression::FunctionCall(
+                        Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                        vec![Expression::MemberAccess(
                             Box::new(Expression::Variable(
-                                String::from("c"),
+                                String::from("msg"),
                                 MemberType::None(Box::new(Type::None)),
                                 VariableAccessLocation::Any
                             )),
-                        ))
+                            String::from("gas")
+                        )],
+                        None
                     ))
                 );
+            } else {
+                unreachable!("Body should not be empty")
+            }
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn tuple_destructuring() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract Contract {
+                uint a;
+                uint b;
+                uint x;
+
+                function swap() external {
+                    (uint x, , bool y) = (a, 1, true);
+                    (a, b) = (b, a);
+                    x;
+                }
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let field = |name: &str| {
+            Expression::Variable(
+                String::from(name),
+                MemberType::Variable(Box::new(Type::Uint(256))),
+                VariableAccessLocation::Any,
+            )
+        };
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+            if let Some(Statement::Block(body)) = &contract.functions[0].body {
                 assert_eq!(
-                    body[5],
-                    Statement::Expression(Expression::AssignDivide(
-                        Box::new(Expression::Variable(
-                            String::from("result"),
-                            MemberType::None(Box::new(Type::None)),
-                            VariableAccessLocation::Any
-                        )),
-                        Box::new(Expression::Variable(
-                            String::from("result"),
-                            MemberType::None(Box::new(Type::None)),
-                            VariableAccessLocation::Any
-                        )),
-                    ))
+                    body[0],
+                    Statement::VariableDefinition(
+                        Expression::List(vec![
+                            Expression::VariableDeclaration(
+                                Box::new(Type::Uint(256)),
+                                String::from("x"),
+                                None
+                            ),
+                            Expression::None,
+                            Expression::VariableDeclaration(
+                                Box::new(Type::Bool),
+                                String::from("y"),
+                                None
+                            ),
+                        ]),
+                        Some(Expression::List(vec![
+                            field("a"),
+                            Expression::NumberLiteral(String::from("1")),
+                            Expression::BoolLiteral(true),
+                        ]))
+                    )
                 );
                 assert_eq!(
-                    body[6],
-                    Statement::Return(Some(Expression::Variable(
-                        String::from("result"),
-                        MemberType::None(Box::new(Type::None)),
-                        VariableAccessLocation::Any
-                    )))
+                    body[1],
+                    Statement::Block(vec![
+                        Statement::VariableDefinition(
+                            Expression::List(vec![
+                                Expression::VariableDeclaration(
+                                    Box::new(Type::None),
+                                    String::from("tuple_0"),
+                                    None
+                                ),
+                                Expression::VariableDeclaration(
+                                    Box::new(Type::None),
+                                    String::from("tuple_1"),
+                                    None
+                                ),
+                            ]),
+                            Some(Expression::List(vec![field("b"), field("a")]))
+                        ),
+                        Statement::Expression(Expression::Assign(
+                            Box::new(field("a")),
+                            Box::new(local!("tuple_0"))
+                        )),
+                        Statement::Expression(Expression::Assign(
+                            Box::new(field("b")),
+                            Box::new(local!("tuple_1"))
+                        )),
+                    ])
                 );
+                // the destructured local shadows the field
+                assert_eq!(body[2], Statement::Expression(local!("x")));
             } else {
                 unreachable!("Body should not be empty")
             }
@@ -2033,58 +9005,149 @@ mod tests {
     }
 
     #[test]
-    fn contract_with_attributes() {
+    fn contract_inheritance() {
         initialize_parser!(parser);
         let output = parser.parse_file(
+            "Contract.sol",
             r#"
-            contract Contract {
-                string public name;
-                uint public age;
-                address payable owner;
-                uint constant FEE = 1 ether;
+            interface I {}
+            contract A is I {
+                uint a;
+                function f() public virtual {}
+            }
+            contract B is A {
+                uint b;
+                function f() public virtual override { super.f(); }
+            }
+            contract C is A {
+                uint c;
+                function f() public virtual override { super.f(); }
+                function g() internal {}
+            }
+            contract D is B, C {
+                function f() public override(B, C) { super.f(); }
             }
             "#,
         );
         assert!(output.is_ok());
 
         let output_ok = output.unwrap();
-        assert_eq!(output_ok.len(), 1);
-
-        if let ParserOutput::Contract(_, contract) = &output_ok[0] {
+        if let ParserOutput::Contract(_, contract) = &output_ok[4] {
+            assert_eq!(contract.base, vec![String::from("B"), String::from("C")]);
+            assert_eq!(contract.interfaces, vec![String::from("I")]);
             assert_eq!(
-                contract.fields[0],
-                contract_field!(Type::String, "name", None, false, true)
+                contract
+                    .fields
+                    .iter()
+                    .map(|field| field.name.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["a", "b", "c"]
             );
+            // D, C, B, A is the linearization of D, so `super.f()` in D calls the `f` of C
             assert_eq!(
-                contract.fields[1],
-                contract_field!(Type::Uint(128), "age", None, false, true)
+                contract
+                    .functions
+                    .iter()
+                    .map(|function| (function.header.name.as_str(), function.header.external))
+                    .collect::<Vec<_>>(),
+                vec![
+                    ("f", true),
+                    ("g", false),
+                    ("f_super_D", false),
+                    ("f_super_C", false),
+                    ("f_super_B", false),
+                ]
             );
             assert_eq!(
-                contract.fields[2],
-                contract_field!(Type::AccountId, "owner", None, false, false)
+                contract.functions[0].body,
+                Some(Statement::Block(vec![Statement::Expression(
+                    Expression::FunctionCall(
+                        Box::new(Expression::Variable(
+                            String::from("f_super_D"),
+                            MemberType::FunctionPrivate,
+                            VariableAccessLocation::Any
+                        )),
+                        Vec::default(),
+                        None
+                    )
+                )]))
             );
+        } else {
+            unreachable!("Contract expected here")
+        }
+    }
+
+    #[test]
+    fn base_constructors() {
+        initialize_parser!(parser);
+        let output = parser.parse_file(
+            "Contract.sol",
+            r#"
+            contract A {
+                uint a;
+                constructor(uint a_) { a = a_; }
+            }
+            contract B is A(1) {
+                constructor() {}
+            }
+            "#,
+        );
+        assert!(output.is_ok());
+
+        let output_ok = output.unwrap();
+        if let ParserOutput::Contract(_, contract) = &output_ok[1] {
+            assert!(contract.constructor.header.modifiers.is_empty());
             assert_eq!(
-                contract.fields[3],
-                contract_field!(
-                    Type::Uint(128),
-                    "FEE",
-                    Some(Expression::Unit(
-                        Box::new(Expression::NumberLiteral(String::from("1"))),
-                        1000000000000000000
-                    )),
-                    true,
-                    false
-                )
+                contract.constructor.body,
+                Some(Statement::Block(vec![Statement::BaseConstructor(
+                    String::from("A"),
+                    vec![
+                        Statement::VariableDefinition(
+                            Expression::VariableDeclaration(
+                                Box::new(Type::Uint(256)),
+                                String::from("a"),
+                                None
+                            ),
+                            Some(Expression::FunctionCall(
+                                Box::new(Expression::Type(Box::new(Type::Uint(256)))),
+                                vec![Expression::NumberLiteral(String::from("1"))],
+                                None
+                            ))
+                        ),
+                        Statement::Expression(Expression::Assign(
+                            Box::new(Expression::Variable(
+                                String::from("a"),
+                                MemberType::Variable(Box::new(Type::Uint(256))),
+                                VariableAccessLocation::Constructor
+                            )),
+                            Box::new(Expression::Variable(
+                                String::from("a_"),
+                                MemberType::None(Box::new(Type::None)),
+                                VariableAccessLocation::Constructor
+                            ))
+                        )),
+                    ]
+                )]))
             );
         } else {
-            unreachable!("Only contract allowed here!")
+            unreachable!("Contract expected here")
         }
     }
 
+    #[test]
+    fn cyclic_inheritance() {
+        initialize_parser!(parser);
+        let output = parser.parse_file("Contract.sol", "contract A is A {}");
+        assert_eq!(
+            output.err(),
+            Some(ParserError::CyclicInheritance(String::from("A")))
+        );
+    }
+
     #[test]
     fn empty_contract_definition() {
         initialize_parser!(parser);
-        let output = parser.parse_file("contract Contract {}");
+        let output = parser.parse_file("Contract.sol", "contract Contract {}");
         assert!(output.is_ok());
 
         let output_ok = output.unwrap();
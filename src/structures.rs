@@ -20,9 +20,12 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use std::collections::{
-    HashMap,
-    HashSet,
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    str::FromStr,
 };
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -42,11 +45,15 @@ pub struct Contract {
     pub events: Vec<Event>,
     pub enums: Vec<Enum>,
     pub structs: Vec<Struct>,
+    pub value_types: Vec<ValueType>,
+    pub errors: Vec<Error>,
+    pub globals_errors: Vec<Error>,
     pub functions: Vec<Function>,
     pub imports: HashSet<Import>,
     pub contract_doc: Vec<String>,
     pub modifiers: Vec<Function>,
     pub base: Vec<String>,
+    pub interfaces: Vec<String>,
 }
 
 #[derive(Clone, Default, Debug)]
@@ -56,6 +63,8 @@ pub struct Library {
     pub events: Vec<Event>,
     pub enums: Vec<Enum>,
     pub structs: Vec<Struct>,
+    pub value_types: Vec<ValueType>,
+    pub errors: Vec<Error>,
     pub functions: Vec<Function>,
     pub imports: HashSet<Import>,
     pub libraray_doc: Vec<String>,
@@ -67,11 +76,24 @@ pub struct Interface {
     pub events: Vec<Event>,
     pub enums: Vec<Enum>,
     pub structs: Vec<Struct>,
+    pub value_types: Vec<ValueType>,
+    pub errors: Vec<Error>,
     pub function_headers: Vec<FunctionHeader>,
     pub imports: HashSet<Import>,
     pub comments: Vec<String>,
 }
 
+#[derive(Clone, Default, Debug)]
+pub struct Globals {
+    pub fields: Vec<ContractField>,
+    pub enums: Vec<Enum>,
+    pub structs: Vec<Struct>,
+    pub value_types: Vec<ValueType>,
+    pub errors: Vec<Error>,
+    pub functions: Vec<Function>,
+    pub imports: HashSet<Import>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContractField {
     pub field_type: Type,
@@ -97,6 +119,19 @@ pub struct EventField {
     pub comments: Vec<String>,
 }
 
+#[derive(Clone, Debug)]
+pub struct Error {
+    pub name: String,
+    pub fields: Vec<ErrorField>,
+    pub comments: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ErrorField {
+    pub field_type: Type,
+    pub name: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct Enum {
     pub name: String,
@@ -124,6 +159,21 @@ pub struct StructField {
     pub comments: Vec<String>,
 }
 
+#[derive(Clone, Debug)]
+pub struct ValueType {
+    pub name: String,
+    pub ty: Type,
+    pub operators: Vec<OperatorBinding>,
+    pub comments: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct OperatorBinding {
+    pub operator: String,
+    pub function: String,
+    pub unary: bool,
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct Function {
     pub header: FunctionHeader,
@@ -148,17 +198,25 @@ pub struct FunctionHeader {
 pub struct FunctionParam {
     pub name: String,
     pub param_type: Type,
+    pub location: Option<DataLocation>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DataLocation {
+    Memory,
+    Storage,
+    Calldata,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Statement {
-    Assembly,
+    Assembly(Vec<Statement>),
+    BaseConstructor(String, Vec<Statement>),
     Block(Vec<Statement>),
     Break,
     Continue,
     DoWhile(Box<Statement>, Expression),
     Emit(Expression),
-    Error,
     Expression(Expression),
     For(
         Option<Box<Statement>>,
@@ -169,13 +227,24 @@ pub enum Statement {
     If(Expression, Box<Statement>, Option<Box<Statement>>),
     Return(Option<Expression>),
     Revert(String, Vec<Expression>),
-    RevertNamedArgs,
-    Try(Expression),
+    RevertNamedArgs(String, Vec<(String, Expression)>),
+    Try(
+        Expression,
+        Vec<FunctionParam>,
+        Box<Statement>,
+        Vec<CatchClause>,
+    ),
     UncheckedBlock(Vec<Statement>),
     VariableDefinition(Expression, Option<Expression>),
     While(Expression, Box<Statement>),
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CatchClause {
+    Simple(Option<FunctionParam>, Statement),
+    Named(String, FunctionParam, Statement),
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum VariableAccessLocation {
     Constructor,
@@ -183,26 +252,114 @@ pub enum VariableAccessLocation {
     Any,
 }
 
+/// The kind of a low-level call of an address
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CallType {
+    Call,
+    StaticCall,
+    DelegateCall,
+}
+
+/// The encoding of the values encoded and decoded with the `abi` functions
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AbiEncoding {
+    /// the values are encoded with SCALE, like the arguments of ink! messages
+    #[default]
+    Scale,
+    /// the values are encoded as in the Ethereum contract ABI
+    Ethereum,
+}
+
+impl FromStr for AbiEncoding {
+    type Err = String;
+
+    fn from_str(encoding: &str) -> Result<Self, Self::Err> {
+        match encoding {
+            "scale" => Ok(AbiEncoding::Scale),
+            "ethereum" => Ok(AbiEncoding::Ethereum),
+            _ => {
+                Err(format!(
+                    "{encoding} is not an encoding, use `scale` or `ethereum`"
+                ))
+            }
+        }
+    }
+}
+
+/// The hash functions of Solidity which are provided by the ink! environment
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HashFunction {
+    Keccak256,
+    Sha256,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Expression {
+    AbiDecode(AbiEncoding, Box<Expression>, Vec<Type>),
+    AbiEncode(
+        AbiEncoding,
+        Option<Box<Expression>>,
+        Vec<(Expression, Type)>,
+    ),
+    AbiEncodePacked(Vec<(Expression, Type)>),
     Add(Box<Expression>, Box<Expression>),
     And(Box<Expression>, Box<Expression>),
     ArraySubscript(Box<Expression>, Option<Box<Expression>>),
     ArrayLiteral(Vec<Expression>),
+    AssemblyCall(String, Vec<Expression>, VariableAccessLocation),
     Assign(Box<Expression>, Box<Expression>),
     AssignAdd(Box<Expression>, Box<Expression>),
     AssignDivide(Box<Expression>, Box<Expression>),
     AssignModulo(Box<Expression>, Box<Expression>),
     AssignMultiply(Box<Expression>, Box<Expression>),
     AssignSubtract(Box<Expression>, Box<Expression>),
+    Balance(Box<Expression>),
     BoolLiteral(bool),
+    Cast(Box<Type>, Box<Expression>),
+    Checked(Box<Expression>),
+    Clone(Box<Expression>),
+    ContractCall(
+        String,
+        Box<Expression>,
+        String,
+        Vec<Expression>,
+        Option<Box<Expression>>,
+    ),
     Delete(Box<Expression>),
     Divide(Box<Expression>, Box<Expression>),
+    EcRecover(
+        Box<Expression>,
+        Box<Expression>,
+        Box<Expression>,
+        Box<Expression>,
+        VariableAccessLocation,
+    ),
     FunctionCall(Box<Expression>, Vec<Expression>, Option<Box<Expression>>),
+    FunctionPointer(Box<Expression>, Option<usize>),
+    FunctionPointerCall(Box<Expression>, Vec<Expression>, VariableAccessLocation),
     Equal(Box<Expression>, Box<Expression>),
+    ExternalFunctionCall(Box<Expression>, Vec<Expression>, Vec<Type>),
+    Hash(HashFunction, Box<Expression>, VariableAccessLocation),
+    Instantiate(
+        String,
+        Box<Expression>,
+        Vec<Expression>,
+        Option<Box<Expression>>,
+        Option<Box<Expression>>,
+    ),
     InvalidModifier(String, Vec<Expression>),
+    Length(Box<Expression>),
     Less(Box<Expression>, Box<Expression>),
     LessEqual(Box<Expression>, Box<Expression>),
+    LibraryCall(String, String, Vec<Expression>),
+    LowLevelCall(
+        CallType,
+        Box<Expression>,
+        Box<Expression>,
+        Vec<Expression>,
+        Option<Box<Expression>>,
+        VariableAccessLocation,
+    ),
     List(Vec<Expression>),
     MappingSubscript(Box<Expression>, Vec<Expression>),
     MemberAccess(Box<Expression>, String),
@@ -212,24 +369,35 @@ pub enum Expression {
     More(Box<Expression>, Box<Expression>),
     MoreEqual(Box<Expression>, Box<Expression>),
     Multiply(Box<Expression>, Box<Expression>),
+    MutableReference(Box<Expression>),
     New(Box<Expression>),
     Not(Box<Expression>),
     NotEqual(Box<Expression>, Box<Expression>),
     NumberLiteral(String),
     Or(Box<Expression>, Box<Expression>),
     Parenthesis(Box<Expression>),
+    Pop(Box<Expression>),
     PostDecrement(Box<Expression>),
     PostIncrement(Box<Expression>),
     Power(Box<Expression>, Box<Expression>),
     PreDecrement(Box<Expression>),
     PreIncrement(Box<Expression>),
+    Push(Box<Expression>, Option<Box<Expression>>),
+    Reference(Box<Expression>),
+    Selector(String),
+    Send(Box<Expression>, Box<Expression>, VariableAccessLocation),
     StringLiteral(Vec<String>),
+    Substitute(String, Box<Type>, Option<Box<Expression>>),
     Subtract(Box<Expression>, Box<Expression>),
     Ternary(Box<Expression>, Box<Expression>, Box<Expression>),
     This(VariableAccessLocation),
+    Transfer(Box<Expression>, Box<Expression>, VariableAccessLocation),
+    Truncate(Box<Type>, Box<Expression>),
     Type(Box<Type>),
     Variable(String, MemberType, VariableAccessLocation),
-    VariableDeclaration(Box<Type>, String),
+    VariableDeclaration(Box<Type>, String, Option<DataLocation>),
+    Wrapping(Box<Expression>),
+    WriteBack(Vec<Statement>, Box<Expression>, Vec<Statement>),
     ShiftLeft(Box<Expression>, Box<Expression>),
     ShiftRight(Box<Expression>, Box<Expression>),
     BitwiseAnd(Box<Expression>, Box<Expression>),
@@ -245,6 +413,8 @@ pub enum Expression {
     UnaryPlus(Box<Expression>),
     UnaryMinus(Box<Expression>),
     Unit(Box<Expression>, i128),
+    Untranslatable(String),
+    Unsupported(String),
     ArraySlice(
         Box<Expression>,
         Option<Box<Expression>>,
@@ -256,26 +426,56 @@ pub enum Expression {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Type {
     AccountId,
+    PayableAccountId,
     Array(Box<Type>, Option<Expression>),
     Bool,
     String,
     Int(u16),
     Uint(u16),
+    Usize,
     Bytes(u8),
     DynamicBytes,
     Variable(String),
+    Contract(String),
     MemberAccess(Expression, String),
     Mapping(Vec<Type>, Box<Type>),
+    // storage array stored as a mapping from the indices to the elements
+    LazyArray(Box<Type>, Box<Type>),
+    // internal function pointer, the flag is set for `view` and `pure` functions which borrow the contract immutably
+    Function(Vec<Type>, Vec<Type>, bool),
+    ExternalFunction(Vec<Type>, Vec<Type>),
     None,
 }
 
+impl Type {
+    /// Returns true if the values of the type implement `Copy`, unknown types are expected to be `Copy`
+    pub fn is_copy(&self) -> bool {
+        match self {
+            Type::Array(ty, Some(_)) => ty.is_copy(),
+            Type::Array(..)
+            | Type::String
+            | Type::DynamicBytes
+            | Type::Mapping(..)
+            | Type::LazyArray(..) => false,
+            // structs and other user defined types
+            Type::Variable(_) | Type::MemberAccess(..) => false,
+            _ => true,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum Import {
     ModifierDefinition,
     Modifiers,
     AccountId,
+    Globals(String),
+    I256,
+    ImportedGlobals(String),
+    Libs,
     Mapping,
     String,
+    U256,
     Vec,
     ZeroAddress,
 }
@@ -20,10 +20,18 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::structures::AbiEncoding;
+
 const INK_VERSION: &str = "~4.1.0";
 const OPENBRUSH_VERSION: &str = "3.1.0";
+const PRIMITIVE_TYPES_VERSION: &str = "0.12";
+const ETHABI_VERSION: &str = "18";
 
-pub fn generate_cargo_toml(package_name: &str, mod_name: Option<String>) -> String {
+pub fn generate_cargo_toml(
+    package_name: &str,
+    mod_name: Option<String>,
+    abi_encoding: AbiEncoding,
+) -> String {
     let mut out = String::new();
     const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -40,7 +48,15 @@ pub fn generate_cargo_toml(package_name: &str, mod_name: Option<String>) -> Stri
     ));
     out.push_str("scale = { package = \"parity-scale-codec\", version = \"3\", default-features = false, features = [\"derive\"] }\n");
     out.push_str("scale-info = { version = \"2.3\", default-features = false, features = [\"derive\"], optional = true }\n");
+    out.push_str(&format!("primitive-types = {{ version = \"{}\", default-features = false, features = [\"codec\", \"scale-info\"] }}\n", PRIMITIVE_TYPES_VERSION));
     out.push_str(&format!("openbrush = {{ git = \"https://github.com/Brushfam/openbrush-contracts\", tag = \"{}\", default-features = false, features = [] }}\n", OPENBRUSH_VERSION));
+    // the Ethereum ABI encoding is implemented by `ethabi`
+    if abi_encoding == AbiEncoding::Ethereum {
+        out.push_str(&format!(
+            "ethabi = {{ version = \"{}\", default-features = false }}\n",
+            ETHABI_VERSION
+        ));
+    }
     out.push('\n');
 
     if let Some(mod_name) = mod_name.clone() {
@@ -63,6 +79,10 @@ pub fn generate_cargo_toml(package_name: &str, mod_name: Option<String>) -> Stri
     out.push_str("\"scale/std\",\n");
     out.push_str("\"scale-info/std\",\n");
     out.push_str("\"openbrush/std\",\n");
+    out.push_str("\"primitive-types/std\",\n");
+    if abi_encoding == AbiEncoding::Ethereum {
+        out.push_str("\"ethabi/std\",\n");
+    }
     if let Some(mod_name) = mod_name {
         out.push('"');
         out.push_str(mod_name.as_str());
@@ -34,6 +34,8 @@ pub mod erc_1155 {
 
     impl ERC165 for ERC1155Contract {}
 
+    impl IERC165 for ERC1155Contract {}
+
     impl IERC1155 for ERC1155Contract {}
 
     impl IERC1155MetadataURI for ERC1155Contract {}
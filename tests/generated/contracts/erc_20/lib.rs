@@ -52,7 +52,7 @@ pub mod erc_20 {
         from: AccountId,
         #[ink(topic)]
         to: AccountId,
-        value: u128,
+        value: U256,
     }
 
     /// @dev Emitted when the allowance of a `spender` for an `owner` is set by
@@ -63,7 +63,7 @@ pub mod erc_20 {
         owner: AccountId,
         #[ink(topic)]
         spender: AccountId,
-        value: u128,
+        value: U256,
     }
 
     #[ink(storage)]
@@ -76,11 +76,11 @@ pub mod erc_20 {
     impl ERC20 for ERC20Contract {}
     impl generated::impls::erc_20::Internal for ERC20Contract {
 
-        fn _emit_transfer(&self, from: AccountId, to: AccountId, value: u128) {
+        fn _emit_transfer(&self, from: AccountId, to: AccountId, value: U256) {
             self.env().emit_event(Transfer { from, to, value });
         }
 
-        fn _emit_approval(&self, owner: AccountId, spender: AccountId, value: u128) {
+        fn _emit_approval(&self, owner: AccountId, spender: AccountId, value: U256) {
             self.env().emit_event(Approval {
                 owner,
                 spender,
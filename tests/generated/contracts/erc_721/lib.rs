@@ -32,6 +32,8 @@ pub mod erc_721 {
 
     impl ERC165 for ERC721Contract {}
 
+    impl IERC165 for ERC721Contract {}
+
     impl IERC721 for ERC721Contract {}
 
     impl IERC721Metadata for ERC721Contract {}
@@ -28,7 +28,7 @@ pub mod function_contract {
         pub fn new() -> Self {
             let mut instance = Self::default();
             instance.data.owner = instance.env().caller();
-            self.x = 10;
+            self.x = U256::from(10);
             instance
         }
 
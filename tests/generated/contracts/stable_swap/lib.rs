@@ -14,22 +14,22 @@ pub mod stable_swap {
     use openbrush::traits::Storage;
 
     /// Number of tokens
-    pub const N: u128 = 3;
+    pub const N: U256 = U256([3, 0, 0, 0]);
     /// Amplification coefficient multiplied by N^(N - 1)
     /// Higher value makes the curve more flat
     /// Lower value makes the curve more like constant product AMM
-    pub const A: u128 = 1000 * (N.pow((N - 1)));
+    pub const A: U256 = 1000 * (N.pow((N - 1)));
     /// 0.03%
-    pub const SWAP_FEE: u128 = 300;
+    pub const SWAP_FEE: U256 = U256([300, 0, 0, 0]);
     /// Liquidity fee is derived from 2 constraints
     /// 1. Fee is 0 for adding / removing liquidity that results in a balanced pool
     /// 2. Swapping in a balanced pool is like adding and then removing liquidity
     ///    from a balanced pool
     /// swap fee = add liquidity fee + remove liquidity fee
-    pub const LIQUIDITY_FEE: u128 = (SWAP_FEE * N) / (4 * (N - 1));
-    pub const FEE_DENOMINATOR: u128 = 1000000;
+    pub const LIQUIDITY_FEE: U256 = (SWAP_FEE * N) / (4 * (N - 1));
+    pub const FEE_DENOMINATOR: U256 = U256([1000000, 0, 0, 0]);
     /// 1 share = 1e18, 18 decimals
-    pub const DECIMALS: u128 = 18;
+    pub const DECIMALS: U256 = U256([18, 0, 0, 0]);
 
     #[ink(storage)]
     #[derive(Default, Storage)]
@@ -5,11 +5,13 @@ pub use crate::{
     impls,
     traits::*,
 };
+pub use ink::prelude::vec::*;
 use openbrush::traits::Storage;
 pub use openbrush::{
     storage::Mapping,
     traits::AccountId,
 };
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
@@ -26,7 +28,7 @@ where
     T: AccessControl,
     F: FnOnce(&mut T) -> Result<R, Error>,
 {
-    instance._check_role(role)?;
+    instance._check_role_bytes_32(role)?;
     body(instance);
 }
 
@@ -70,7 +72,7 @@ impl<T: Storage<Data>> AccessControl for T {
     /// May emit a {RoleGranted} event.
     fn grant_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
         let __role: [u8; 32] = self.get_role_admin(role)?;
-        self._check_role(role)?;
+        self._check_role_bytes_32(role)?;
         self._grant_role(role, account)?;
         Ok(())
     }
@@ -86,7 +88,7 @@ impl<T: Storage<Data>> AccessControl for T {
     /// May emit a {RoleRevoked} event.
     fn revoke_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
         let __role: [u8; 32] = self.get_role_admin(role)?;
-        self._check_role(role)?;
+        self._check_role_bytes_32(role)?;
         self._revoke_role(role, account)?;
         Ok(())
     }
@@ -124,14 +126,15 @@ pub trait Internal {
     /// Format of the revert message is described in {_checkRole}.
     ///
     /// _Available since v4.6._
-    fn _check_role(&self, role: [u8; 32]) -> Result<(), Error>;
+    fn _check_role_bytes_32(&self, role: [u8; 32]) -> Result<(), Error>;
 
     /// @dev Revert with a standard message if `account` is missing `role`.
     ///
     /// The format of the revert reason is given by the following regular expression:
     ///
     ///  /^AccessControl: account (0x[0-9a-f]{40}) is missing role (0x[0-9a-f]{64})$/
-    fn _check_role(&self, role: [u8; 32], account: AccountId) -> Result<(), Error>;
+    fn _check_role_bytes_32_address(&self, role: [u8; 32], account: AccountId)
+        -> Result<(), Error>;
 
     /// @dev Grants `role` to `account`.
     ///
@@ -181,8 +184,8 @@ impl<T: Storage<Data>> Internal for T {
     /// Format of the revert message is described in {_checkRole}.
     ///
     /// _Available since v4.6._
-    default fn _check_role(&self, role: [u8; 32]) -> Result<(), Error> {
-        self._check_role(role, msg_sender()?)?;
+    default fn _check_role_bytes_32(&self, role: [u8; 32]) -> Result<(), Error> {
+        self._check_role_bytes_32_address(role, msg_sender()?)?;
         Ok(())
     }
 
@@ -191,9 +194,21 @@ impl<T: Storage<Data>> Internal for T {
     /// The format of the revert reason is given by the following regular expression:
     ///
     ///  /^AccessControl: account (0x[0-9a-f]{40}) is missing role (0x[0-9a-f]{64})$/
-    default fn _check_role(&self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
+    default fn _check_role_bytes_32_address(
+        &self,
+        role: [u8; 32],
+        account: AccountId,
+    ) -> Result<(), Error> {
         if !self.has_role(role, account)? {
-            return Err(Error::Custom(String::from("_")))
+            return Err(Error::Custom(<String>::from(
+                [
+                    "AccessControl: account ".as_bytes().to_vec(),
+                    AsRef::<[u8]>::as_ref(&strings.to_hex_string(account)?).to_vec(),
+                    " is missing role ".as_bytes().to_vec(),
+                    AsRef::<[u8]>::as_ref(&strings.to_hex_string(U256::from(role), 32)?).to_vec(),
+                ]
+                .concat(),
+            )));
         }
         Ok(())
     }
@@ -230,7 +245,9 @@ impl<T: Storage<Data>> Internal for T {
         admin_role: [u8; 32],
     ) -> Result<(), Error> {
         let mut previous_admin_role: [u8; 32] = self.get_role_admin(role)?;
-        self.data().roles.get(&role).unwrap_or_default().admin_role = admin_role;
+        let mut roles_entry: RoleData = self.data().roles.get(&role).unwrap_or_default();
+        roles_entry.admin_role = admin_role;
+        self.data().roles.insert(&(role), &(roles_entry));
         self._emit_role_admin_changed(role, previous_admin_role, admin_role);
         Ok(())
     }
@@ -242,12 +259,9 @@ impl<T: Storage<Data>> Internal for T {
     /// May emit a {RoleGranted} event.
     default fn _grant_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
         if !self.has_role(role, account)? {
-            self.data()
-                .roles
-                .get(&role)
-                .unwrap_or_default()
-                .members
-                .insert(&(account), &(true));
+            let mut roles_entry: RoleData = self.data().roles.get(&role).unwrap_or_default();
+            roles_entry.members.insert(&(account), &(true));
+            self.data().roles.insert(&(role), &(roles_entry));
             self._emit_role_granted(role, account, msg_sender()?);
         }
         Ok(())
@@ -260,12 +274,9 @@ impl<T: Storage<Data>> Internal for T {
     /// May emit a {RoleRevoked} event.
     default fn _revoke_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
         if self.has_role(role, account)? {
-            self.data()
-                .roles
-                .get(&role)
-                .unwrap_or_default()
-                .members
-                .insert(&(account), &(false));
+            let mut roles_entry: RoleData = self.data().roles.get(&role).unwrap_or_default();
+            roles_entry.members.insert(&(account), &(false));
+            self.data().roles.insert(&(role), &(roles_entry));
             self._emit_role_revoked(role, account, msg_sender()?);
         }
         Ok(())
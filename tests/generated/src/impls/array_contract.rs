@@ -7,15 +7,16 @@ pub use crate::{
 };
 pub use openbrush::storage::Mapping;
 use openbrush::traits::Storage;
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
 #[derive(Default, Debug)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
-    pub storage_mapping: Mapping<u128, u128>,
-    pub storage_f_array: Vec<u128>,
-    pub storage_d_array: Vec<u128>,
+    pub storage_mapping: Mapping<U256, U256>,
+    pub storage_f_array: [U256; 13],
+    pub storage_d_array: Vec<U256>,
     pub storage_d_struct_array: Vec<NestedTestStruct>,
     pub _reserved: Option<()>,
 }
@@ -26,44 +27,59 @@ impl<T: Storage<Data>> ArrayContract for T {}
 pub trait Internal {
     fn _work_with_arrays(
         &self,
-        element: u128,
-        f_array: Vec<u8>,
-        d_array: Vec<u8>,
-    ) -> Result<Vec<u128>, Error>;
+        element: U256,
+        f_array: &[u8; 13],
+        d_array: &[u8],
+    ) -> Result<Vec<U256>, Error>;
 
 }
 
 impl<T: Storage<Data>> Internal for T {
     default fn _work_with_arrays(
         &self,
-        element: u128,
-        f_array: Vec<u8>,
-        d_array: Vec<u8>,
-    ) -> Result<Vec<u128>, Error> {
+        element: U256,
+        f_array: &[u8; 13],
+        d_array: &[u8],
+    ) -> Result<Vec<U256>, Error> {
         f_array[1] = 0;
         d_array[1] = element;
+        let mut function_f_array: [u8; 13] = Default::default();
         function_f_array[1] = 0;
-        let mut function_d_array: Vec<u128> = vec![u128::default(); 1];
-        function_d_array[self.data().storage_f_array.length] = element;
-        self.data().storage_f_array[1] = 0;
-        self.data().storage_d_array[self.data().storage_f_array.length] = 0;
-        self.data().storage_mapping.insert(&(1), &(element));
-        function_d_array[1 + element] = self.data().storage_f_array[0];
-        function_d_array[1 / element] = self.data().storage_d_array[1 + element];
+        let mut function_d_array: Vec<U256> = vec![U256::default(); 1];
+        function_d_array[13] = element;
+        self.data().storage_f_array[1] = U256::from(0);
+        self.data().storage_d_array[13] = U256::from(0);
+        self.data()
+            .storage_mapping
+            .insert(&(U256::from(1)), &(element));
+        function_d_array[U256::from(1)
+            .checked_add(element)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+            .as_usize()] = self.data().storage_f_array[0];
+        function_d_array[(U256::from(1) / element).as_usize()] = self.data().storage_d_array
+            [U256::from(1)
+                .checked_add(element)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                .as_usize()];
         element = self
             .data()
             .storage_mapping
-            .get(&element + 1)
+            .get(
+                &element
+                    .checked_add(U256::from(1))
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+            )
             .unwrap_or_default();
         self.data().storage_d_struct_array[1]
             .test_struct
             .struct_f_array[1] = 0;
         self.data().storage_d_struct_array[1]
             .test_struct
-            .struct_d_array[1] = 0;
+            .struct_d_array[1] = U256::from(0);
         self.data().storage_d_struct_array[1]
             .test_struct
-            .struct_mapping[1] = 0;
+            .struct_mapping
+            .insert(&(U256::from(1)), &(U256::from(0)));
         element = self.data().storage_d_struct_array[1]
             .test_struct
             .struct_f_array[1];
@@ -72,32 +88,49 @@ impl<T: Storage<Data>> Internal for T {
             .struct_d_array[1];
         element = self.data().storage_d_struct_array[1]
             .test_struct
-            .struct_mapping[1];
+            .struct_mapping
+            .get(&U256::from(1))
+            .unwrap_or_default();
+        let mut test_struct: TestStruct = Default::default();
         test_struct.struct_f_array[1] = element;
-        test_struct.struct_d_array[1] = d_array.length;
-        test_struct.struct_mapping[1] = element;
+        test_struct.struct_d_array[1] = U256::from(d_array.len());
+        test_struct
+            .struct_mapping
+            .insert(&(U256::from(1)), &(element));
+        let mut nested_test_struct: NestedTestStruct = Default::default();
         nested_test_struct.test_struct.struct_f_array[1] = element;
-        nested_test_struct.test_struct.struct_d_array[1] = 0;
-        nested_test_struct.test_struct.struct_mapping[1] = element;
+        nested_test_struct.test_struct.struct_d_array[1] = U256::from(0);
+        nested_test_struct
+            .test_struct
+            .struct_mapping
+            .insert(&(U256::from(1)), &(element));
         function_d_array[1] = test_struct.struct_f_array[1];
         function_d_array[1] = nested_test_struct.test_struct.struct_d_array[1];
-        element = test_struct.struct_mapping[1];
-        function_d_array.push(1)?;
-        self.data().storage_d_array.push(element)?;
+        element = test_struct
+            .struct_mapping
+            .get(&U256::from(1))
+            .unwrap_or_default();
+        function_d_array.push(U256::from(1));
+        self.data().storage_d_array.push(element);
         nested_test_struct.test_struct.struct_d_array[1].push(element)?;
         self.data().storage_d_struct_array[1]
             .test_struct
             .struct_d_array[1]
             .push(1)?;
-        function_d_array.pop()?;
-        self.data().storage_d_array.pop()?;
+        function_d_array
+            .pop()
+            .ok_or(Error::Custom(String::from("Pop from empty array")))?;
+        self.data()
+            .storage_d_array
+            .pop()
+            .ok_or(Error::Custom(String::from("Pop from empty array")))?;
         nested_test_struct.test_struct.struct_d_array[1].pop()?;
         self.data().storage_d_struct_array[1]
             .test_struct
             .struct_d_array[1]
             .pop()?;
-        self.data().storage_mapping.remove(&(1));
-        // Deletion of storage member
+        self.data().storage_mapping.remove(&(U256::from(1)));
+        test_struct.struct_mapping.remove(&(U256::from(1)));
         return Ok(function_d_array)
     }
 
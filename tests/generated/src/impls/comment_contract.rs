@@ -14,6 +14,7 @@ pub use openbrush::{
         String,
     },
 };
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
@@ -38,8 +39,8 @@ pub trait Internal {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -53,8 +54,8 @@ impl<T: Storage<Data>> Internal for T {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         ids = 5;
@@ -66,11 +67,24 @@ impl<T: Storage<Data>> Internal for T {
             ids = 0;
         }
         if to.is_contract()? {
-            if ierc_1155_receiver(to)?
-                .on_erc_1155_batch_received(operator, from, ids, amounts, data)?
-                .is_err()
+            match ierc_1155_receiver(to)?
+                .on_erc_1155_batch_received(operator, from, ids, amounts, data)
             {
-                return Err(Error::Custom("Try failed"))
+                Ok(response) => {
+                    if response != ierc_1155_receiver.on_erc_1155_batch_received.selector {
+                        return Err(Error::Custom(String::from(
+                            "ERC1155: ERC1155Receiver rejected tokens",
+                        )));
+                    }
+                }
+                Err(Error::Custom(reason)) => {
+                    return Err(Error::Custom(reason));
+                }
+                Err(_) => {
+                    return Err(Error::Custom(String::from(
+                        "ERC1155: transfer to non-ERC1155Receiver implementer",
+                    )));
+                }
             }
         }
         Ok(())
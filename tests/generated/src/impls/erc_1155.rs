@@ -3,6 +3,7 @@
 
 pub use crate::{
     impls,
+    libs,
     traits::*,
 };
 pub use ink::prelude::vec::*;
@@ -16,6 +17,7 @@ pub use openbrush::{
         ZERO_ADDRESS,
     },
 };
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
@@ -23,7 +25,7 @@ pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
     /// Mapping from token ID to account balances
-    pub balances: Mapping<(u128, AccountId), u128>,
+    pub balances: Mapping<(U256, AccountId), U256>,
     /// Mapping from account to operator approvals
     pub operator_approvals: Mapping<(AccountId, AccountId), bool>,
     /// Used as the URI for all token types by relying on ID substitution, e.g. https://token-cdn-domain/{id}.json
@@ -48,7 +50,7 @@ impl<T: Storage<Data>> ERC1155 for T {
     ///
     /// Clients calling this function must replace the `\{id\}` substring with the
     /// actual token type ID.
-    fn uri(&self, _: u128) -> Result<String, Error> {
+    fn uri(&self, _: U256) -> Result<String, Error> {
         return Ok(self.data().uri)
     }
 
@@ -57,7 +59,7 @@ impl<T: Storage<Data>> ERC1155 for T {
     /// Requirements:
     ///
     /// - `account` cannot be the zero address.
-    fn balance_of(&self, account: AccountId, id: u128) -> Result<u128, Error> {
+    fn balance_of(&self, account: AccountId, id: U256) -> Result<U256, Error> {
         if !(account != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from(
                 "ERC1155: address zero is not a valid owner",
@@ -74,18 +76,21 @@ impl<T: Storage<Data>> ERC1155 for T {
     fn balance_of_batch(
         &self,
         accounts: Vec<AccountId>,
-        ids: Vec<u128>,
-    ) -> Result<Vec<u128>, Error> {
-        if !(accounts.length == ids.length) {
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Error> {
+        if !(U256::from(accounts.len()) == U256::from(ids.len())) {
             return Err(Error::Custom(String::from(
                 "ERC1155: accounts and ids length mismatch",
             )))
         };
-        let mut batch_balances: Vec<u128> = vec![u128::default(); accounts.length];
-        let mut i: u128 = 0;
-        while i < accounts.length {
-            batch_balances[i] = self.balance_of(accounts[i], ids[i])?;
-            i += 1;
+        let mut batch_balances: Vec<U256> = vec![U256::default(); accounts.len()];
+        let mut i: U256 = U256::from(0);
+        while i < U256::from(accounts.len()) {
+            batch_balances[i.as_usize()] =
+                self.balance_of(accounts[i.as_usize()], ids[i.as_usize()])?;
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
         return Ok(batch_balances)
     }
@@ -110,8 +115,8 @@ impl<T: Storage<Data>> ERC1155 for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        id: u128,
-        amount: u128,
+        id: U256,
+        amount: U256,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         if !(from == msg_sender()? || self.is_approved_for_all(from, msg_sender()?)?) {
@@ -128,8 +133,8 @@ impl<T: Storage<Data>> ERC1155 for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         if !(from == msg_sender()? || self.is_approved_for_all(from, msg_sender()?)?) {
@@ -158,8 +163,8 @@ pub trait Internal {
         &mut self,
         from: AccountId,
         to: AccountId,
-        id: u128,
-        amount: u128,
+        id: U256,
+        amount: U256,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -175,8 +180,8 @@ pub trait Internal {
         &mut self,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -208,7 +213,7 @@ pub trait Internal {
     /// - `to` cannot be the zero address.
     /// - If `to` refers to a smart contract, it must implement {IERC1155Receiver-onERC1155Received} and return the
     /// acceptance magic value.
-    fn _mint(&mut self, to: AccountId, id: u128, amount: u128, data: Vec<u8>) -> Result<(), Error>;
+    fn _mint(&mut self, to: AccountId, id: U256, amount: U256, data: Vec<u8>) -> Result<(), Error>;
 
     /// @dev xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {_mint}.
     ///
@@ -222,8 +227,8 @@ pub trait Internal {
     fn _mint_batch(
         &mut self,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -235,7 +240,7 @@ pub trait Internal {
     ///
     /// - `from` cannot be the zero address.
     /// - `from` must have at least `amount` tokens of token type `id`.
-    fn _burn(&mut self, from: AccountId, id: u128, amount: u128) -> Result<(), Error>;
+    fn _burn(&mut self, from: AccountId, id: U256, amount: U256) -> Result<(), Error>;
 
     /// @dev xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {_burn}.
     ///
@@ -247,8 +252,8 @@ pub trait Internal {
     fn _burn_batch(
         &mut self,
         from: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
     ) -> Result<(), Error>;
 
     /// @dev Approve `operator` to operate on all of `owner` tokens
@@ -284,8 +289,8 @@ pub trait Internal {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -312,8 +317,8 @@ pub trait Internal {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -322,8 +327,8 @@ pub trait Internal {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        id: u128,
-        amount: u128,
+        id: U256,
+        amount: U256,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -332,12 +337,12 @@ pub trait Internal {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
-    fn _as_singleton_array(&self, element: u128) -> Result<Vec<u128>, Error>;
+    fn _as_singleton_array(&self, element: U256) -> Result<Vec<U256>, Error>;
 
 }
 
@@ -356,8 +361,8 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        id: u128,
-        amount: u128,
+        id: U256,
+        amount: U256,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         if !(to != ZERO_ADDRESS.into()) {
@@ -366,10 +371,10 @@ impl<T: Storage<Data>> Internal for T {
             )))
         };
         let mut operator: AccountId = msg_sender()?;
-        let mut ids: Vec<u128> = self._as_singleton_array(id)?;
-        let mut amounts: Vec<u128> = self._as_singleton_array(amount)?;
+        let mut ids: Vec<U256> = self._as_singleton_array(id)?;
+        let mut amounts: Vec<U256> = self._as_singleton_array(amount)?;
         self._before_token_transfer(operator, from, to, ids, amounts, data)?;
-        let mut from_balance: u128 = self.data().balances.get(&(id, from)).unwrap_or_default();
+        let mut from_balance: U256 = self.data().balances.get(&(id, from)).unwrap_or_default();
         if !(from_balance >= amount) {
             return Err(Error::Custom(String::from(
                 "ERC1155: insufficient balance for transfer",
@@ -377,9 +382,17 @@ impl<T: Storage<Data>> Internal for T {
         };
         self.data()
             .balances
-            .insert(&(id, from), &(from_balance - amount));
-        let new_value = self.data().balances.get(&(id, to)).unwrap_or_default() + amount;
-        self.data().balances.insert(&(id, to), &new_value);
+            .insert(&(id, from), &(from_balance.overflowing_sub(amount).0));
+        self.data().balances.insert(
+            &(id, to),
+            &(self
+                .data()
+                .balances
+                .get(&(id, to))
+                .unwrap_or_default()
+                .checked_add(amount)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+        );
         self._emit_transfer_single(operator, from, to, id, amount);
         self._after_token_transfer(operator, from, to, ids, amounts, data)?;
         self._do_safe_transfer_acceptance_check(operator, from, to, id, amount, data)?;
@@ -398,11 +411,11 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error> {
-        if !(ids.length == amounts.length) {
+        if !(U256::from(ids.len()) == U256::from(amounts.len())) {
             return Err(Error::Custom(String::from(
                 "ERC1155: ids and amounts length mismatch",
             )))
@@ -414,11 +427,11 @@ impl<T: Storage<Data>> Internal for T {
         };
         let mut operator: AccountId = msg_sender()?;
         self._before_token_transfer(operator, from, to, ids, amounts, data)?;
-        let mut i: u128 = 0;
-        while i < ids.length {
-            let mut id: u128 = ids[i];
-            let mut amount: u128 = amounts[i];
-            let mut from_balance: u128 = self.data().balances.get(&(id, from)).unwrap_or_default();
+        let mut i: U256 = U256::from(0);
+        while i < U256::from(ids.len()) {
+            let mut id: U256 = ids[i.as_usize()];
+            let mut amount: U256 = amounts[i.as_usize()];
+            let mut from_balance: U256 = self.data().balances.get(&(id, from)).unwrap_or_default();
             if !(from_balance >= amount) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: insufficient balance for transfer",
@@ -426,10 +439,20 @@ impl<T: Storage<Data>> Internal for T {
             };
             self.data()
                 .balances
-                .insert(&(id, from), &(from_balance - amount));
-            let new_value = self.data().balances.get(&(id, to)).unwrap_or_default() + amount;
-            self.data().balances.insert(&(id, to), &new_value);
-            i += 1;
+                .insert(&(id, from), &(from_balance.overflowing_sub(amount).0));
+            self.data().balances.insert(
+                &(id, to),
+                &(self
+                    .data()
+                    .balances
+                    .get(&(id, to))
+                    .unwrap_or_default()
+                    .checked_add(amount)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+            );
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
         self._emit_transfer_batch(operator, from, to, ids, amounts);
         self._after_token_transfer(operator, from, to, ids, amounts, data)?;
@@ -471,8 +494,8 @@ impl<T: Storage<Data>> Internal for T {
     default fn _mint(
         &mut self,
         to: AccountId,
-        id: u128,
-        amount: u128,
+        id: U256,
+        amount: U256,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         if !(to != ZERO_ADDRESS.into()) {
@@ -481,11 +504,19 @@ impl<T: Storage<Data>> Internal for T {
             )))
         };
         let mut operator: AccountId = msg_sender()?;
-        let mut ids: Vec<u128> = self._as_singleton_array(id)?;
-        let mut amounts: Vec<u128> = self._as_singleton_array(amount)?;
+        let mut ids: Vec<U256> = self._as_singleton_array(id)?;
+        let mut amounts: Vec<U256> = self._as_singleton_array(amount)?;
         self._before_token_transfer(operator, ZERO_ADDRESS.into(), to, ids, amounts, data)?;
-        let new_value = self.data().balances.get(&(id, to)).unwrap_or_default() + amount;
-        self.data().balances.insert(&(id, to), &new_value);
+        self.data().balances.insert(
+            &(id, to),
+            &(self
+                .data()
+                .balances
+                .get(&(id, to))
+                .unwrap_or_default()
+                .checked_add(amount)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+        );
         self._emit_transfer_single(operator, ZERO_ADDRESS.into(), to, id, amount);
         self._after_token_transfer(operator, ZERO_ADDRESS.into(), to, ids, amounts, data)?;
         self._do_safe_transfer_acceptance_check(
@@ -511,8 +542,8 @@ impl<T: Storage<Data>> Internal for T {
     default fn _mint_batch(
         &mut self,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         if !(to != ZERO_ADDRESS.into()) {
@@ -520,19 +551,28 @@ impl<T: Storage<Data>> Internal for T {
                 "ERC1155: mint to the zero address",
             )))
         };
-        if !(ids.length == amounts.length) {
+        if !(U256::from(ids.len()) == U256::from(amounts.len())) {
             return Err(Error::Custom(String::from(
                 "ERC1155: ids and amounts length mismatch",
             )))
         };
         let mut operator: AccountId = msg_sender()?;
         self._before_token_transfer(operator, ZERO_ADDRESS.into(), to, ids, amounts, data)?;
-        let mut i: u128 = 0;
-        while i < ids.length {
-            let new_value =
-                self.data().balances.get(&(ids[i], to)).unwrap_or_default() + amounts[i];
-            self.data().balances.insert(&(ids[i], to), &new_value);
-            i += 1;
+        let mut i: U256 = U256::from(0);
+        while i < U256::from(ids.len()) {
+            self.data().balances.insert(
+                &(ids[i.as_usize()], to),
+                &(self
+                    .data()
+                    .balances
+                    .get(&(ids[i.as_usize()], to))
+                    .unwrap_or_default()
+                    .checked_add(amounts[i.as_usize()])
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+            );
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
         self._emit_transfer_batch(operator, ZERO_ADDRESS.into(), to, ids, amounts);
         self._after_token_transfer(operator, ZERO_ADDRESS.into(), to, ids, amounts, data)?;
@@ -555,17 +595,17 @@ impl<T: Storage<Data>> Internal for T {
     ///
     /// - `from` cannot be the zero address.
     /// - `from` must have at least `amount` tokens of token type `id`.
-    default fn _burn(&mut self, from: AccountId, id: u128, amount: u128) -> Result<(), Error> {
+    default fn _burn(&mut self, from: AccountId, id: U256, amount: U256) -> Result<(), Error> {
         if !(from != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from(
                 "ERC1155: burn from the zero address",
             )))
         };
         let mut operator: AccountId = msg_sender()?;
-        let mut ids: Vec<u128> = self._as_singleton_array(id)?;
-        let mut amounts: Vec<u128> = self._as_singleton_array(amount)?;
+        let mut ids: Vec<U256> = self._as_singleton_array(id)?;
+        let mut amounts: Vec<U256> = self._as_singleton_array(amount)?;
         self._before_token_transfer(operator, from, ZERO_ADDRESS.into(), ids, amounts, "")?;
-        let mut from_balance: u128 = self.data().balances.get(&(id, from)).unwrap_or_default();
+        let mut from_balance: U256 = self.data().balances.get(&(id, from)).unwrap_or_default();
         if !(from_balance >= amount) {
             return Err(Error::Custom(String::from(
                 "ERC1155: burn amount exceeds balance",
@@ -573,7 +613,7 @@ impl<T: Storage<Data>> Internal for T {
         };
         self.data()
             .balances
-            .insert(&(id, from), &(from_balance - amount));
+            .insert(&(id, from), &(from_balance.overflowing_sub(amount).0));
         self._emit_transfer_single(operator, from, ZERO_ADDRESS.into(), id, amount);
         self._after_token_transfer(operator, from, ZERO_ADDRESS.into(), ids, amounts, "")?;
         Ok(())
@@ -589,26 +629,26 @@ impl<T: Storage<Data>> Internal for T {
     default fn _burn_batch(
         &mut self,
         from: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
     ) -> Result<(), Error> {
         if !(from != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from(
                 "ERC1155: burn from the zero address",
             )))
         };
-        if !(ids.length == amounts.length) {
+        if !(U256::from(ids.len()) == U256::from(amounts.len())) {
             return Err(Error::Custom(String::from(
                 "ERC1155: ids and amounts length mismatch",
             )))
         };
         let mut operator: AccountId = msg_sender()?;
         self._before_token_transfer(operator, from, ZERO_ADDRESS.into(), ids, amounts, "")?;
-        let mut i: u128 = 0;
-        while i < ids.length {
-            let mut id: u128 = ids[i];
-            let mut amount: u128 = amounts[i];
-            let mut from_balance: u128 = self.data().balances.get(&(id, from)).unwrap_or_default();
+        let mut i: U256 = U256::from(0);
+        while i < U256::from(ids.len()) {
+            let mut id: U256 = ids[i.as_usize()];
+            let mut amount: U256 = amounts[i.as_usize()];
+            let mut from_balance: U256 = self.data().balances.get(&(id, from)).unwrap_or_default();
             if !(from_balance >= amount) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: burn amount exceeds balance",
@@ -616,8 +656,10 @@ impl<T: Storage<Data>> Internal for T {
             };
             self.data()
                 .balances
-                .insert(&(id, from), &(from_balance - amount));
-            i += 1;
+                .insert(&(id, from), &(from_balance.overflowing_sub(amount).0));
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
         self._emit_transfer_batch(operator, from, ZERO_ADDRESS.into(), ids, amounts);
         self._after_token_transfer(operator, from, ZERO_ADDRESS.into(), ids, amounts, "")?;
@@ -668,8 +710,8 @@ impl<T: Storage<Data>> Internal for T {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         Ok(())
@@ -698,8 +740,8 @@ impl<T: Storage<Data>> Internal for T {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         Ok(())
@@ -710,16 +752,27 @@ impl<T: Storage<Data>> Internal for T {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        id: u128,
-        amount: u128,
+        id: U256,
+        amount: U256,
         data: Vec<u8>,
     ) -> Result<(), Error> {
-        if to.is_contract()? {
-            if ierc_1155_receiver(to)?
-                .on_erc_1155_received(operator, from, id, amount, data)?
-                .is_err()
-            {
-                return Err(Error::Custom("Try failed"))
+        if libs::address::is_contract(to)? {
+            match ierc_1155_receiver(to)?.on_erc_1155_received(operator, from, id, amount, data) {
+                Ok(response) => {
+                    if response != ierc_1155_receiver.on_erc_1155_received.selector {
+                        return Err(Error::Custom(String::from(
+                            "ERC1155: ERC1155Receiver rejected tokens",
+                        )));
+                    }
+                }
+                Err(Error::Custom(reason)) => {
+                    return Err(Error::Custom(reason));
+                }
+                Err(_) => {
+                    return Err(Error::Custom(String::from(
+                        "ERC1155: transfer to non-ERC1155Receiver implementer",
+                    )));
+                }
             }
         }
         Ok(())
@@ -730,23 +783,36 @@ impl<T: Storage<Data>> Internal for T {
         operator: AccountId,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error> {
-        if to.is_contract()? {
-            if ierc_1155_receiver(to)?
-                .on_erc_1155_batch_received(operator, from, ids, amounts, data)?
-                .is_err()
+        if libs::address::is_contract(to)? {
+            match ierc_1155_receiver(to)?
+                .on_erc_1155_batch_received(operator, from, ids, amounts, data)
             {
-                return Err(Error::Custom("Try failed"))
+                Ok(response) => {
+                    if response != ierc_1155_receiver.on_erc_1155_batch_received.selector {
+                        return Err(Error::Custom(String::from(
+                            "ERC1155: ERC1155Receiver rejected tokens",
+                        )));
+                    }
+                }
+                Err(Error::Custom(reason)) => {
+                    return Err(Error::Custom(reason));
+                }
+                Err(_) => {
+                    return Err(Error::Custom(String::from(
+                        "ERC1155: transfer to non-ERC1155Receiver implementer",
+                    )));
+                }
             }
         }
         Ok(())
     }
 
-    default fn _as_singleton_array(&self, element: u128) -> Result<Vec<u128>, Error> {
-        let mut array: Vec<u128> = vec![u128::default(); 1];
+    default fn _as_singleton_array(&self, element: U256) -> Result<Vec<U256>, Error> {
+        let mut array: Vec<U256> = vec![U256::default(); 1];
         array[0] = element;
         return Ok(array)
     }
@@ -15,15 +15,16 @@ pub use openbrush::{
         ZERO_ADDRESS,
     },
 };
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
 #[derive(Default, Debug)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
-    pub balances: Mapping<AccountId, u128>,
-    pub allowances: Mapping<(AccountId, AccountId), u128>,
-    pub total_supply: u128,
+    pub balances: Mapping<AccountId, U256>,
+    pub allowances: Mapping<(AccountId, AccountId), U256>,
+    pub total_supply: U256,
     pub name: String,
     pub symbol: String,
     pub _reserved: Option<()>,
@@ -58,12 +59,12 @@ impl<T: Storage<Data>> ERC20 for T {
     }
 
     /// @dev See {IERC20-totalSupply}.
-    fn total_supply(&self) -> Result<u128, Error> {
+    fn total_supply(&self) -> Result<U256, Error> {
         return Ok(self.data().total_supply)
     }
 
     /// @dev See {IERC20-balanceOf}.
-    fn balance_of(&self, account: AccountId) -> Result<u128, Error> {
+    fn balance_of(&self, account: AccountId) -> Result<U256, Error> {
         return Ok(self.data().balances.get(&account).unwrap_or_default())
     }
 
@@ -73,14 +74,14 @@ impl<T: Storage<Data>> ERC20 for T {
     ///
     /// - `to` cannot be the zero address.
     /// - the caller must have a balance of at least `amount`.
-    fn transfer(&mut self, to: AccountId, amount: u128) -> Result<bool, Error> {
+    fn transfer(&mut self, to: AccountId, amount: U256) -> Result<bool, Error> {
         let mut owner: AccountId = Self::env().caller();
         self._transfer(owner, to, amount)?;
         return Ok(true)
     }
 
     /// @dev See {IERC20-allowance}.
-    fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<u128, Error> {
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<U256, Error> {
         return Ok(self
             .data()
             .allowances
@@ -96,7 +97,7 @@ impl<T: Storage<Data>> ERC20 for T {
     /// Requirements:
     ///
     /// - `spender` cannot be the zero address.
-    fn approve(&mut self, spender: AccountId, amount: u128) -> Result<bool, Error> {
+    fn approve(&mut self, spender: AccountId, amount: U256) -> Result<bool, Error> {
         let mut owner: AccountId = Self::env().caller();
         self._approve(owner, spender, amount)?;
         return Ok(true)
@@ -120,7 +121,7 @@ impl<T: Storage<Data>> ERC20 for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<bool, Error> {
         let mut spender: AccountId = Self::env().caller();
         self._spend_allowance(from, spender, amount)?;
@@ -138,12 +139,14 @@ impl<T: Storage<Data>> ERC20 for T {
     /// Requirements:
     ///
     /// - `spender` cannot be the zero address.
-    fn increase_allowance(&mut self, spender: AccountId, added_value: u128) -> Result<bool, Error> {
+    fn increase_allowance(&mut self, spender: AccountId, added_value: U256) -> Result<bool, Error> {
         let mut owner: AccountId = Self::env().caller();
         self._approve(
             owner,
             spender,
-            self.allowance(owner, spender)? + added_value,
+            self.allowance(owner, spender)?
+                .checked_add(added_value)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
         )?;
         return Ok(true)
     }
@@ -163,16 +166,20 @@ impl<T: Storage<Data>> ERC20 for T {
     fn decrease_allowance(
         &mut self,
         spender: AccountId,
-        subtracted_value: u128,
+        subtracted_value: U256,
     ) -> Result<bool, Error> {
         let mut owner: AccountId = Self::env().caller();
-        let mut current_allowance: u128 = self.allowance(owner, spender)?;
+        let mut current_allowance: U256 = self.allowance(owner, spender)?;
         if !(current_allowance >= subtracted_value) {
             return Err(Error::Custom(String::from(
                 "ERC20: decreased allowance below zero",
             )))
         };
-        self._approve(owner, spender, current_allowance - subtracted_value)?;
+        self._approve(
+            owner,
+            spender,
+            current_allowance.overflowing_sub(subtracted_value).0,
+        )?;
         return Ok(true)
     }
 
@@ -191,7 +198,7 @@ pub trait Internal {
     /// - `from` cannot be the zero address.
     /// - `to` cannot be the zero address.
     /// - `from` must have a balance of at least `amount`.
-    fn _transfer(&mut self, from: AccountId, to: AccountId, amount: u128) -> Result<(), Error>;
+    fn _transfer(&mut self, from: AccountId, to: AccountId, amount: U256) -> Result<(), Error>;
 
     /// Overflow not possible: the sum of all balances is capped by totalSupply, and the sum is preserved by
     /// decrementing then incrementing.
@@ -203,7 +210,7 @@ pub trait Internal {
     /// Requirements:
     ///
     /// - `account` cannot be the zero address.
-    fn _mint(&mut self, account: AccountId, amount: u128) -> Result<(), Error>;
+    fn _mint(&mut self, account: AccountId, amount: U256) -> Result<(), Error>;
 
     /// Overflow not possible: balance + amount is at most totalSupply + amount, which is checked above.
     /// @dev Destroys `amount` tokens from `account`, reducing the
@@ -215,7 +222,7 @@ pub trait Internal {
     ///
     /// - `account` cannot be the zero address.
     /// - `account` must have at least `amount` tokens.
-    fn _burn(&mut self, account: AccountId, amount: u128) -> Result<(), Error>;
+    fn _burn(&mut self, account: AccountId, amount: U256) -> Result<(), Error>;
 
     /// Overflow not possible: amount <= accountBalance <= totalSupply.
     /// @dev Sets `amount` as the allowance of `spender` over the `owner` s tokens.
@@ -229,7 +236,7 @@ pub trait Internal {
     ///
     /// - `owner` cannot be the zero address.
     /// - `spender` cannot be the zero address.
-    fn _approve(&mut self, owner: AccountId, spender: AccountId, amount: u128)
+    fn _approve(&mut self, owner: AccountId, spender: AccountId, amount: U256)
         -> Result<(), Error>;
 
     /// @dev Updates `owner` s allowance for `spender` based on spent `amount`.
@@ -242,7 +249,7 @@ pub trait Internal {
         &mut self,
         owner: AccountId,
         spender: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<(), Error>;
 
     /// @dev Hook that is called before any transfer of tokens. This includes
@@ -261,7 +268,7 @@ pub trait Internal {
         &mut self,
         from: AccountId,
         to: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<(), Error>;
 
     /// @dev Hook that is called after any transfer of tokens. This includes
@@ -280,12 +287,12 @@ pub trait Internal {
         &mut self,
         from: AccountId,
         to: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<(), Error>;
 
-    fn _emit_transfer(&self, from: AccountId, to: AccountId, value: u128);
+    fn _emit_transfer(&self, from: AccountId, to: AccountId, value: U256);
 
-    fn _emit_approval(&self, owner: AccountId, spender: AccountId, value: u128);
+    fn _emit_approval(&self, owner: AccountId, spender: AccountId, value: U256);
 
 }
 
@@ -306,7 +313,7 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<(), Error> {
         if !(from != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from(
@@ -319,7 +326,7 @@ impl<T: Storage<Data>> Internal for T {
             )))
         };
         self._before_token_transfer(from, to, amount)?;
-        let mut from_balance: u128 = self.data().balances.get(&from).unwrap_or_default();
+        let mut from_balance: U256 = self.data().balances.get(&from).unwrap_or_default();
         if !(from_balance >= amount) {
             return Err(Error::Custom(String::from(
                 "ERC20: transfer amount exceeds balance",
@@ -327,9 +334,17 @@ impl<T: Storage<Data>> Internal for T {
         };
         self.data()
             .balances
-            .insert(&(from), &(from_balance - amount));
-        let new_value = self.data().balances.get(&(to)).unwrap_or_default() + amount;
-        self.data().balances.insert(&(to), &new_value);
+            .insert(&(from), &(from_balance.overflowing_sub(amount).0));
+        self.data().balances.insert(
+            &(to),
+            &(self
+                .data()
+                .balances
+                .get(&to)
+                .unwrap_or_default()
+                .overflowing_add(amount)
+                .0),
+        );
         self._emit_transfer(from, to, amount);
         self._after_token_transfer(from, to, amount)?;
         Ok(())
@@ -345,16 +360,28 @@ impl<T: Storage<Data>> Internal for T {
     /// Requirements:
     ///
     /// - `account` cannot be the zero address.
-    default fn _mint(&mut self, account: AccountId, amount: u128) -> Result<(), Error> {
+    default fn _mint(&mut self, account: AccountId, amount: U256) -> Result<(), Error> {
         if !(account != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from(
                 "ERC20: mint to the zero address",
             )))
         };
         self._before_token_transfer(ZERO_ADDRESS.into(), account, amount)?;
-        self.data().total_supply += amount;
-        let new_value = self.data().balances.get(&(account)).unwrap_or_default() + amount;
-        self.data().balances.insert(&(account), &new_value);
+        self.data().total_supply = self
+            .data()
+            .total_supply
+            .checked_add(amount)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        self.data().balances.insert(
+            &(account),
+            &(self
+                .data()
+                .balances
+                .get(&account)
+                .unwrap_or_default()
+                .overflowing_add(amount)
+                .0),
+        );
         self._emit_transfer(ZERO_ADDRESS.into(), account, amount);
         self._after_token_transfer(ZERO_ADDRESS.into(), account, amount)?;
         Ok(())
@@ -370,14 +397,14 @@ impl<T: Storage<Data>> Internal for T {
     ///
     /// - `account` cannot be the zero address.
     /// - `account` must have at least `amount` tokens.
-    default fn _burn(&mut self, account: AccountId, amount: u128) -> Result<(), Error> {
+    default fn _burn(&mut self, account: AccountId, amount: U256) -> Result<(), Error> {
         if !(account != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from(
                 "ERC20: burn from the zero address",
             )))
         };
         self._before_token_transfer(account, ZERO_ADDRESS.into(), amount)?;
-        let mut account_balance: u128 = self.data().balances.get(&account).unwrap_or_default();
+        let mut account_balance: U256 = self.data().balances.get(&account).unwrap_or_default();
         if !(account_balance >= amount) {
             return Err(Error::Custom(String::from(
                 "ERC20: burn amount exceeds balance",
@@ -385,8 +412,8 @@ impl<T: Storage<Data>> Internal for T {
         };
         self.data()
             .balances
-            .insert(&(account), &(account_balance - amount));
-        self.data().total_supply -= amount;
+            .insert(&(account), &(account_balance.overflowing_sub(amount).0));
+        self.data().total_supply = self.data().total_supply.overflowing_sub(amount).0;
         self._emit_transfer(account, ZERO_ADDRESS.into(), amount);
         self._after_token_transfer(account, ZERO_ADDRESS.into(), amount)?;
         Ok(())
@@ -408,7 +435,7 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         owner: AccountId,
         spender: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<(), Error> {
         if !(owner != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from(
@@ -435,14 +462,14 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         owner: AccountId,
         spender: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<(), Error> {
-        let mut current_allowance: u128 = self.allowance(owner, spender)?;
-        if current_allowance != type_of(u128)?.max {
+        let mut current_allowance: U256 = self.allowance(owner, spender)?;
+        if current_allowance != U256::MAX {
             if !(current_allowance >= amount) {
                 return Err(Error::Custom(String::from("ERC20: insufficient allowance")))
             };
-            self._approve(owner, spender, current_allowance - amount)?;
+            self._approve(owner, spender, current_allowance.overflowing_sub(amount).0)?;
         }
         Ok(())
     }
@@ -463,7 +490,7 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<(), Error> {
         Ok(())
     }
@@ -484,13 +511,13 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<(), Error> {
         Ok(())
     }
 
-    default fn _emit_transfer(&self, _: AccountId, _: AccountId, _: u128) {}
+    default fn _emit_transfer(&self, _: AccountId, _: AccountId, _: U256) {}
 
-    default fn _emit_approval(&self, _: AccountId, _: AccountId, _: u128) {}
+    default fn _emit_approval(&self, _: AccountId, _: AccountId, _: U256) {}
 
 }
@@ -3,6 +3,7 @@
 
 pub use crate::{
     impls,
+    libs,
     traits::*,
 };
 pub use ink::prelude::vec::*;
@@ -16,6 +17,7 @@ pub use openbrush::{
         ZERO_ADDRESS,
     },
 };
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
@@ -27,11 +29,11 @@ pub struct Data {
     /// Token symbol
     pub symbol: String,
     /// Mapping from token ID to owner address
-    pub owners: Mapping<u128, AccountId>,
+    pub owners: Mapping<U256, AccountId>,
     /// Mapping owner address to token count
-    pub balances: Mapping<AccountId, u128>,
+    pub balances: Mapping<AccountId, U256>,
     /// Mapping from token ID to approved address
-    pub token_approvals: Mapping<u128, AccountId>,
+    pub token_approvals: Mapping<U256, AccountId>,
     /// Mapping from owner to operator approvals
     pub operator_approvals: Mapping<(AccountId, AccountId), bool>,
     pub _reserved: Option<()>,
@@ -47,7 +49,7 @@ impl<T: Storage<Data>> ERC721 for T {
     }
 
     /// @dev See {IERC721-balanceOf}.
-    fn balance_of(&self, owner: AccountId) -> Result<u128, Error> {
+    fn balance_of(&self, owner: AccountId) -> Result<U256, Error> {
         if !(owner != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from(
                 "ERC721: address zero is not a valid owner",
@@ -57,7 +59,7 @@ impl<T: Storage<Data>> ERC721 for T {
     }
 
     /// @dev See {IERC721-ownerOf}.
-    fn owner_of(&self, token_id: u128) -> Result<AccountId, Error> {
+    fn owner_of(&self, token_id: U256) -> Result<AccountId, Error> {
         let mut owner: AccountId = self._owner_of(token_id)?;
         if !(owner != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from("ERC721: invalid token ID")))
@@ -76,18 +78,26 @@ impl<T: Storage<Data>> ERC721 for T {
     }
 
     /// @dev See {IERC721Metadata-tokenURI}.
-    fn token_uri(&self, token_id: u128) -> Result<String, Error> {
+    fn token_uri(&self, token_id: U256) -> Result<String, Error> {
         self._require_minted(token_id)?;
         let mut base_uri: String = self._base_uri()?;
-        return Ok(if Vec::<u8>::from(base_uri).length > 0 {
-            <String>::from(abi.encode_packed(base_uri, token_id.to_string()?)?)
-        } else {
-            ""
-        })
+        return Ok(
+            if U256::from(Vec::<u8>::from(base_uri).len()) > U256::from(0) {
+                <String>::from(
+                    [
+                        base_uri.as_bytes().to_vec(),
+                        AsRef::<[u8]>::as_ref(&libs::strings::to_string(token_id)?).to_vec(),
+                    ]
+                    .concat(),
+                )
+            } else {
+                ""
+            },
+        )
     }
 
     /// @dev See {IERC721-approve}.
-    fn approve(&mut self, to: AccountId, token_id: u128) -> Result<(), Error> {
+    fn approve(&mut self, to: AccountId, token_id: U256) -> Result<(), Error> {
         let mut owner: AccountId = erc_721.owner_of(token_id)?;
         if !(to != owner) {
             return Err(Error::Custom(String::from(
@@ -104,7 +114,7 @@ impl<T: Storage<Data>> ERC721 for T {
     }
 
     /// @dev See {IERC721-getApproved}.
-    fn get_approved(&self, token_id: u128) -> Result<AccountId, Error> {
+    fn get_approved(&self, token_id: U256) -> Result<AccountId, Error> {
         self._require_minted(token_id)?;
         return Ok(self
             .data()
@@ -133,7 +143,7 @@ impl<T: Storage<Data>> ERC721 for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
     ) -> Result<(), Error> {
         if !(self._is_approved_or_owner(msg_sender()?, token_id)?) {
             return Err(Error::Custom(String::from(
@@ -146,22 +156,22 @@ impl<T: Storage<Data>> ERC721 for T {
 
     ///solhint-disable-next-line max-line-length
     /// @dev See {IERC721-safeTransferFrom}.
-    fn safe_transfer_from(
+    fn safe_transfer_from_address_address_uint_256(
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
     ) -> Result<(), Error> {
-        self.safe_transfer_from(from, to, token_id, "")?;
+        self.safe_transfer_from_address_address_uint_256_bytes(from, to, token_id, "")?;
         Ok(())
     }
 
     /// @dev See {IERC721-safeTransferFrom}.
-    fn safe_transfer_from(
+    fn safe_transfer_from_address_address_uint_256_bytes(
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         if !(self._is_approved_or_owner(msg_sender()?, token_id)?) {
@@ -201,12 +211,12 @@ pub trait Internal {
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
     /// @dev Returns the owner of the `tokenId`. Does NOT revert if token doesn't exist
-    fn _owner_of(&self, token_id: u128) -> Result<AccountId, Error>;
+    fn _owner_of(&self, token_id: U256) -> Result<AccountId, Error>;
 
     /// @dev Returns whether `tokenId` exists.
     ///
@@ -214,14 +224,14 @@ pub trait Internal {
     ///
     /// Tokens start existing when they are minted (`_mint`),
     /// and stop existing when they are burned (`_burn`).
-    fn _exists(&self, token_id: u128) -> Result<bool, Error>;
+    fn _exists(&self, token_id: U256) -> Result<bool, Error>;
 
     /// @dev Returns whether `spender` is allowed to manage `tokenId`.
     ///
     /// Requirements:
     ///
     /// - `tokenId` must exist.
-    fn _is_approved_or_owner(&self, spender: AccountId, token_id: u128) -> Result<bool, Error>;
+    fn _is_approved_or_owner(&self, spender: AccountId, token_id: U256) -> Result<bool, Error>;
 
     /// @dev Safely mints `tokenId` and transfers it to `to`.
     ///
@@ -231,11 +241,16 @@ pub trait Internal {
     /// - If `to` refers to a smart contract, it must implement {IERC721Receiver-onERC721Received}, which is called upon a safe transfer.
     ///
     /// Emits a {Transfer} event.
-    fn _safe_mint(&mut self, to: AccountId, token_id: u128) -> Result<(), Error>;
+    fn _safe_mint_address_uint_256(&mut self, to: AccountId, token_id: U256) -> Result<(), Error>;
 
     /// @dev Same as {xref-ERC721-_safeMint-address-uint256-}[`_safeMint`], with an additional `data` parameter which is
     /// forwarded in {IERC721Receiver-onERC721Received} to contract recipients.
-    fn _safe_mint(&mut self, to: AccountId, token_id: u128, data: Vec<u8>) -> Result<(), Error>;
+    fn _safe_mint_address_uint_256_bytes(
+        &mut self,
+        to: AccountId,
+        token_id: U256,
+        data: Vec<u8>,
+    ) -> Result<(), Error>;
 
     /// @dev Mints `tokenId` and transfers it to `to`.
     ///
@@ -247,7 +262,7 @@ pub trait Internal {
     /// - `to` cannot be the zero address.
     ///
     /// Emits a {Transfer} event.
-    fn _mint(&mut self, to: AccountId, token_id: u128) -> Result<(), Error>;
+    fn _mint(&mut self, to: AccountId, token_id: U256) -> Result<(), Error>;
 
     /// Check that tokenId was not minted by `_beforeTokenTransfer` hook
     /// Will not overflow unless all 2**256 token ids are minted to the same owner.
@@ -263,7 +278,7 @@ pub trait Internal {
     /// - `tokenId` must exist.
     ///
     /// Emits a {Transfer} event.
-    fn _burn(&mut self, token_id: u128) -> Result<(), Error>;
+    fn _burn(&mut self, token_id: U256) -> Result<(), Error>;
 
     /// Update ownership in case tokenId was transferred by `_beforeTokenTransfer` hook
     /// Clear approvals
@@ -278,7 +293,7 @@ pub trait Internal {
     /// - `tokenId` token must be owned by `from`.
     ///
     /// Emits a {Transfer} event.
-    fn _transfer(&mut self, from: AccountId, to: AccountId, token_id: u128) -> Result<(), Error>;
+    fn _transfer(&mut self, from: AccountId, to: AccountId, token_id: U256) -> Result<(), Error>;
 
     /// Check that tokenId was not transferred by `_beforeTokenTransfer` hook
     /// Clear approvals from the previous owner
@@ -290,7 +305,7 @@ pub trait Internal {
     /// @dev Approve `to` to operate on `tokenId`
     ///
     /// Emits an {Approval} event.
-    fn _approve(&mut self, to: AccountId, token_id: u128) -> Result<(), Error>;
+    fn _approve(&mut self, to: AccountId, token_id: U256) -> Result<(), Error>;
 
     /// @dev Approve `operator` to operate on all of `owner` tokens
     ///
@@ -303,7 +318,7 @@ pub trait Internal {
     ) -> Result<(), Error>;
 
     /// @dev Reverts if the `tokenId` has not been minted yet.
-    fn _require_minted(&self, token_id: u128) -> Result<(), Error>;
+    fn _require_minted(&self, token_id: U256) -> Result<(), Error>;
 
     /// @dev Internal function to invoke {IERC721Receiver-onERC721Received} on a target address.
     /// The call is not executed if the target address is not a contract.
@@ -317,7 +332,7 @@ pub trait Internal {
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
         data: Vec<u8>,
     ) -> Result<bool, Error>;
 
@@ -339,8 +354,8 @@ pub trait Internal {
         &mut self,
         from: AccountId,
         to: AccountId,
-        _: u128,
-        batch_size: u128,
+        _: U256,
+        batch_size: U256,
     ) -> Result<(), Error>;
 
     /// @dev Hook that is called after any token transfer. This includes minting and burning. If {ERC721Consecutive} is
@@ -359,8 +374,8 @@ pub trait Internal {
         &mut self,
         from: AccountId,
         to: AccountId,
-        first_token_id: u128,
-        batch_size: u128,
+        first_token_id: U256,
+        batch_size: U256,
     ) -> Result<(), Error>;
 
 }
@@ -393,7 +408,7 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         self._transfer(from, to, token_id)?;
@@ -406,7 +421,7 @@ impl<T: Storage<Data>> Internal for T {
     }
 
     /// @dev Returns the owner of the `tokenId`. Does NOT revert if token doesn't exist
-    default fn _owner_of(&self, token_id: u128) -> Result<AccountId, Error> {
+    default fn _owner_of(&self, token_id: U256) -> Result<AccountId, Error> {
         return Ok(self.data().owners.get(&token_id).unwrap_or_default())
     }
 
@@ -416,7 +431,7 @@ impl<T: Storage<Data>> Internal for T {
     ///
     /// Tokens start existing when they are minted (`_mint`),
     /// and stop existing when they are burned (`_burn`).
-    default fn _exists(&self, token_id: u128) -> Result<bool, Error> {
+    default fn _exists(&self, token_id: U256) -> Result<bool, Error> {
         return Ok(self._owner_of(token_id)? != ZERO_ADDRESS.into())
     }
 
@@ -428,7 +443,7 @@ impl<T: Storage<Data>> Internal for T {
     default fn _is_approved_or_owner(
         &self,
         spender: AccountId,
-        token_id: u128,
+        token_id: U256,
     ) -> Result<bool, Error> {
         let mut owner: AccountId = erc_721.owner_of(token_id)?;
         return Ok((spender == owner
@@ -444,17 +459,21 @@ impl<T: Storage<Data>> Internal for T {
     /// - If `to` refers to a smart contract, it must implement {IERC721Receiver-onERC721Received}, which is called upon a safe transfer.
     ///
     /// Emits a {Transfer} event.
-    default fn _safe_mint(&mut self, to: AccountId, token_id: u128) -> Result<(), Error> {
-        self._safe_mint(to, token_id, "")?;
+    default fn _safe_mint_address_uint_256(
+        &mut self,
+        to: AccountId,
+        token_id: U256,
+    ) -> Result<(), Error> {
+        self._safe_mint_address_uint_256_bytes(to, token_id, "")?;
         Ok(())
     }
 
     /// @dev Same as {xref-ERC721-_safeMint-address-uint256-}[`_safeMint`], with an additional `data` parameter which is
     /// forwarded in {IERC721Receiver-onERC721Received} to contract recipients.
-    default fn _safe_mint(
+    default fn _safe_mint_address_uint_256_bytes(
         &mut self,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
         data: Vec<u8>,
     ) -> Result<(), Error> {
         self._mint(to, token_id)?;
@@ -476,7 +495,7 @@ impl<T: Storage<Data>> Internal for T {
     /// - `to` cannot be the zero address.
     ///
     /// Emits a {Transfer} event.
-    default fn _mint(&mut self, to: AccountId, token_id: u128) -> Result<(), Error> {
+    default fn _mint(&mut self, to: AccountId, token_id: U256) -> Result<(), Error> {
         if !(to != ZERO_ADDRESS.into()) {
             return Err(Error::Custom(String::from(
                 "ERC721: mint to the zero address",
@@ -485,15 +504,23 @@ impl<T: Storage<Data>> Internal for T {
         if !(!self._exists(token_id)?) {
             return Err(Error::Custom(String::from("ERC721: token already minted")))
         };
-        self._before_token_transfer(ZERO_ADDRESS.into(), to, token_id, 1)?;
+        self._before_token_transfer(ZERO_ADDRESS.into(), to, token_id, U256::from(1))?;
         if !(!self._exists(token_id)?) {
             return Err(Error::Custom(String::from("ERC721: token already minted")))
         };
-        let new_value = self.data().balances.get(&(to)).unwrap_or_default() + 1;
-        self.data().balances.insert(&(to), &new_value);
+        self.data().balances.insert(
+            &(to),
+            &(self
+                .data()
+                .balances
+                .get(&to)
+                .unwrap_or_default()
+                .overflowing_add(U256::from(1))
+                .0),
+        );
         self.data().owners.insert(&(token_id), &(to));
         self._emit_transfer(ZERO_ADDRESS.into(), to, token_id);
-        self._after_token_transfer(ZERO_ADDRESS.into(), to, token_id, 1)?;
+        self._after_token_transfer(ZERO_ADDRESS.into(), to, token_id, U256::from(1))?;
         Ok(())
     }
 
@@ -511,16 +538,24 @@ impl<T: Storage<Data>> Internal for T {
     /// - `tokenId` must exist.
     ///
     /// Emits a {Transfer} event.
-    default fn _burn(&mut self, token_id: u128) -> Result<(), Error> {
+    default fn _burn(&mut self, token_id: U256) -> Result<(), Error> {
         let mut owner: AccountId = erc_721.owner_of(token_id)?;
-        self._before_token_transfer(owner, ZERO_ADDRESS.into(), token_id, 1)?;
+        self._before_token_transfer(owner, ZERO_ADDRESS.into(), token_id, U256::from(1))?;
         owner = erc_721.owner_of(token_id)?;
         self.data().token_approvals.remove(&(token_id));
-        let new_value = self.data().balances.get(&(owner)).unwrap_or_default() - 1;
-        self.data().balances.insert(&(owner), &new_value);
+        self.data().balances.insert(
+            &(owner),
+            &(self
+                .data()
+                .balances
+                .get(&owner)
+                .unwrap_or_default()
+                .overflowing_sub(U256::from(1))
+                .0),
+        );
         self.data().owners.remove(&(token_id));
         self._emit_transfer(owner, ZERO_ADDRESS.into(), token_id);
-        self._after_token_transfer(owner, ZERO_ADDRESS.into(), token_id, 1)?;
+        self._after_token_transfer(owner, ZERO_ADDRESS.into(), token_id, U256::from(1))?;
         Ok(())
     }
 
@@ -541,7 +576,7 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
     ) -> Result<(), Error> {
         if !(erc_721.owner_of(token_id)? == from) {
             return Err(Error::Custom(String::from(
@@ -553,20 +588,36 @@ impl<T: Storage<Data>> Internal for T {
                 "ERC721: transfer to the zero address",
             )))
         };
-        self._before_token_transfer(from, to, token_id, 1)?;
+        self._before_token_transfer(from, to, token_id, U256::from(1))?;
         if !(erc_721.owner_of(token_id)? == from) {
             return Err(Error::Custom(String::from(
                 "ERC721: transfer from incorrect owner",
             )))
         };
         self.data().token_approvals.remove(&(token_id));
-        let new_value = self.data().balances.get(&(from)).unwrap_or_default() - 1;
-        self.data().balances.insert(&(from), &new_value);
-        let new_value = self.data().balances.get(&(to)).unwrap_or_default() + 1;
-        self.data().balances.insert(&(to), &new_value);
+        self.data().balances.insert(
+            &(from),
+            &(self
+                .data()
+                .balances
+                .get(&from)
+                .unwrap_or_default()
+                .overflowing_sub(U256::from(1))
+                .0),
+        );
+        self.data().balances.insert(
+            &(to),
+            &(self
+                .data()
+                .balances
+                .get(&to)
+                .unwrap_or_default()
+                .overflowing_add(U256::from(1))
+                .0),
+        );
         self.data().owners.insert(&(token_id), &(to));
         self._emit_transfer(from, to, token_id);
-        self._after_token_transfer(from, to, token_id, 1)?;
+        self._after_token_transfer(from, to, token_id, U256::from(1))?;
         Ok(())
     }
 
@@ -580,7 +631,7 @@ impl<T: Storage<Data>> Internal for T {
     /// @dev Approve `to` to operate on `tokenId`
     ///
     /// Emits an {Approval} event.
-    default fn _approve(&mut self, to: AccountId, token_id: u128) -> Result<(), Error> {
+    default fn _approve(&mut self, to: AccountId, token_id: U256) -> Result<(), Error> {
         self.data().token_approvals.insert(&(token_id), &(to));
         self._emit_approval(erc_721.owner_of(token_id)?, to, token_id);
         Ok(())
@@ -606,7 +657,7 @@ impl<T: Storage<Data>> Internal for T {
     }
 
     /// @dev Reverts if the `tokenId` has not been minted yet.
-    default fn _require_minted(&self, token_id: u128) -> Result<(), Error> {
+    default fn _require_minted(&self, token_id: U256) -> Result<(), Error> {
         if !(self._exists(token_id)?) {
             return Err(Error::Custom(String::from("ERC721: invalid token ID")))
         };
@@ -625,15 +676,23 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
         data: Vec<u8>,
     ) -> Result<bool, Error> {
-        if to.is_contract()? {
-            if ierc_721_receiver(to)?
-                .on_erc_721_received(msg_sender()?, from, token_id, data)?
-                .is_err()
-            {
-                return Err(Error::Custom("Try failed"))
+        if libs::address::is_contract(to)? {
+            match ierc_721_receiver(to)?.on_erc_721_received(msg_sender()?, from, token_id, data) {
+                Ok(retval) => return Ok(retval == ierc_721_receiver.on_erc_721_received.selector),
+                Err(reason) => {
+                    let reason: Vec<u8> = scale::Encode::encode(&reason);
+                    if U256::from(reason.len()) == U256::from(0) {
+                        return Err(Error::Custom(String::from(
+                            "ERC721: transfer to non ERC721Receiver implementer",
+                        )));
+                    } else {
+                        return Err(<Error as scale::Decode>::decode(&mut &reason[..])
+                            .unwrap_or(Error::Custom(String::from("Reverted in assembly"))));
+                    }
+                }
             }
         } else {
             return Ok(true)
@@ -658,17 +717,33 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        _: u128,
-        batch_size: u128,
+        _: U256,
+        batch_size: U256,
     ) -> Result<(), Error> {
-        if batch_size > 1 {
+        if batch_size > U256::from(1) {
             if from != ZERO_ADDRESS.into() {
-                let new_value = self.data().balances.get(&(from)).unwrap_or_default() - batch_size;
-                self.data().balances.insert(&(from), &new_value);
+                self.data().balances.insert(
+                    &(from),
+                    &(self
+                        .data()
+                        .balances
+                        .get(&from)
+                        .unwrap_or_default()
+                        .checked_sub(batch_size)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+                );
             }
             if to != ZERO_ADDRESS.into() {
-                let new_value = self.data().balances.get(&(to)).unwrap_or_default() + batch_size;
-                self.data().balances.insert(&(to), &new_value);
+                self.data().balances.insert(
+                    &(to),
+                    &(self
+                        .data()
+                        .balances
+                        .get(&to)
+                        .unwrap_or_default()
+                        .checked_add(batch_size)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+                );
             }
         }
         Ok(())
@@ -690,8 +765,8 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         from: AccountId,
         to: AccountId,
-        first_token_id: u128,
-        batch_size: u128,
+        first_token_id: U256,
+        batch_size: U256,
     ) -> Result<(), Error> {
         Ok(())
     }
@@ -11,6 +11,7 @@ pub use openbrush::traits::{
     AccountIdExt,
     ZERO_ADDRESS,
 };
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
@@ -46,34 +47,54 @@ impl<T: Storage<Data>> example for T {
     /// int32 to uint32
     ///Convert celcius to fahrenheit
     fn celcius_2_fahrenheit(&self, celcius: i32) -> Result<i32, Error> {
-        let mut fahrenheit: i32 = celcius * 9 / 5 + 32;
+        let mut fahrenheit: i32 = (celcius
+            .checked_mul(9)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+            / 5)
+        .checked_add(32)
+        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         return Ok(fahrenheit)
     }
 
     ///Convert fahrenheit to celcius
     fn fahrenheit_2_celcius(&self, fahrenheit: i32) -> Result<i32, Error> {
-        return Ok((fahrenheit - 32) * 5 / 9)
+        return Ok((fahrenheit
+            .checked_sub(32)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+        .checked_mul(5)
+        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+            / 9)
     }
 
     ///is this number a power-of-two
-    fn is_power_of_2(&self, n: u128) -> Result<bool, Error> {
-        return Ok(n != 0 && (n & (n - 1)) == 0)
+    fn is_power_of_2(&self, n: U256) -> Result<bool, Error> {
+        return Ok(n != U256::from(0)
+            && (n
+                & (n.checked_sub(U256::from(1))
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?))
+                == U256::from(0))
     }
 
     ///calculate the population count (number of set bits) using Brian Kerningham's way
-    fn population_count(&self, n: u128) -> Result<u128, Error> {
+    fn population_count(&self, n: U256) -> Result<U256, Error> {
         let mut count = Default::default();
-        count = 0;
-        while n != 0 {
-            n &= (n - 1);
-            count += 1;
+        count = U256::from(0);
+        while n != U256::from(0) {
+            n &= (n
+                .checked_sub(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            count = count
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
         Ok(count)
     }
 
     ///calculate the power of base to exp
-    fn power(&self, base: u128, exp: u128) -> Result<u128, Error> {
-        return Ok(base.pow(exp))
+    fn power(&self, base: U256, exp: U256) -> Result<U256, Error> {
+        return Ok(base
+            .checked_pow(exp)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
     }
 
     ///returns true if the address is 0
@@ -98,10 +119,16 @@ impl<T: Storage<Data>> example for T {
     fn reap_processes(&mut self) -> Result<(), Error> {
         let mut n: u32 = 0;
         while n < 100 {
-            if self._get_pid_state(n)? == state.zombie {
-                self.data().reaped += 1;
+            if self._get_pid_state_uint_64(n)? == state.zombie {
+                self.data().reaped = self
+                    .data()
+                    .reaped
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
-            n += 1;
+            n = n
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
         Ok(())
     }
@@ -111,10 +138,16 @@ impl<T: Storage<Data>> example for T {
         let mut count: u16 = 0;
         let mut n: u32 = 0;
         loop {
-            if self._get_pid_state(n)? == state.waiting {
-                count += 1;
+            if self._get_pid_state_uint_64(n)? == state.waiting {
+                count = count
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
-            if !n += 1 < 1000 {
+            if !n = n
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                < 1000
+            {
                 break
             }
         }
@@ -161,7 +194,7 @@ impl<T: Storage<Data>> example for T {
 
 pub trait Internal {
     /// This mocks a pid state
-    fn _get_pid_state(&self, pid: u64) -> Result<State, Error>;
+    fn _get_pid_state_uint_64(&self, pid: u64) -> Result<State, Error>;
 
     ///Overloaded function with different return value!
     fn _get_pid_state(&self) -> Result<u32, Error>;
@@ -170,16 +203,20 @@ pub trait Internal {
 
 impl<T: Storage<Data>> Internal for T {
     /// This mocks a pid state
-    default fn _get_pid_state(&self, pid: u64) -> Result<State, Error> {
+    default fn _get_pid_state_uint_64(&self, pid: u64) -> Result<State, Error> {
         let mut n: u64 = 8;
         let mut i: u16 = 1;
         while i < 10 {
             if (i % 3) == 0 {
-                n *= pid / <u64>::from(i);
+                n = n
+                    .checked_mul(pid / <u64>::from(i))
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             } else {
                 n /= 3;
             }
-            i += 1;
+            i = i
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
         return Ok(state(n % <u64>::from(state.state_count))?)
     }
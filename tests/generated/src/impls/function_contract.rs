@@ -12,6 +12,7 @@ pub use openbrush::traits::{
     String,
     ZERO_ADDRESS,
 };
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
@@ -19,10 +20,10 @@ pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
     pub owner: AccountId,
-    pub x: u128,
+    pub x: U256,
     pub locked: bool,
     /// Can use array for output
-    pub arr: Vec<u128>,
+    pub arr: Vec<U256>,
     pub _reserved: Option<()>,
 }
 
@@ -74,75 +75,89 @@ impl<T: Storage<Data>> FunctionContract for T {
     }
 
     #[modifiers(no_reentrancy())]
-    fn decrement(&mut self, i: u128) -> Result<(), Error> {
-        self.data().x -= i;
-        if i > 1 {
-            self.decrement(i - 1)?;
+    fn decrement(&mut self, i: U256) -> Result<(), Error> {
+        self.data().x = self
+            .data()
+            .x
+            .checked_sub(i)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        if i > U256::from(1) {
+            self.decrement(
+                i.checked_sub(U256::from(1))
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+            )?;
         }
         Ok(())
     }
 
     /// Functions can return multiple values.
-    fn return_many(&self) -> Result<(u128, bool, u128), Error> {
-        return Ok((_, _, _))
+    fn return_many(&self) -> Result<(U256, bool, U256), Error> {
+        return Ok((U256::from(1), true, U256::from(2)))
     }
 
     /// Return values can be named.
-    fn named(&self) -> Result<(u128, bool, u128), Error> {
+    fn named(&self) -> Result<(U256, bool, U256), Error> {
         let mut x = Default::default();
         let mut b = Default::default();
         let mut y = Default::default();
-        return Ok((_, _, _))
+        return Ok((U256::from(1), true, U256::from(2)))
     }
 
     /// Return values can be assigned to their name.
     /// In this case the return statement can be omitted.
-    fn assigned(&self) -> Result<(u128, bool, u128), Error> {
+    fn assigned(&self) -> Result<(U256, bool, U256), Error> {
         let mut x = Default::default();
         let mut b = Default::default();
         let mut y = Default::default();
-        self.data().x = 1;
+        x = U256::from(1);
         b = true;
-        y = 2;
+        y = U256::from(2);
         Ok((x, b, y))
     }
 
     /// Use destructuring assignment when calling another
     /// function that returns multiple values.
-    fn destructuring_assignments(&self) -> Result<(u128, bool, u128, u128, u128), Error> {
-        (i, b, j) = self.return_many()?;
-        (x, _, y) = (_, _, _);
-        return Ok((_, _, _, _, _))
+    fn destructuring_assignments(&self) -> Result<(U256, bool, U256, U256, U256), Error> {
+        let (mut i, mut b, mut j): (U256, bool, U256) = self.return_many()?;
+        let (mut x, _, mut y): (U256, _, U256) = (4, 5, 6);
+        return Ok((i, b, j, x, y))
     }
 
     /// Values can be left out.
     /// Cannot use map for either input or output
     /// Can use array for input
-    fn array_input(&mut self, arr: Vec<u128>) -> Result<(), Error> {
+    fn array_input(&mut self, arr: Vec<U256>) -> Result<(), Error> {
         Ok(())
     }
 
-    fn array_output(&self) -> Result<Vec<u128>, Error> {
+    fn array_output(&self) -> Result<Vec<U256>, Error> {
         return Ok(self.data().arr)
     }
 
     /// Call function with key-value inputs
     fn some_func_with_many_inputs(
         &self,
-        x: u128,
-        y: u128,
-        z: u128,
+        x: U256,
+        y: U256,
+        z: U256,
         a: AccountId,
         b: bool,
         c: String,
-    ) -> Result<u128, Error> {
+    ) -> Result<U256, Error> {
     }
 
-    fn call_func(&self) -> Result<u128, Error> {
-        return Ok(self.some_func_with_many_inputs(1, 2, 3, ZERO_ADDRESS.into(), true, "c")?)
+    fn call_func(&self) -> Result<U256, Error> {
+        return Ok(self.some_func_with_many_inputs(
+            U256::from(1),
+            U256::from(2),
+            U256::from(3),
+            ZERO_ADDRESS.into(),
+            true,
+            "c",
+        )?)
     }
 
-    fn call_func_with_key_value(&self) -> Result<u128, Error> {
+    fn call_func_with_key_value(&self) -> Result<U256, Error> {
         return Ok(self.some_func_with_many_inputs(ZERO_ADDRESS.into(), true, "c", 1, 2, 3)?)
     }
 
@@ -150,7 +165,7 @@ impl<T: Storage<Data>> FunctionContract for T {
         self.data().owner
     }
 
-    fn x(&self) -> u128 {
+    fn x(&self) -> U256 {
         self.data().x
     }
 
@@ -158,7 +173,7 @@ impl<T: Storage<Data>> FunctionContract for T {
         self.data().locked
     }
 
-    fn arr(&self) -> Vec<u128> {
+    fn arr(&self) -> Vec<U256> {
         self.data().arr
     }
 
@@ -3,10 +3,12 @@
 
 pub use crate::{
     impls,
+    libs::i_256::I256,
     traits::*,
 };
 pub use openbrush::traits::AccountId;
 use openbrush::traits::Storage;
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
@@ -28,11 +30,17 @@ impl<T: Storage<Data>> primitives for T {
 
     fn op_i_64(&self, op: Oper, a: i64, b: i64) -> Result<i64, Error> {
         if op == oper.add {
-            return Ok(a + b)
+            return Ok(a
+                .checked_add(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.sub {
-            return Ok(a - b)
+            return Ok(a
+                .checked_sub(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.mul {
-            return Ok(a * b)
+            return Ok(a
+                .checked_mul(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.div {
             return Ok(a / b)
         } else if op == oper.modulo {
@@ -42,61 +50,83 @@ impl<T: Storage<Data>> primitives for T {
         } else if op == oper.shr {
             return Ok(a >> b)
         } else {
-            return Err(Error::Custom(String::from("_")))
+            return Err(Error::Custom(String::from("_")));
         }
     }
 
     fn op_u_64(&self, op: Oper, a: u64, b: u64) -> Result<u64, Error> {
         if op == oper.add {
-            return Ok(a + b)
+            return Ok(a
+                .checked_add(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.sub {
-            return Ok(a - b)
+            return Ok(a
+                .checked_sub(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.mul {
-            return Ok(a * b)
+            return Ok(a
+                .checked_mul(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.div {
             return Ok(a / b)
         } else if op == oper.modulo {
             return Ok(a % b)
         } else if op == oper.pow {
-            return Ok(a.pow(b))
+            return Ok(a
+                .checked_pow(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.shl {
             return Ok(a << b)
         } else if op == oper.shr {
             return Ok(a >> b)
         } else {
-            return Err(Error::Custom(String::from("_")))
+            return Err(Error::Custom(String::from("_")));
         }
     }
 
-    fn op_u_256(&self, op: Oper, a: u128, b: u128) -> Result<u128, Error> {
+    fn op_u_256(&self, op: Oper, a: U256, b: U256) -> Result<U256, Error> {
         if op == oper.add {
-            return Ok(a + b)
+            return Ok(a
+                .checked_add(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.sub {
-            return Ok(a - b)
+            return Ok(a
+                .checked_sub(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.mul {
-            return Ok(a * b)
+            return Ok(a
+                .checked_mul(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.div {
             return Ok(a / b)
         } else if op == oper.modulo {
             return Ok(a % b)
         } else if op == oper.pow {
-            return Ok(a.pow(<u128>::from(b)))
+            return Ok(a
+                .checked_pow(U256::from(b))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.shl {
             return Ok(a << b)
         } else if op == oper.shr {
             return Ok(a >> b)
         } else {
-            return Err(Error::Custom(String::from("_")))
+            return Err(Error::Custom(String::from("_")));
         }
     }
 
-    fn op_i_256(&self, op: Oper, a: i128, b: i128) -> Result<i128, Error> {
+    fn op_i_256(&self, op: Oper, a: I256, b: I256) -> Result<I256, Error> {
         if op == oper.add {
-            return Ok(a + b)
+            return Ok(a
+                .checked_add(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.sub {
-            return Ok(a - b)
+            return Ok(a
+                .checked_sub(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.mul {
-            return Ok(a * b)
+            return Ok(a
+                .checked_mul(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
         } else if op == oper.div {
             return Ok(a / b)
         } else if op == oper.modulo {
@@ -106,7 +136,7 @@ impl<T: Storage<Data>> primitives for T {
         } else if op == oper.shr {
             return Ok(a >> b)
         } else {
-            return Err(Error::Custom(String::from("_")))
+            return Err(Error::Custom(String::from("_")));
         }
     }
 
@@ -120,7 +150,7 @@ impl<T: Storage<Data>> primitives for T {
         } else if op == oper.shr {
             return Ok(a >> r)
         } else {
-            return Err(Error::Custom(String::from("_")))
+            return Err(Error::Custom(String::from("_")));
         }
     }
 
@@ -132,7 +162,7 @@ impl<T: Storage<Data>> primitives for T {
         } else if op == oper.xor {
             return Ok(a ^ b)
         } else {
-            return Err(Error::Custom(String::from("_")))
+            return Err(Error::Custom(String::from("_")));
         }
     }
 
@@ -142,7 +172,7 @@ impl<T: Storage<Data>> primitives for T {
         } else if op == oper.shr {
             return Ok(a >> r)
         } else {
-            return Err(Error::Custom(String::from("_")))
+            return Err(Error::Custom(String::from("_")));
         }
     }
 
@@ -154,7 +184,7 @@ impl<T: Storage<Data>> primitives for T {
         } else if op == oper.xor {
             return Ok(a ^ b)
         } else {
-            return Err(Error::Custom(String::from("_")))
+            return Err(Error::Custom(String::from("_")));
         }
     }
 
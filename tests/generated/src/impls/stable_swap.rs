@@ -3,6 +3,7 @@
 
 pub use crate::{
     impls,
+    libs,
     traits::*,
 };
 use openbrush::traits::Storage;
@@ -10,19 +11,20 @@ pub use openbrush::{
     storage::Mapping,
     traits::AccountId,
 };
+pub use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
 #[derive(Default, Debug)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
-    pub tokens: Vec<AccountId>,
+    pub tokens: [AccountId; 3],
     /// Normalize each token to 18 decimals
     /// Example - DAI (18 decimals), USDC (6 decimals), USDT (6 decimals)
-    pub multipliers: Vec<u128>,
-    pub balances: Vec<u128>,
-    pub total_supply: u128,
-    pub balance_of: Mapping<AccountId, u128>,
+    pub multipliers: [U256; 3],
+    pub balances: [U256; 3],
+    pub total_supply: U256,
+    pub balance_of: Mapping<AccountId, U256>,
     pub _reserved: Option<()>,
 }
 
@@ -32,11 +34,17 @@ impl<T: Storage<Data>> StableSwap for T {
     /// Initial guess, y <= d
     /// Estimate value of 1 share
     /// How many tokens is one share worth?
-    fn get_virtual_price(&self) -> Result<u128, Error> {
-        let mut d: u128 = self._get_d(self._xp()?)?;
-        let mut total_supply: u128 = self.data().total_supply;
-        if total_supply > 0 {
-            return Ok((d * 10.pow(DECIMALS)) / total_supply)
+    fn get_virtual_price(&self) -> Result<U256, Error> {
+        let mut d: U256 = self._get_d(self._xp()?)?;
+        let mut total_supply: U256 = self.data().total_supply;
+        if total_supply > U256::from(0) {
+            return Ok((d
+                .checked_mul(
+                    10.checked_pow(DECIMALS)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+                )
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                / total_supply)
         }
         return Ok(0)
     }
@@ -46,29 +54,48 @@ impl<T: Storage<Data>> StableSwap for T {
     /// @param j Index of token out
     /// @param dx Token in amount
     /// @param minDy Minimum token out
-    fn swap(&mut self, i: u128, j: u128, dx: u128, min_dy: u128) -> Result<u128, Error> {
+    fn swap(&mut self, i: U256, j: U256, dx: U256, min_dy: U256) -> Result<U256, Error> {
         let mut dy = Default::default();
         if !(i != j) {
             return Err(Error::Custom(String::from("i = j")))
         };
-        ierc_20(self.data().tokens[i])?.transfer_from(
+        ierc_20(self.data().tokens[i.as_usize()])?.transfer_from(
             Self::env().caller(),
             Self::env().account_id(),
             dx,
         )?;
-        let mut xp: Vec<u128> = self._xp()?;
-        let mut x: u128 = xp[i] + dx * self.data().multipliers[i];
-        let mut y_0: u128 = xp[j];
-        let mut y_1: u128 = self._get_y(i, j, x, xp)?;
-        dy = (y_0 - y_1 - 1) / self.data().multipliers[j];
-        let mut fee: u128 = (dy * SWAP_FEE) / FEE_DENOMINATOR;
-        dy -= fee;
+        let mut xp: [U256; 3] = self._xp()?;
+        let mut x: U256 = xp[i.as_usize()]
+            .checked_add(
+                dx.checked_mul(self.data().multipliers[i.as_usize()])
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+            )
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        let mut y_0: U256 = xp[j.as_usize()];
+        let mut y_1: U256 = self._get_y(i, j, x, xp)?;
+        dy = (y_0
+            .checked_sub(y_1)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+            .checked_sub(U256::from(1))
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+            / self.data().multipliers[j.as_usize()];
+        let mut fee: U256 = (dy
+            .checked_mul(SWAP_FEE)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+            / FEE_DENOMINATOR;
+        dy = dy
+            .checked_sub(fee)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         if !(dy >= min_dy) {
             return Err(Error::Custom(String::from("dy < min")))
         };
-        self.data().balances[i] += dx;
-        self.data().balances[j] -= dy;
-        ierc_20(self.data().tokens[j])?.transfer(Self::env().caller(), dy)?;
+        self.data().balances[i.as_usize()] = self.data().balances[i.as_usize()]
+            .checked_add(dx)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        self.data().balances[j.as_usize()] = self.data().balances[j.as_usize()]
+            .checked_sub(dy)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        ierc_20(self.data().tokens[j.as_usize()])?.transfer(Self::env().caller(), dy)?;
         Ok(dy)
     }
 
@@ -76,48 +103,83 @@ impl<T: Storage<Data>> StableSwap for T {
     /// y0 must be >= y1, since x has increased
     /// -1 to round down
     /// Subtract fee from dy
-    fn add_liquidity(&mut self, amounts: Vec<u128>, min_shares: u128) -> Result<u128, Error> {
+    fn add_liquidity(&mut self, amounts: [U256; 3], min_shares: U256) -> Result<U256, Error> {
         let mut shares = Default::default();
-        let mut total_supply: u128 = self.data().total_supply;
-        let mut old_xs: Vec<u128> = self._xp()?;
-        if total_supply > 0 {
+        let mut total_supply: U256 = self.data().total_supply;
+        let mut d_0: U256 = Default::default();
+        let mut old_xs: [U256; 3] = self._xp()?;
+        if total_supply > U256::from(0) {
             d_0 = self._get_d(old_xs)?;
-        };
+        }
+        let mut new_xs: [U256; 3] = Default::default();
+        let mut i: U256 = Default::default();
         while i < N {
-            let mut amount: u128 = amounts[i];
-            if amount > 0 {
-                ierc_20(self.data().tokens[i])?.transfer_from(
+            let mut amount: U256 = amounts[i.as_usize()];
+            if amount > U256::from(0) {
+                ierc_20(self.data().tokens[i.as_usize()])?.transfer_from(
                     Self::env().caller(),
                     Self::env().account_id(),
                     amount,
                 )?;
-                new_xs[i] = old_xs[i] + amount * self.data().multipliers[i];
+                new_xs[i.as_usize()] = old_xs[i.as_usize()]
+                    .checked_add(
+                        amount
+                            .checked_mul(self.data().multipliers[i.as_usize()])
+                            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+                    )
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             } else {
-                new_xs[i] = old_xs[i];
+                new_xs[i.as_usize()] = old_xs[i.as_usize()];
             }
-            i += 1;
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
-        let mut d_1: u128 = self._get_d(new_xs)?;
+        let mut d_1: U256 = self._get_d(new_xs)?;
         if !(d_1 > d_0) {
             return Err(Error::Custom(String::from("liquidity didn't increase")))
         };
-        if total_supply > 0 {
+        let mut d_2: U256 = Default::default();
+        if total_supply > U256::from(0) {
+            let mut i: U256 = Default::default();
             while i < N {
-                let mut ideal_balance: u128 = (old_xs[i] * d_1) / d_0;
-                let mut diff: u128 = math.abs(new_xs[i], ideal_balance)?;
-                new_xs[i] -= (LIQUIDITY_FEE * diff) / FEE_DENOMINATOR;
-                i += 1;
+                let mut ideal_balance: U256 = (old_xs[i.as_usize()]
+                    .checked_mul(d_1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                    / d_0;
+                let mut diff: U256 = libs::math::abs(new_xs[i.as_usize()], ideal_balance)?;
+                new_xs[i.as_usize()] = new_xs[i.as_usize()]
+                    .checked_sub(
+                        (LIQUIDITY_FEE
+                            .checked_mul(diff)
+                            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                            / FEE_DENOMINATOR,
+                    )
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+                i = i
+                    .checked_add(U256::from(1))
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
             d_2 = self._get_d(new_xs)?;
         } else {
             d_2 = d_1;
-        };
+        }
+        let mut i: U256 = Default::default();
         while i < N {
-            self.data().balances[i] += amounts[i];
-            i += 1;
+            self.data().balances[i.as_usize()] = self.data().balances[i.as_usize()]
+                .checked_add(amounts[i.as_usize()])
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
-        if total_supply > 0 {
-            shares = ((d_2 - d_0) * total_supply) / d_0;
+        if total_supply > U256::from(0) {
+            shares = ((d_2
+                .checked_sub(d_0)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+            .checked_mul(total_supply)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                / d_0;
         } else {
             shares = d_2;
         }
@@ -138,20 +200,29 @@ impl<T: Storage<Data>> StableSwap for T {
     /// d1 >= d2 >= d0
     fn remove_liquidity(
         &mut self,
-        shares: u128,
-        min_amounts_out: Vec<u128>,
-    ) -> Result<Vec<u128>, Error> {
+        shares: U256,
+        min_amounts_out: [U256; 3],
+    ) -> Result<[U256; 3], Error> {
         let mut amounts_out = Default::default();
-        let mut total_supply: u128 = self.data().total_supply;
+        let mut total_supply: U256 = self.data().total_supply;
+        let mut i: U256 = Default::default();
         while i < N {
-            let mut amount_out: u128 = (self.data().balances[i] * shares) / total_supply;
-            if !(amount_out >= min_amounts_out[i]) {
+            let mut amount_out: U256 = (self.data().balances[i.as_usize()]
+                .checked_mul(shares)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                / total_supply;
+            if !(amount_out >= min_amounts_out[i.as_usize()]) {
                 return Err(Error::Custom(String::from("out < min")))
             };
-            self.data().balances[i] -= amount_out;
-            amounts_out[i] = amount_out;
-            ierc_20(self.data().tokens[i])?.transfer(Self::env().caller(), amount_out)?;
-            i += 1;
+            self.data().balances[i.as_usize()] = self.data().balances[i.as_usize()]
+                .checked_sub(amount_out)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            amounts_out[i.as_usize()] = amount_out;
+            ierc_20(self.data().tokens[i.as_usize()])?
+                .transfer(Self::env().caller(), amount_out)?;
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
         self._burn(Self::env().caller(), shares)?;
         Ok(amounts_out)
@@ -164,7 +235,7 @@ impl<T: Storage<Data>> StableSwap for T {
     /// d1 / d0 <= 1
     /// Recalculate y with xp including imbalance fees
     /// - 1 to round down
-    fn calc_withdraw_one_token(&self, shares: u128, i: u128) -> Result<(u128, u128), Error> {
+    fn calc_withdraw_one_token(&self, shares: U256, i: U256) -> Result<(U256, U256), Error> {
         let mut dy = Default::default();
         let mut fee = Default::default();
         return Ok(self._calc_withdraw_one_token(shares, i)?)
@@ -176,52 +247,54 @@ impl<T: Storage<Data>> StableSwap for T {
     /// @param minAmountOut Minimum amount of token i that must be withdrawn
     fn remove_liquidity_one_token(
         &mut self,
-        shares: u128,
-        i: u128,
-        min_amount_out: u128,
-    ) -> Result<u128, Error> {
+        shares: U256,
+        i: U256,
+        min_amount_out: U256,
+    ) -> Result<U256, Error> {
         let mut amount_out = Default::default();
         (_, _) = self._calc_withdraw_one_token(shares, i)?;
         if !(amount_out >= min_amount_out) {
             return Err(Error::Custom(String::from("out < min")))
         };
-        self.data().balances[i] -= amount_out;
+        self.data().balances[i.as_usize()] = self.data().balances[i.as_usize()]
+            .checked_sub(amount_out)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         self._burn(Self::env().caller(), shares)?;
-        ierc_20(self.data().tokens[i])?.transfer(Self::env().caller(), amount_out)?;
+        ierc_20(self.data().tokens[i.as_usize()])?.transfer(Self::env().caller(), amount_out)?;
         Ok(amount_out)
     }
 
-    fn tokens(&self) -> Vec<AccountId> {
+    fn tokens(&self) -> [AccountId; 3] {
         self.data().tokens
     }
 
-    fn balances(&self) -> Vec<u128> {
+    fn balances(&self) -> [U256; 3] {
         self.data().balances
     }
 
-    fn total_supply(&self) -> u128 {
+    fn total_supply(&self) -> U256 {
         self.data().total_supply
     }
 
-    fn balance_of(&self) -> Mapping<AccountId, u128> {
+    fn balance_of(&self) -> Mapping<AccountId, U256> {
         self.data().balance_of
     }
 
 }
 
 pub trait Internal {
-    fn _mint(&mut self, to: AccountId, amount: u128) -> Result<(), Error>;
+    fn _mint(&mut self, to: AccountId, amount: U256) -> Result<(), Error>;
 
-    fn _burn(&mut self, from: AccountId, amount: u128) -> Result<(), Error>;
+    fn _burn(&mut self, from: AccountId, amount: U256) -> Result<(), Error>;
 
     /// Return precision-adjusted balances, adjusted to 18 decimals
-    fn _xp(&self) -> Result<Vec<u128>, Error>;
+    fn _xp(&self) -> Result<[U256; 3], Error>;
 
     /// @notice Calculate D, sum of balances in a perfectly balanced pool
     /// If balances of x_0, x_1, ... x_(n-1) then sum(x_i) = D
     /// @param xp Precision-adjusted balances
     /// @return D
-    fn _get_d(&self, xp: Vec<u128>) -> Result<u128, Error>;
+    fn _get_d(&self, xp: [U256; 3]) -> Result<U256, Error>;
 
     ///Newton's method to compute D
     ///        -----------------------------
@@ -245,7 +318,7 @@ pub trait Internal {
     /// @param j Index of token out
     /// @param x New balance of token i
     /// @param xp Current precision-adjusted balances
-    fn _get_y(&self, i: u128, j: u128, x: u128, xp: Vec<u128>) -> Result<u128, Error>;
+    fn _get_y(&self, i: U256, j: U256, x: U256, xp: [U256; 3]) -> Result<U256, Error>;
 
     ///Newton's method to compute y
     ///        -----------------------------
@@ -271,38 +344,67 @@ pub trait Internal {
     /// @param xp Precision-adjusted balances
     /// @param d Liquidity d
     /// @return New balance of token i
-    fn _get_yd(&self, i: u128, xp: Vec<u128>, d: u128) -> Result<u128, Error>;
+    fn _get_yd(&self, i: U256, xp: [U256; 3], d: U256) -> Result<U256, Error>;
 
     /// @notice Calculate amount of token i to receive for shares
     /// @param shares Shares to burn
     /// @param i Index of token to withdraw
     /// @return dy Amount of token i to receive
     ///         fee Fee for withdraw. Fee already included in dy
-    fn _calc_withdraw_one_token(&self, shares: u128, i: u128) -> Result<(u128, u128), Error>;
+    fn _calc_withdraw_one_token(&self, shares: U256, i: U256) -> Result<(U256, U256), Error>;
 
 }
 
 impl<T: Storage<Data>> Internal for T {
-    default fn _mint(&mut self, to: AccountId, amount: u128) -> Result<(), Error> {
-        let new_value = self.data().balance_of.get(&(to)).unwrap_or_default() + amount;
-        self.data().balance_of.insert(&(to), &new_value);
-        self.data().total_supply += amount;
+    default fn _mint(&mut self, to: AccountId, amount: U256) -> Result<(), Error> {
+        self.data().balance_of.insert(
+            &(to),
+            &(self
+                .data()
+                .balance_of
+                .get(&to)
+                .unwrap_or_default()
+                .checked_add(amount)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+        );
+        self.data().total_supply = self
+            .data()
+            .total_supply
+            .checked_add(amount)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         Ok(())
     }
 
-    default fn _burn(&mut self, from: AccountId, amount: u128) -> Result<(), Error> {
-        let new_value = self.data().balance_of.get(&(from)).unwrap_or_default() - amount;
-        self.data().balance_of.insert(&(from), &new_value);
-        self.data().total_supply -= amount;
+    default fn _burn(&mut self, from: AccountId, amount: U256) -> Result<(), Error> {
+        self.data().balance_of.insert(
+            &(from),
+            &(self
+                .data()
+                .balance_of
+                .get(&from)
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+        );
+        self.data().total_supply = self
+            .data()
+            .total_supply
+            .checked_sub(amount)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         Ok(())
     }
 
     /// Return precision-adjusted balances, adjusted to 18 decimals
-    default fn _xp(&self) -> Result<Vec<u128>, Error> {
+    default fn _xp(&self) -> Result<[U256; 3], Error> {
         let mut xp = Default::default();
+        let mut i: U256 = Default::default();
         while i < N {
-            xp[i] = self.data().balances[i] * self.data().multipliers[i];
-            i += 1;
+            xp[i.as_usize()] = self.data().balances[i.as_usize()]
+                .checked_mul(self.data().multipliers[i.as_usize()])
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
         Ok(xp)
     }
@@ -311,27 +413,67 @@ impl<T: Storage<Data>> Internal for T {
     /// If balances of x_0, x_1, ... x_(n-1) then sum(x_i) = D
     /// @param xp Precision-adjusted balances
     /// @return D
-    default fn _get_d(&self, xp: Vec<u128>) -> Result<u128, Error> {
-        let mut a: u128 = A * N;
+    default fn _get_d(&self, xp: [U256; 3]) -> Result<U256, Error> {
+        let mut a: U256 = A
+            .checked_mul(N)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        let mut s: U256 = Default::default();
+        let mut i: U256 = Default::default();
         while i < N {
-            s += xp[i];
-            i += 1;
+            s = s
+                .checked_add(xp[i.as_usize()])
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
-        let mut d: u128 = s;
-        while i < 255 {
-            let mut p: u128 = d;
+        let mut d: U256 = s;
+        let mut d_prev: U256 = Default::default();
+        let mut i: U256 = Default::default();
+        while i < U256::from(255) {
+            let mut p: U256 = d;
+            let mut j: U256 = Default::default();
             while j < N {
-                p = (p * d) / (N * xp[j]);
-                j += 1;
+                p = (p
+                    .checked_mul(d)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                    / (N.checked_mul(xp[j.as_usize()])
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+                j = j
+                    .checked_add(U256::from(1))
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
             d_prev = d;
-            d = ((a * s + N * p) * d) / ((a - 1) * d + (N + 1) * p);
-            if math.abs(d, d_prev)? <= 1 {
+            d = ((a
+                .checked_mul(s)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                .checked_add(
+                    N.checked_mul(p)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+                )
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+            .checked_mul(d)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                / ((a
+                    .checked_sub(U256::from(1))
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                .checked_mul(d)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                .checked_add(
+                    (N.checked_add(1)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                    .checked_mul(p)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+                )
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            if libs::math::abs(d, d_prev)? <= U256::from(1) {
                 return Ok(d)
             }
-            i += 1;
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
-        return Err(Error::Custom(String::from("_")))
+        return Err(Error::Custom(String::from("D didn't converge")))
     }
 
     ///Newton's method to compute D
@@ -356,34 +498,68 @@ impl<T: Storage<Data>> Internal for T {
     /// @param j Index of token out
     /// @param x New balance of token i
     /// @param xp Current precision-adjusted balances
-    default fn _get_y(&self, i: u128, j: u128, x: u128, xp: Vec<u128>) -> Result<u128, Error> {
-        let mut a: u128 = A * N;
-        let mut d: u128 = self._get_d(xp)?;
-        let mut c: u128 = d;
+    default fn _get_y(&self, i: U256, j: U256, x: U256, xp: [U256; 3]) -> Result<U256, Error> {
+        let mut a: U256 = A
+            .checked_mul(N)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        let mut d: U256 = self._get_d(xp)?;
+        let mut s: U256 = Default::default();
+        let mut c: U256 = d;
+        let mut x: U256 = Default::default();
+        let mut k: U256 = Default::default();
         while k < N {
             if k == i {
                 x = x;
             } else if k == j {
                 continue
             } else {
-                x = xp[k];
+                x = xp[k.as_usize()];
             }
-            s += x;
-            c = (c * d) / (N * x);
-            k += 1;
+            s = s
+                .checked_add(x)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            c = (c
+                .checked_mul(d)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                / (N.checked_mul(x)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            k = k
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
-        c = (c * d) / (N * a);
-        let mut b: u128 = s + d / a;
-        let mut y: u128 = d;
-        while i < 255 {
+        c = (c
+            .checked_mul(d)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+            / (N.checked_mul(a)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+        let mut b: U256 = s
+            .checked_add(d / a)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        let mut y_prev: U256 = Default::default();
+        let mut y: U256 = d;
+        let mut i: U256 = Default::default();
+        while i < U256::from(255) {
             y_prev = y;
-            y = (y * y + c) / (2 * y + b - d);
-            if math.abs(y, y_prev)? <= 1 {
+            y = (y
+                .checked_mul(y)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                .checked_add(c)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                / (U256::from(2)
+                    .checked_mul(y)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                    .checked_add(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                    .checked_sub(d)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            if libs::math::abs(y, y_prev)? <= U256::from(1) {
                 return Ok(y)
             }
-            i += 1;
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
-        return Err(Error::Custom(String::from("_")))
+        return Err(Error::Custom(String::from("y didn't converge")))
     }
 
     ///Newton's method to compute y
@@ -410,31 +586,65 @@ impl<T: Storage<Data>> Internal for T {
     /// @param xp Precision-adjusted balances
     /// @param d Liquidity d
     /// @return New balance of token i
-    default fn _get_yd(&self, i: u128, xp: Vec<u128>, d: u128) -> Result<u128, Error> {
-        let mut a: u128 = A * N;
-        let mut c: u128 = d;
+    default fn _get_yd(&self, i: U256, xp: [U256; 3], d: U256) -> Result<U256, Error> {
+        let mut a: U256 = A
+            .checked_mul(N)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        let mut s: U256 = Default::default();
+        let mut c: U256 = d;
+        let mut x: U256 = Default::default();
+        let mut k: U256 = Default::default();
         while k < N {
             if k != i {
-                x = xp[k];
+                x = xp[k.as_usize()];
             } else {
                 continue
             }
-            s += x;
-            c = (c * d) / (N * x);
-            k += 1;
+            s = s
+                .checked_add(x)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            c = (c
+                .checked_mul(d)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                / (N.checked_mul(x)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            k = k
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
-        c = (c * d) / (N * a);
-        let mut b: u128 = s + d / a;
-        let mut y: u128 = d;
-        while i < 255 {
+        c = (c
+            .checked_mul(d)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+            / (N.checked_mul(a)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+        let mut b: U256 = s
+            .checked_add(d / a)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        let mut y_prev: U256 = Default::default();
+        let mut y: U256 = d;
+        let mut i: U256 = Default::default();
+        while i < U256::from(255) {
             y_prev = y;
-            y = (y * y + c) / (2 * y + b - d);
-            if math.abs(y, y_prev)? <= 1 {
+            y = (y
+                .checked_mul(y)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                .checked_add(c)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                / (U256::from(2)
+                    .checked_mul(y)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                    .checked_add(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                    .checked_sub(d)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            if libs::math::abs(y, y_prev)? <= U256::from(1) {
                 return Ok(y)
             }
-            i += 1;
+            i = i
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
-        return Err(Error::Custom(String::from("_")))
+        return Err(Error::Custom(String::from("y didn't converge")))
     }
 
     /// @notice Calculate amount of token i to receive for shares
@@ -444,29 +654,68 @@ impl<T: Storage<Data>> Internal for T {
     ///         fee Fee for withdraw. Fee already included in dy
     default fn _calc_withdraw_one_token(
         &self,
-        shares: u128,
-        i: u128,
-    ) -> Result<(u128, u128), Error> {
+        shares: U256,
+        i: U256,
+    ) -> Result<(U256, U256), Error> {
         let mut dy = Default::default();
         let mut fee = Default::default();
-        let mut total_supply: u128 = self.data().total_supply;
-        let mut xp: Vec<u128> = self._xp()?;
-        let mut d_0: u128 = self._get_d(xp)?;
-        let mut d_1: u128 = d_0 - (d_0 * shares) / total_supply;
-        let mut y_0: u128 = self._get_yd(i, xp, d_1)?;
-        let mut dy_0: u128 = (xp[i] - y_0) / self.data().multipliers[i];
+        let mut total_supply: U256 = self.data().total_supply;
+        let mut xp: [U256; 3] = self._xp()?;
+        let mut d_0: U256 = self._get_d(xp)?;
+        let mut d_1: U256 = d_0
+            .checked_sub(
+                (d_0.checked_mul(shares)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                    / total_supply,
+            )
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        let mut y_0: U256 = self._get_yd(i, xp, d_1)?;
+        let mut dy_0: U256 = (xp[i.as_usize()]
+            .checked_sub(y_0)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+            / self.data().multipliers[i.as_usize()];
+        let mut dx: U256 = Default::default();
+        let mut j: U256 = Default::default();
         while j < N {
             if j == i {
-                dx = (xp[j] * d_1) / d_0 - y_0;
+                dx = ((xp[j.as_usize()]
+                    .checked_mul(d_1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                    / d_0)
+                    .checked_sub(y_0)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             } else {
-                dx = xp[j] - (xp[j] * d_1) / d_0;
+                dx = xp[j.as_usize()]
+                    .checked_sub(
+                        (xp[j.as_usize()]
+                            .checked_mul(d_1)
+                            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                            / d_0,
+                    )
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
-            xp[j] -= (LIQUIDITY_FEE * dx) / FEE_DENOMINATOR;
-            j += 1;
+            xp[j.as_usize()] = xp[j.as_usize()]
+                .checked_sub(
+                    (LIQUIDITY_FEE
+                        .checked_mul(dx)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+                        / FEE_DENOMINATOR,
+                )
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            j = j
+                .checked_add(U256::from(1))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         }
-        let mut y_1: u128 = self._get_yd(i, xp, d_1)?;
-        dy = (xp[i] - y_1 - 1) / self.data().multipliers[i];
-        fee = dy_0 - dy;
+        let mut y_1: U256 = self._get_yd(i, xp, d_1)?;
+        dy = (xp[i.as_usize()]
+            .checked_sub(y_1)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+            .checked_sub(U256::from(1))
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+            / self.data().multipliers[i.as_usize()];
+        fee = dy_0
+            .checked_sub(dy)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
         Ok((dy, fee))
     }
 
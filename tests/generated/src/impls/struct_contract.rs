@@ -51,17 +51,18 @@ impl<T: Storage<Data>> StructContract for T {
     fn create_todo(&mut self, text: String, priority: u8, comment: String) -> Result<(), Error> {
         self.data()
             .todos
-            .push(todo(text, false, priority, comment)?)?;
+            .push(todo(text, false, priority, comment)?);
         self.data().todos.push(todo {
             text,
             completed: false,
             priority,
             comment,
-        })?;
+        });
+        let mut todo: Todo = Default::default();
         todo.text = text;
         todo.priority = priority;
         todo.comment = comment;
-        self.data().todos.push(todo)?;
+        self.data().todos.push(todo);
         Ok(())
     }
 
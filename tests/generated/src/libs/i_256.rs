@@ -0,0 +1,309 @@
+use primitive_types::U256;
+use scale::{
+    Decode,
+    Encode,
+};
+
+// Generated with Sol2Ink v2.1.0
+// https://github.com/Brushfam/sol2ink
+
+/// Signed 256 bit integer in two's complement
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct I256(pub U256);
+
+impl I256 {
+    pub const MAX: I256 = I256(U256([u64::MAX, u64::MAX, u64::MAX, i64::MAX as u64]));
+    pub const MIN: I256 = I256(U256([0, 0, 0, 1 << 63]));
+
+    pub fn zero() -> Self {
+        I256(U256::zero())
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0.bit(255)
+    }
+
+    pub fn low_u128(&self) -> u128 {
+        self.0.low_u128()
+    }
+
+    fn unsigned_abs(self) -> U256 {
+        if self.is_negative() {
+            (!self.0).overflowing_add(U256::one()).0
+        } else {
+            self.0
+        }
+    }
+
+    fn from_sign(negative: bool, value: U256) -> Option<Self> {
+        if !negative {
+            return (!value.bit(255)).then_some(I256(value))
+        }
+        (value <= U256::one() << 255).then_some(I256((!value).overflowing_add(U256::one()).0))
+    }
+
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let result = I256(self.0.overflowing_add(other.0).0);
+        let overflow =
+            self.is_negative() == other.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let result = I256(self.0.overflowing_sub(other.0).0);
+        let overflow =
+            self.is_negative() != other.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let result = I256(self.0.overflowing_mul(other.0).0);
+        let (value, overflow) = self.unsigned_abs().overflowing_mul(other.unsigned_abs());
+        let negative = self.is_negative() != other.is_negative();
+        (
+            result,
+            overflow || I256::from_sign(negative, value).is_none(),
+        )
+    }
+
+    pub fn overflowing_pow(self, exponent: impl Into<U256>) -> (Self, bool) {
+        let mut exponent = exponent.into();
+        let mut base = self;
+        let mut result = I256::from(1);
+        let mut overflow = false;
+        while !exponent.is_zero() {
+            if exponent.bit(0) {
+                let (value, overflowed) = result.overflowing_mul(base);
+                result = value;
+                overflow |= overflowed;
+            }
+            exponent = exponent >> 1;
+            if !exponent.is_zero() {
+                let (value, overflowed) = base.overflowing_mul(base);
+                base = value;
+                overflow |= overflowed;
+            }
+        }
+        (result, overflow)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(other);
+        (!overflow).then_some(result)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(other);
+        (!overflow).then_some(result)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_mul(other);
+        (!overflow).then_some(result)
+    }
+
+    pub fn checked_pow(self, exponent: impl Into<U256>) -> Option<Self> {
+        let (result, overflow) = self.overflowing_pow(exponent);
+        (!overflow).then_some(result)
+    }
+
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0.is_zero() {
+            return None
+        }
+        let negative = self.is_negative() != other.is_negative();
+        I256::from_sign(negative, self.unsigned_abs() / other.unsigned_abs())
+    }
+
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        if other.0.is_zero() {
+            return None
+        }
+        I256::from_sign(
+            self.is_negative(),
+            self.unsigned_abs() % other.unsigned_abs(),
+        )
+    }
+}
+
+impl Ord for I256 {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => core::cmp::Ordering::Less,
+            (false, true) => core::cmp::Ordering::Greater,
+            _ => self.0.cmp(&other.0),
+        }
+    }
+}
+
+impl PartialOrd for I256 {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::ops::Add for I256 {
+    type Output = I256;
+    fn add(self, other: I256) -> I256 {
+        self.checked_add(other).expect("Arithmetic overflow")
+    }
+}
+
+impl core::ops::Sub for I256 {
+    type Output = I256;
+    fn sub(self, other: I256) -> I256 {
+        self.checked_sub(other).expect("Arithmetic overflow")
+    }
+}
+
+impl core::ops::Mul for I256 {
+    type Output = I256;
+    fn mul(self, other: I256) -> I256 {
+        self.checked_mul(other).expect("Arithmetic overflow")
+    }
+}
+
+impl core::ops::Div for I256 {
+    type Output = I256;
+    fn div(self, other: I256) -> I256 {
+        self.checked_div(other).expect("Arithmetic overflow")
+    }
+}
+
+impl core::ops::Rem for I256 {
+    type Output = I256;
+    fn rem(self, other: I256) -> I256 {
+        self.checked_rem(other).expect("Arithmetic overflow")
+    }
+}
+
+impl<T: Into<U256>> core::ops::Shl<T> for I256 {
+    type Output = I256;
+    fn shl(self, shift: T) -> I256 {
+        let shift = shift.into();
+        if shift >= U256::from(256) {
+            return I256::zero()
+        }
+        I256(self.0 << shift.as_usize())
+    }
+}
+
+/// the shift to the right is arithmetic, negative values stay negative
+impl<T: Into<U256>> core::ops::Shr<T> for I256 {
+    type Output = I256;
+    fn shr(self, shift: T) -> I256 {
+        let shift = shift.into().min(U256::from(255));
+        if self.is_negative() {
+            I256(!(!self.0 >> shift.as_usize()))
+        } else {
+            I256(self.0 >> shift.as_usize())
+        }
+    }
+}
+
+impl core::ops::Neg for I256 {
+    type Output = I256;
+    fn neg(self) -> I256 {
+        I256::zero().checked_sub(self).expect("Arithmetic overflow")
+    }
+}
+
+impl From<i8> for I256 {
+    fn from(value: i8) -> Self {
+        let value = value as i128;
+        if value < 0 {
+            I256(!U256::from(!value as u128))
+        } else {
+            I256(U256::from(value as u128))
+        }
+    }
+}
+
+impl From<i16> for I256 {
+    fn from(value: i16) -> Self {
+        let value = value as i128;
+        if value < 0 {
+            I256(!U256::from(!value as u128))
+        } else {
+            I256(U256::from(value as u128))
+        }
+    }
+}
+
+impl From<i32> for I256 {
+    fn from(value: i32) -> Self {
+        let value = value as i128;
+        if value < 0 {
+            I256(!U256::from(!value as u128))
+        } else {
+            I256(U256::from(value as u128))
+        }
+    }
+}
+
+impl From<i64> for I256 {
+    fn from(value: i64) -> Self {
+        let value = value as i128;
+        if value < 0 {
+            I256(!U256::from(!value as u128))
+        } else {
+            I256(U256::from(value as u128))
+        }
+    }
+}
+
+impl From<i128> for I256 {
+    fn from(value: i128) -> Self {
+        let value = value as i128;
+        if value < 0 {
+            I256(!U256::from(!value as u128))
+        } else {
+            I256(U256::from(value as u128))
+        }
+    }
+}
+
+impl From<u8> for I256 {
+    fn from(value: u8) -> Self {
+        I256(U256::from(value))
+    }
+}
+
+impl From<u16> for I256 {
+    fn from(value: u16) -> Self {
+        I256(U256::from(value))
+    }
+}
+
+impl From<u32> for I256 {
+    fn from(value: u32) -> Self {
+        I256(U256::from(value))
+    }
+}
+
+impl From<u64> for I256 {
+    fn from(value: u64) -> Self {
+        I256(U256::from(value))
+    }
+}
+
+impl From<u128> for I256 {
+    fn from(value: u128) -> Self {
+        I256(U256::from(value))
+    }
+}
+
+/// `int256(x)` keeps the bits of `x`
+impl From<U256> for I256 {
+    fn from(value: U256) -> Self {
+        I256(value)
+    }
+}
+
+/// `uint256(x)` keeps the bits of `x`
+impl From<I256> for U256 {
+    fn from(value: I256) -> Self {
+        value.0
+    }
+}
@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![feature(min_specialization)]
 
+pub use primitive_types::U256;
 
 // Generated with Sol2Ink v2.1.0
 // https://github.com/Brushfam/sol2ink
@@ -30,7 +31,13 @@ pub enum Error {
 }
 
 
-pub fn abs(&self, x: u128, y: u128) -> Result<u128, Error> {
-    return Ok(if x >= y { x - y } else { y - x })
+pub fn abs(x: U256, y: U256) -> Result<U256, Error> {
+    return Ok(if x >= y {
+        x.checked_sub(y)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+    } else {
+        y.checked_sub(x)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+    })
 }
 
@@ -4,3 +4,6 @@ pub use safe_math::*;
 pub mod math;
 pub use math::*;
 
+pub mod i_256;
+pub use i_256::*;
+
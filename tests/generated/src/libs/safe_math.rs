@@ -2,6 +2,7 @@
 #![feature(min_specialization)]
 
 pub use openbrush::traits::String;
+pub use primitive_types::U256;
 
 // Generated with Sol2Ink v2.1.0
 // https://github.com/Brushfam/sol2ink
@@ -24,36 +25,36 @@ pub enum Error {
 /// @dev Returns the addition of two unsigned integers, with an overflow flag.
 ///
 /// _Available since v3.4._
-pub fn try_add(&self, a: u128, b: u128) -> Result<(bool, u128), Error> {
-    let mut c: u128 = a + b;
+pub fn try_add(a: U256, b: U256) -> Result<(bool, U256), Error> {
+    let mut c: U256 = a.overflowing_add(b).0;
     if c < a {
-        return Ok((_, _))
+        return Ok((false, U256::from(0)))
     }
-    return Ok((_, _))
+    return Ok((true, c))
 }
 
 /// @dev Returns the subtraction of two unsigned integers, with an overflow flag.
 ///
 /// _Available since v3.4._
-pub fn try_sub(&self, a: u128, b: u128) -> Result<(bool, u128), Error> {
+pub fn try_sub(a: U256, b: U256) -> Result<(bool, U256), Error> {
     if b > a {
-        return Ok((_, _))
+        return Ok((false, U256::from(0)))
     }
-    return Ok((_, _))
+    return Ok((true, a.overflowing_sub(b).0))
 }
 
 /// @dev Returns the multiplication of two unsigned integers, with an overflow flag.
 ///
 /// _Available since v3.4._
-pub fn try_mul(&self, a: u128, b: u128) -> Result<(bool, u128), Error> {
-    if a == 0 {
-        return Ok((_, _))
+pub fn try_mul(a: U256, b: U256) -> Result<(bool, U256), Error> {
+    if a == U256::from(0) {
+        return Ok((true, U256::from(0)))
     }
-    let mut c: u128 = a * b;
+    let mut c: U256 = a.overflowing_mul(b).0;
     if c / a != b {
-        return Ok((_, _))
+        return Ok((false, U256::from(0)))
     }
-    return Ok((_, _))
+    return Ok((true, c))
 }
 
 /// Gas optimization: this is cheaper than requiring 'a' not being zero, but the
@@ -62,21 +63,21 @@ pub fn try_mul(&self, a: u128, b: u128) -> Result<(bool, u128), Error> {
 /// @dev Returns the division of two unsigned integers, with a division by zero flag.
 ///
 /// _Available since v3.4._
-pub fn try_div(&self, a: u128, b: u128) -> Result<(bool, u128), Error> {
-    if b == 0 {
-        return Ok((_, _))
+pub fn try_div(a: U256, b: U256) -> Result<(bool, U256), Error> {
+    if b == U256::from(0) {
+        return Ok((false, U256::from(0)))
     }
-    return Ok((_, _))
+    return Ok((true, a / b))
 }
 
 /// @dev Returns the remainder of dividing two unsigned integers, with a division by zero flag.
 ///
 /// _Available since v3.4._
-pub fn try_mod(&self, a: u128, b: u128) -> Result<(bool, u128), Error> {
-    if b == 0 {
-        return Ok((_, _))
+pub fn try_mod(a: U256, b: U256) -> Result<(bool, U256), Error> {
+    if b == U256::from(0) {
+        return Ok((false, U256::from(0)))
     }
-    return Ok((_, _))
+    return Ok((true, a % b))
 }
 
 /// @dev Returns the addition of two unsigned integers, reverting on
@@ -87,8 +88,10 @@ pub fn try_mod(&self, a: u128, b: u128) -> Result<(bool, u128), Error> {
 /// Requirements:
 ///
 /// - Addition cannot overflow.
-pub fn add(&self, a: u128, b: u128) -> Result<u128, Error> {
-    return Ok(a + b)
+pub fn add(a: U256, b: U256) -> Result<U256, Error> {
+    return Ok(a
+        .checked_add(b)
+        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
 }
 
 /// @dev Returns the subtraction of two unsigned integers, reverting on
@@ -99,8 +102,10 @@ pub fn add(&self, a: u128, b: u128) -> Result<u128, Error> {
 /// Requirements:
 ///
 /// - Subtraction cannot overflow.
-pub fn sub(&self, a: u128, b: u128) -> Result<u128, Error> {
-    return Ok(a - b)
+pub fn sub_uint_256_uint_256(a: U256, b: U256) -> Result<U256, Error> {
+    return Ok(a
+        .checked_sub(b)
+        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
 }
 
 /// @dev Returns the multiplication of two unsigned integers, reverting on
@@ -111,8 +116,10 @@ pub fn sub(&self, a: u128, b: u128) -> Result<u128, Error> {
 /// Requirements:
 ///
 /// - Multiplication cannot overflow.
-pub fn mul(&self, a: u128, b: u128) -> Result<u128, Error> {
-    return Ok(a * b)
+pub fn mul(a: U256, b: U256) -> Result<U256, Error> {
+    return Ok(a
+        .checked_mul(b)
+        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
 }
 
 /// @dev Returns the integer division of two unsigned integers, reverting on
@@ -123,7 +130,7 @@ pub fn mul(&self, a: u128, b: u128) -> Result<u128, Error> {
 /// Requirements:
 ///
 /// - The divisor cannot be zero.
-pub fn div(&self, a: u128, b: u128) -> Result<u128, Error> {
+pub fn div_uint_256_uint_256(a: U256, b: U256) -> Result<U256, Error> {
     return Ok(a / b)
 }
 
@@ -137,7 +144,7 @@ pub fn div(&self, a: u128, b: u128) -> Result<u128, Error> {
 /// Requirements:
 ///
 /// - The divisor cannot be zero.
-pub fn mod_is_rust_keyword(&self, a: u128, b: u128) -> Result<u128, Error> {
+pub fn mod_uint_256_uint_256(a: U256, b: U256) -> Result<U256, Error> {
     return Ok(a % b)
 }
 
@@ -152,11 +159,15 @@ pub fn mod_is_rust_keyword(&self, a: u128, b: u128) -> Result<u128, Error> {
 /// Requirements:
 ///
 /// - Subtraction cannot overflow.
-pub fn sub(&self, a: u128, b: u128, error_message: String) -> Result<u128, Error> {
+pub fn sub_uint_256_uint_256_string(
+    a: U256,
+    b: U256,
+    error_message: String,
+) -> Result<U256, Error> {
     if !(b <= a) {
         return Err(Error::Custom(error_message))
     };
-    return Ok(a - b)
+    return Ok(a.overflowing_sub(b).0)
 }
 
 /// @dev Returns the integer division of two unsigned integers, reverting with custom message on
@@ -169,8 +180,12 @@ pub fn sub(&self, a: u128, b: u128, error_message: String) -> Result<u128, Error
 /// Requirements:
 ///
 /// - The divisor cannot be zero.
-pub fn div(&self, a: u128, b: u128, error_message: String) -> Result<u128, Error> {
-    if !(b > 0) {
+pub fn div_uint_256_uint_256_string(
+    a: U256,
+    b: U256,
+    error_message: String,
+) -> Result<U256, Error> {
+    if !(b > U256::from(0)) {
         return Err(Error::Custom(error_message))
     };
     return Ok(a / b)
@@ -189,8 +204,12 @@ pub fn div(&self, a: u128, b: u128, error_message: String) -> Result<u128, Error
 /// Requirements:
 ///
 /// - The divisor cannot be zero.
-pub fn mod_is_rust_keyword(&self, a: u128, b: u128, error_message: String) -> Result<u128, Error> {
-    if !(b > 0) {
+pub fn mod_uint_256_uint_256_string(
+    a: U256,
+    b: U256,
+    error_message: String,
+) -> Result<U256, Error> {
+    if !(b > U256::from(0)) {
         return Err(Error::Custom(error_message))
     };
     return Ok(a % b)
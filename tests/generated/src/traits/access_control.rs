@@ -1,10 +1,12 @@
 // Generated with Sol2Ink v2.1.0
 // https://github.com/Brushfam/sol2ink
 
+pub use ink::prelude::vec::*;
 pub use openbrush::{
     storage::Mapping,
     traits::AccountId,
 };
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
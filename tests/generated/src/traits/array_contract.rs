@@ -2,6 +2,7 @@
 // https://github.com/Brushfam/sol2ink
 
 pub use openbrush::storage::Mapping;
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
@@ -17,9 +18,9 @@ pub enum Error {
 #[derive(Default, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub struct TestStruct {
-    struct_mapping: Mapping<u128, u128>,
+    struct_mapping: Mapping<U256, U256>,
     struct_f_array: [u8; 32],
-    struct_d_array: Vec<u128>,
+    struct_d_array: Vec<U256>,
 }
 
 #[derive(Default, Encode, Decode)]
@@ -9,6 +9,7 @@ pub use openbrush::{
         String,
     },
 };
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
@@ -1,6 +1,7 @@
 // Generated with Sol2Ink v2.1.0
 // https://github.com/Brushfam/sol2ink
 
+pub use crate::libs;
 pub use ink::prelude::vec::*;
 pub use openbrush::{
     storage::Mapping,
@@ -11,6 +12,7 @@ pub use openbrush::{
         ZERO_ADDRESS,
     },
 };
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
@@ -42,7 +44,7 @@ pub trait ERC1155 {
     /// Clients calling this function must replace the `\{id\}` substring with the
     /// actual token type ID.
     #[ink(message)]
-    fn uri(&self, _: u128) -> Result<String, Error>;
+    fn uri(&self, _: U256) -> Result<String, Error>;
 
     /// @dev See {IERC1155-balanceOf}.
     ///
@@ -50,7 +52,7 @@ pub trait ERC1155 {
     ///
     /// - `account` cannot be the zero address.
     #[ink(message)]
-    fn balance_of(&self, account: AccountId, id: u128) -> Result<u128, Error>;
+    fn balance_of(&self, account: AccountId, id: U256) -> Result<U256, Error>;
 
     /// @dev See {IERC1155-balanceOfBatch}.
     ///
@@ -61,8 +63,8 @@ pub trait ERC1155 {
     fn balance_of_batch(
         &self,
         accounts: Vec<AccountId>,
-        ids: Vec<u128>,
-    ) -> Result<Vec<u128>, Error>;
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Error>;
 
     /// @dev See {IERC1155-setApprovalForAll}.
     #[ink(message)]
@@ -78,8 +80,8 @@ pub trait ERC1155 {
         &mut self,
         from: AccountId,
         to: AccountId,
-        id: u128,
-        amount: u128,
+        id: U256,
+        amount: U256,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -89,8 +91,8 @@ pub trait ERC1155 {
         &mut self,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
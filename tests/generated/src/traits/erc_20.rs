@@ -10,6 +10,7 @@ pub use openbrush::{
         ZERO_ADDRESS,
     },
 };
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
@@ -53,11 +54,11 @@ pub trait ERC20 {
 
     /// @dev See {IERC20-totalSupply}.
     #[ink(message)]
-    fn total_supply(&self) -> Result<u128, Error>;
+    fn total_supply(&self) -> Result<U256, Error>;
 
     /// @dev See {IERC20-balanceOf}.
     #[ink(message)]
-    fn balance_of(&self, account: AccountId) -> Result<u128, Error>;
+    fn balance_of(&self, account: AccountId) -> Result<U256, Error>;
 
     /// @dev See {IERC20-transfer}.
     ///
@@ -66,11 +67,11 @@ pub trait ERC20 {
     /// - `to` cannot be the zero address.
     /// - the caller must have a balance of at least `amount`.
     #[ink(message)]
-    fn transfer(&mut self, to: AccountId, amount: u128) -> Result<bool, Error>;
+    fn transfer(&mut self, to: AccountId, amount: U256) -> Result<bool, Error>;
 
     /// @dev See {IERC20-allowance}.
     #[ink(message)]
-    fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<u128, Error>;
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<U256, Error>;
 
     /// @dev See {IERC20-approve}.
     ///
@@ -81,7 +82,7 @@ pub trait ERC20 {
     ///
     /// - `spender` cannot be the zero address.
     #[ink(message)]
-    fn approve(&mut self, spender: AccountId, amount: u128) -> Result<bool, Error>;
+    fn approve(&mut self, spender: AccountId, amount: U256) -> Result<bool, Error>;
 
     /// @dev See {IERC20-transferFrom}.
     ///
@@ -102,7 +103,7 @@ pub trait ERC20 {
         &mut self,
         from: AccountId,
         to: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<bool, Error>;
 
     /// @dev Atomically increases the allowance granted to `spender` by the caller.
@@ -116,7 +117,7 @@ pub trait ERC20 {
     ///
     /// - `spender` cannot be the zero address.
     #[ink(message)]
-    fn increase_allowance(&mut self, spender: AccountId, added_value: u128) -> Result<bool, Error>;
+    fn increase_allowance(&mut self, spender: AccountId, added_value: U256) -> Result<bool, Error>;
 
     /// @dev Atomically decreases the allowance granted to `spender` by the caller.
     ///
@@ -134,7 +135,7 @@ pub trait ERC20 {
     fn decrease_allowance(
         &mut self,
         spender: AccountId,
-        subtracted_value: u128,
+        subtracted_value: U256,
     ) -> Result<bool, Error>;
 
 }
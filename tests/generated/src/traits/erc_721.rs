@@ -1,6 +1,7 @@
 // Generated with Sol2Ink v2.1.0
 // https://github.com/Brushfam/sol2ink
 
+pub use crate::libs;
 pub use ink::prelude::vec::*;
 pub use openbrush::{
     storage::Mapping,
@@ -11,6 +12,7 @@ pub use openbrush::{
         ZERO_ADDRESS,
     },
 };
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
@@ -35,11 +37,11 @@ pub trait ERC721 {
 
     /// @dev See {IERC721-balanceOf}.
     #[ink(message)]
-    fn balance_of(&self, owner: AccountId) -> Result<u128, Error>;
+    fn balance_of(&self, owner: AccountId) -> Result<U256, Error>;
 
     /// @dev See {IERC721-ownerOf}.
     #[ink(message)]
-    fn owner_of(&self, token_id: u128) -> Result<AccountId, Error>;
+    fn owner_of(&self, token_id: U256) -> Result<AccountId, Error>;
 
     /// @dev See {IERC721Metadata-name}.
     #[ink(message)]
@@ -51,15 +53,15 @@ pub trait ERC721 {
 
     /// @dev See {IERC721Metadata-tokenURI}.
     #[ink(message)]
-    fn token_uri(&self, token_id: u128) -> Result<String, Error>;
+    fn token_uri(&self, token_id: U256) -> Result<String, Error>;
 
     /// @dev See {IERC721-approve}.
     #[ink(message)]
-    fn approve(&mut self, to: AccountId, token_id: u128) -> Result<(), Error>;
+    fn approve(&mut self, to: AccountId, token_id: U256) -> Result<(), Error>;
 
     /// @dev See {IERC721-getApproved}.
     #[ink(message)]
-    fn get_approved(&self, token_id: u128) -> Result<AccountId, Error>;
+    fn get_approved(&self, token_id: U256) -> Result<AccountId, Error>;
 
     /// @dev See {IERC721-setApprovalForAll}.
     #[ink(message)]
@@ -75,26 +77,26 @@ pub trait ERC721 {
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
     ) -> Result<(), Error>;
 
     ///solhint-disable-next-line max-line-length
     /// @dev See {IERC721-safeTransferFrom}.
     #[ink(message)]
-    fn safe_transfer_from(
+    fn safe_transfer_from_address_address_uint_256(
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
     ) -> Result<(), Error>;
 
     /// @dev See {IERC721-safeTransferFrom}.
     #[ink(message)]
-    fn safe_transfer_from(
+    fn safe_transfer_from_address_address_uint_256_bytes(
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
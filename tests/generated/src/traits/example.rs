@@ -6,6 +6,7 @@ pub use openbrush::traits::{
     AccountIdExt,
     ZERO_ADDRESS,
 };
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
@@ -90,15 +91,15 @@ pub trait example {
 
     ///is this number a power-of-two
     #[ink(message)]
-    fn is_power_of_2(&self, n: u128) -> Result<bool, Error>;
+    fn is_power_of_2(&self, n: U256) -> Result<bool, Error>;
 
     ///calculate the population count (number of set bits) using Brian Kerningham's way
     #[ink(message)]
-    fn population_count(&self, n: u128) -> Result<u128, Error>;
+    fn population_count(&self, n: U256) -> Result<U256, Error>;
 
     ///calculate the power of base to exp
     #[ink(message)]
-    fn power(&self, base: u128, exp: u128) -> Result<u128, Error>;
+    fn power(&self, base: U256, exp: U256) -> Result<U256, Error>;
 
     ///returns true if the address is 0
     #[ink(message)]
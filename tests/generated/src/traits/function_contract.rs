@@ -7,6 +7,7 @@ pub use openbrush::traits::{
     String,
     ZERO_ADDRESS,
 };
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
@@ -29,51 +30,51 @@ pub trait FunctionContract {
     fn change_owner(&mut self, new_owner: AccountId) -> Result<(), Error>;
 
     #[ink(message)]
-    fn decrement(&mut self, i: u128) -> Result<(), Error>;
+    fn decrement(&mut self, i: U256) -> Result<(), Error>;
 
     /// Functions can return multiple values.
     #[ink(message)]
-    fn return_many(&self) -> Result<(u128, bool, u128), Error>;
+    fn return_many(&self) -> Result<(U256, bool, U256), Error>;
 
     /// Return values can be named.
     #[ink(message)]
-    fn named(&self) -> Result<(u128, bool, u128), Error>;
+    fn named(&self) -> Result<(U256, bool, U256), Error>;
 
     /// Return values can be assigned to their name.
     /// In this case the return statement can be omitted.
     #[ink(message)]
-    fn assigned(&self) -> Result<(u128, bool, u128), Error>;
+    fn assigned(&self) -> Result<(U256, bool, U256), Error>;
 
     /// Use destructuring assignment when calling another
     /// function that returns multiple values.
     #[ink(message)]
-    fn destructuring_assignments(&self) -> Result<(u128, bool, u128, u128, u128), Error>;
+    fn destructuring_assignments(&self) -> Result<(U256, bool, U256, U256, U256), Error>;
 
     /// Values can be left out.
     /// Cannot use map for either input or output
     /// Can use array for input
     #[ink(message)]
-    fn array_input(&mut self, arr: Vec<u128>) -> Result<(), Error>;
+    fn array_input(&mut self, arr: Vec<U256>) -> Result<(), Error>;
 
     #[ink(message)]
-    fn array_output(&self) -> Result<Vec<u128>, Error>;
+    fn array_output(&self) -> Result<Vec<U256>, Error>;
 
     /// Call function with key-value inputs
     #[ink(message)]
     fn some_func_with_many_inputs(
         &self,
-        x: u128,
-        y: u128,
-        z: u128,
+        x: U256,
+        y: U256,
+        z: U256,
         a: AccountId,
         b: bool,
         c: String,
-    ) -> Result<u128, Error>;
+    ) -> Result<U256, Error>;
 
     #[ink(message)]
-    fn call_func(&self) -> Result<u128, Error>;
+    fn call_func(&self) -> Result<U256, Error>;
 
     #[ink(message)]
-    fn call_func_with_key_value(&self) -> Result<u128, Error>;
+    fn call_func_with_key_value(&self) -> Result<U256, Error>;
 
 }
@@ -6,6 +6,7 @@ pub use openbrush::traits::{
     AccountId,
     String,
 };
+pub use primitive_types::U256;
 
 /// @dev Emitted when `value` tokens of token type `id` are transferred from `from` to `to` by `operator`.
 #[ink(event)]
@@ -16,8 +17,8 @@ pub struct TransferSingle {
     from: AccountId,
     #[ink(topic)]
     to: AccountId,
-    id: u128,
-    value: u128,
+    id: U256,
+    value: U256,
 }
 
 /// @dev Equivalent to multiple {TransferSingle} events, where `operator`, `from` and `to` are the same for all
@@ -30,8 +31,8 @@ pub struct TransferBatch {
     from: AccountId,
     #[ink(topic)]
     to: AccountId,
-    ids: Vec<u128>,
-    values: Vec<u128>,
+    ids: Vec<U256>,
+    values: Vec<U256>,
 }
 
 /// @dev Emitted when `account` grants or revokes permission to `operator` to transfer their tokens, according to
@@ -54,7 +55,7 @@ pub struct ApprovalForAll {
 pub struct URI {
     value: String,
     #[ink(topic)]
-    id: u128,
+    id: U256,
 }
 
 #[openbrush::wrapper]
@@ -68,7 +69,7 @@ pub trait IERC1155 {
     ///
     /// - `account` cannot be the zero address.
     #[ink(message)]
-    fn balance_of(&self, account: AccountId, id: u128) -> Result<u128, Error>;
+    fn balance_of(&self, account: AccountId, id: U256) -> Result<U256, Error>;
 
     /// @dev xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {balanceOf}.
     ///
@@ -79,8 +80,8 @@ pub trait IERC1155 {
     fn balance_of_batch(
         &self,
         accounts: Vec<AccountId>,
-        ids: Vec<u128>,
-    ) -> Result<Vec<u128>, Error>;
+        ids: Vec<U256>,
+    ) -> Result<Vec<U256>, Error>;
 
     /// @dev Grants or revokes permission to `operator` to transfer the caller's tokens, according to `approved`,
     ///
@@ -114,8 +115,8 @@ pub trait IERC1155 {
         &mut self,
         from: AccountId,
         to: AccountId,
-        id: u128,
-        amount: u128,
+        id: U256,
+        amount: U256,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -133,8 +134,8 @@ pub trait IERC1155 {
         &mut self,
         from: AccountId,
         to: AccountId,
-        ids: Vec<u128>,
-        amounts: Vec<u128>,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
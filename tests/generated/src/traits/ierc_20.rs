@@ -1,10 +1,12 @@
 // Generated with Sol2Ink v2.1.0
 // https://github.com/Brushfam/sol2ink
 
+pub use crate::libs;
 pub use openbrush::{
     storage::Mapping,
     traits::AccountId,
 };
+pub use primitive_types::U256;
 
 #[ink(event)]
 pub struct Transfer {
@@ -12,7 +14,7 @@ pub struct Transfer {
     from: AccountId,
     #[ink(topic)]
     to: AccountId,
-    amount: u128,
+    amount: U256,
 }
 
 #[ink(event)]
@@ -21,7 +23,7 @@ pub struct Approval {
     owner: AccountId,
     #[ink(topic)]
     spender: AccountId,
-    amount: u128,
+    amount: U256,
 }
 
 #[openbrush::wrapper]
@@ -30,26 +32,26 @@ pub type IERC20Ref = dyn IERC20;
 #[openbrush::trait_definition]
 pub trait IERC20 {
     #[ink(message)]
-    fn total_supply(&self) -> Result<u128, Error>;
+    fn total_supply(&self) -> Result<U256, Error>;
 
     #[ink(message)]
-    fn balance_of(&self, account: AccountId) -> Result<u128, Error>;
+    fn balance_of(&self, account: AccountId) -> Result<U256, Error>;
 
     #[ink(message)]
-    fn transfer(&mut self, recipient: AccountId, amount: u128) -> Result<bool, Error>;
+    fn transfer(&mut self, recipient: AccountId, amount: U256) -> Result<bool, Error>;
 
     #[ink(message)]
-    fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<u128, Error>;
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<U256, Error>;
 
     #[ink(message)]
-    fn approve(&mut self, spender: AccountId, amount: u128) -> Result<bool, Error>;
+    fn approve(&mut self, spender: AccountId, amount: U256) -> Result<bool, Error>;
 
     #[ink(message)]
     fn transfer_from(
         &mut self,
         sender: AccountId,
         recipient: AccountId,
-        amount: u128,
+        amount: U256,
     ) -> Result<bool, Error>;
 
 }
@@ -3,6 +3,7 @@
 
 pub use ink::prelude::vec::*;
 pub use openbrush::traits::AccountId;
+pub use primitive_types::U256;
 
 /// @dev Emitted when `tokenId` token is transferred from `from` to `to`.
 #[ink(event)]
@@ -12,7 +13,7 @@ pub struct Transfer {
     #[ink(topic)]
     to: AccountId,
     #[ink(topic)]
-    token_id: u128,
+    token_id: U256,
 }
 
 /// @dev Emitted when `owner` enables `approved` to manage the `tokenId` token.
@@ -23,7 +24,7 @@ pub struct Approval {
     #[ink(topic)]
     approved: AccountId,
     #[ink(topic)]
-    token_id: u128,
+    token_id: U256,
 }
 
 /// @dev Emitted when `owner` enables or disables (`approved`) `operator` to manage all of its assets.
@@ -43,7 +44,7 @@ pub type IERC721Ref = dyn IERC721;
 pub trait IERC721 {
     /// @dev Returns the number of tokens in ``owner``'s account.
     #[ink(message)]
-    fn balance_of(&self, owner: AccountId) -> Result<u128, Error>;
+    fn balance_of(&self, owner: AccountId) -> Result<U256, Error>;
 
     /// @dev Returns the owner of the `tokenId` token.
     ///
@@ -51,7 +52,7 @@ pub trait IERC721 {
     ///
     /// - `tokenId` must exist.
     #[ink(message)]
-    fn owner_of(&self, token_id: u128) -> Result<AccountId, Error>;
+    fn owner_of(&self, token_id: U256) -> Result<AccountId, Error>;
 
     /// @dev Safely transfers `tokenId` token from `from` to `to`.
     ///
@@ -65,11 +66,11 @@ pub trait IERC721 {
     ///
     /// Emits a {Transfer} event.
     #[ink(message)]
-    fn safe_transfer_from(
+    fn safe_transfer_from_address_address_uint_256_bytes(
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
@@ -86,11 +87,11 @@ pub trait IERC721 {
     ///
     /// Emits a {Transfer} event.
     #[ink(message)]
-    fn safe_transfer_from(
+    fn safe_transfer_from_address_address_uint_256(
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
     ) -> Result<(), Error>;
 
     /// @dev Transfers `tokenId` token from `from` to `to`.
@@ -112,7 +113,7 @@ pub trait IERC721 {
         &mut self,
         from: AccountId,
         to: AccountId,
-        token_id: u128,
+        token_id: U256,
     ) -> Result<(), Error>;
 
     /// @dev Gives permission to `to` to transfer `tokenId` token to another account.
@@ -127,7 +128,7 @@ pub trait IERC721 {
     ///
     /// Emits an {Approval} event.
     #[ink(message)]
-    fn approve(&mut self, to: AccountId, token_id: u128) -> Result<(), Error>;
+    fn approve(&mut self, to: AccountId, token_id: U256) -> Result<(), Error>;
 
     /// @dev Approve or remove `operator` as an operator for the caller.
     /// Operators can call {transferFrom} or {safeTransferFrom} for any token owned by the caller.
@@ -146,7 +147,7 @@ pub trait IERC721 {
     ///
     /// - `tokenId` must exist.
     #[ink(message)]
-    fn get_approved(&self, token_id: u128) -> Result<AccountId, Error>;
+    fn get_approved(&self, token_id: U256) -> Result<AccountId, Error>;
 
     /// @dev Returns if the `operator` is allowed to manage all of the assets of `owner`.
     ///
@@ -1,7 +1,9 @@
 // Generated with Sol2Ink v2.1.0
 // https://github.com/Brushfam/sol2ink
 
+pub use crate::libs::i_256::I256;
 pub use openbrush::traits::AccountId;
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
@@ -47,10 +49,10 @@ pub trait primitives {
     fn op_u_64(&self, op: Oper, a: u64, b: u64) -> Result<u64, Error>;
 
     #[ink(message)]
-    fn op_u_256(&self, op: Oper, a: u128, b: u128) -> Result<u128, Error>;
+    fn op_u_256(&self, op: Oper, a: U256, b: U256) -> Result<U256, Error>;
 
     #[ink(message)]
-    fn op_i_256(&self, op: Oper, a: i128, b: i128) -> Result<i128, Error>;
+    fn op_i_256(&self, op: Oper, a: I256, b: I256) -> Result<I256, Error>;
 
     #[ink(message)]
     fn return_u_8_6(&self) -> Result<[u8; 6], Error>;
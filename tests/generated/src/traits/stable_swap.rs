@@ -1,10 +1,12 @@
 // Generated with Sol2Ink v2.1.0
 // https://github.com/Brushfam/sol2ink
 
+pub use crate::libs;
 pub use openbrush::{
     storage::Mapping,
     traits::AccountId,
 };
+pub use primitive_types::U256;
 use scale::{
     Decode,
     Encode,
@@ -28,7 +30,7 @@ pub trait StableSwap {
     /// Estimate value of 1 share
     /// How many tokens is one share worth?
     #[ink(message)]
-    fn get_virtual_price(&self) -> Result<u128, Error>;
+    fn get_virtual_price(&self) -> Result<U256, Error>;
 
     /// @notice Swap dx amount of token i for token j
     /// @param i Index of token in
@@ -36,14 +38,14 @@ pub trait StableSwap {
     /// @param dx Token in amount
     /// @param minDy Minimum token out
     #[ink(message)]
-    fn swap(&mut self, i: u128, j: u128, dx: u128, min_dy: u128) -> Result<u128, Error>;
+    fn swap(&mut self, i: U256, j: U256, dx: U256, min_dy: U256) -> Result<U256, Error>;
 
     /// Calculate dy
     /// y0 must be >= y1, since x has increased
     /// -1 to round down
     /// Subtract fee from dy
     #[ink(message)]
-    fn add_liquidity(&mut self, amounts: Vec<u128>, min_shares: u128) -> Result<u128, Error>;
+    fn add_liquidity(&mut self, amounts: [U256; 3], min_shares: U256) -> Result<U256, Error>;
 
     /// calculate current liquidity d0
     /// Transfer tokens in
@@ -56,9 +58,9 @@ pub trait StableSwap {
     #[ink(message)]
     fn remove_liquidity(
         &mut self,
-        shares: u128,
-        min_amounts_out: Vec<u128>,
-    ) -> Result<Vec<u128>, Error>;
+        shares: U256,
+        min_amounts_out: [U256; 3],
+    ) -> Result<[U256; 3], Error>;
 
     /// Calculate d0 and d1
     /// Calculate reduction in y if D = d1
@@ -68,7 +70,7 @@ pub trait StableSwap {
     /// Recalculate y with xp including imbalance fees
     /// - 1 to round down
     #[ink(message)]
-    fn calc_withdraw_one_token(&self, shares: u128, i: u128) -> Result<(u128, u128), Error>;
+    fn calc_withdraw_one_token(&self, shares: U256, i: U256) -> Result<(U256, U256), Error>;
 
     /// @notice Withdraw liquidity in token i
     /// @param shares Shares to burn
@@ -77,9 +79,9 @@ pub trait StableSwap {
     #[ink(message)]
     fn remove_liquidity_one_token(
         &mut self,
-        shares: u128,
-        i: u128,
-        min_amount_out: u128,
-    ) -> Result<u128, Error>;
+        shares: U256,
+        i: U256,
+        min_amount_out: U256,
+    ) -> Result<U256, Error>;
 
 }